@@ -0,0 +1,109 @@
+use serde::Deserialize;
+use terra_sdk_core::distribution::{
+    CommunityPool, DelegatorTotalRewards, DistributionParams, ValidatorRewards,
+};
+use terra_sdk_core::{AccAddress, DecCoins, ValAddress};
+
+use crate::lcd_client::api::{ApiRequester, Handle};
+
+pub struct DistributionApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+#[derive(Deserialize)]
+struct OutstandingRewards {
+    rewards: DecCoins,
+}
+
+#[derive(Deserialize)]
+struct ValidatorCommission {
+    commission: DecCoins,
+}
+
+impl<T> DistributionApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    /// Rewards of all of a delegator's delegations.
+    pub async fn delegator_rewards(
+        &self,
+        delegator: &AccAddress,
+    ) -> Result<DelegatorTotalRewards, String> {
+        let endpoint = format!(
+            "cosmos/distribution/v1beta1/delegators/{}/rewards",
+            delegator
+        );
+        self.requester.get(&endpoint).await
+    }
+
+    pub async fn delegation_rewards(
+        &self,
+        delegator: &AccAddress,
+        validator: &ValAddress,
+    ) -> Result<DecCoins, String> {
+        let endpoint = format!(
+            "cosmos/distribution/v1beta1/delegators/{}/rewards/{}",
+            delegator, validator
+        );
+        self.requester.get_field(&endpoint, "rewards").await
+    }
+
+    pub async fn validator_rewards(
+        &self,
+        validator: &ValAddress,
+    ) -> Result<ValidatorRewards, String> {
+        let outstanding: OutstandingRewards = self
+            .requester
+            .get_field(
+                &format!(
+                    "cosmos/distribution/v1beta1/validators/{}/outstanding_rewards",
+                    validator
+                ),
+                "rewards",
+            )
+            .await?;
+        let commission: ValidatorCommission = self
+            .requester
+            .get_field(
+                &format!(
+                    "cosmos/distribution/v1beta1/validators/{}/commission",
+                    validator
+                ),
+                "commission",
+            )
+            .await?;
+        Ok(ValidatorRewards {
+            outstanding_rewards: outstanding.rewards,
+            commission: commission.commission,
+        })
+    }
+
+    pub async fn community_pool(&self) -> Result<CommunityPool, String> {
+        self.requester
+            .get("cosmos/distribution/v1beta1/community_pool")
+            .await
+    }
+
+    pub async fn withdraw_address(&self, delegator: &AccAddress) -> Result<AccAddress, String> {
+        let endpoint = format!(
+            "cosmos/distribution/v1beta1/delegators/{}/withdraw_address",
+            delegator
+        );
+        self.requester
+            .get_field(&endpoint, "withdraw_address")
+            .await
+    }
+
+    pub async fn parameters(&self) -> Result<DistributionParams, String> {
+        self.requester
+            .get_field("cosmos/distribution/v1beta1/params", "params")
+            .await
+    }
+}
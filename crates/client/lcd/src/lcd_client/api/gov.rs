@@ -0,0 +1,122 @@
+use serde::Deserialize;
+use terra_sdk_core::gov::{
+    Deposit, DepositParams, GovParams, Proposal, ProposalStatus, TallyParams, TallyResult, Vote,
+    VotingParams,
+};
+
+use crate::lcd_client::api::{with_query, ApiRequester, Handle, Pagination, PaginationOptions};
+
+pub struct GovApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+#[derive(Deserialize)]
+struct ProposalsResponse {
+    proposals: Vec<Proposal>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct VotesResponse {
+    votes: Vec<Vote>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct DepositsResponse {
+    deposits: Vec<Deposit>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+impl<T> GovApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    pub async fn proposal(&self, proposal_id: u64) -> Result<Proposal, String> {
+        let endpoint = format!("cosmos/gov/v1beta1/proposals/{}", proposal_id);
+        self.requester.get_field(&endpoint, "proposal").await
+    }
+
+    pub async fn proposals(
+        &self,
+        status: Option<ProposalStatus>,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<Proposal>, Pagination), String> {
+        let mut query = pagination.unwrap_or_default().to_query();
+        if let Some(status) = status {
+            query.push(("proposal_status".into(), status.as_str().into()));
+        }
+        let endpoint = with_query("cosmos/gov/v1beta1/proposals", &query);
+        let response: ProposalsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.proposals, response.pagination))
+    }
+
+    /// Current tally of a proposal in its voting period.
+    pub async fn tally(&self, proposal_id: u64) -> Result<TallyResult, String> {
+        let endpoint = format!("cosmos/gov/v1beta1/proposals/{}/tally", proposal_id);
+        self.requester.get_field(&endpoint, "tally").await
+    }
+
+    pub async fn votes(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<Vote>, Pagination), String> {
+        let endpoint = with_query(
+            &format!("cosmos/gov/v1beta1/proposals/{}/votes", proposal_id),
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: VotesResponse = self.requester.get(&endpoint).await?;
+        Ok((response.votes, response.pagination))
+    }
+
+    pub async fn deposits(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<Deposit>, Pagination), String> {
+        let endpoint = with_query(
+            &format!("cosmos/gov/v1beta1/proposals/{}/deposits", proposal_id),
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: DepositsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.deposits, response.pagination))
+    }
+
+    pub async fn deposit_parameters(&self) -> Result<DepositParams, String> {
+        self.requester
+            .get_field("cosmos/gov/v1beta1/params/deposit", "deposit_params")
+            .await
+    }
+
+    pub async fn voting_parameters(&self) -> Result<VotingParams, String> {
+        self.requester
+            .get_field("cosmos/gov/v1beta1/params/voting", "voting_params")
+            .await
+    }
+
+    pub async fn tally_parameters(&self) -> Result<TallyParams, String> {
+        self.requester
+            .get_field("cosmos/gov/v1beta1/params/tallying", "tally_params")
+            .await
+    }
+
+    /// All gov parameters; the LCD serves each group from its own endpoint.
+    pub async fn parameters(&self) -> Result<GovParams, String> {
+        Ok(GovParams {
+            deposit_params: self.deposit_parameters().await?,
+            voting_params: self.voting_parameters().await?,
+            tally_params: self.tally_parameters().await?,
+        })
+    }
+}
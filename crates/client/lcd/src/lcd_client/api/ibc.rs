@@ -0,0 +1,151 @@
+use serde::Deserialize;
+use serde_json::Value;
+use terra_sdk_core::ibc::{
+    Channel, ConsensusStateWithHeight, IdentifiedChannel, IdentifiedClientState,
+    IdentifiedConnection, PacketState,
+};
+
+use crate::lcd_client::api::{with_query, ApiRequester, Handle, Pagination, PaginationOptions};
+
+pub struct IbcApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+#[derive(Deserialize)]
+struct ClientStatesResponse {
+    client_states: Vec<IdentifiedClientState>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct ConsensusStatesResponse {
+    consensus_states: Vec<ConsensusStateWithHeight>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct ConnectionsResponse {
+    connections: Vec<IdentifiedConnection>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct ChannelsResponse {
+    channels: Vec<IdentifiedChannel>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct PacketCommitmentsResponse {
+    commitments: Vec<PacketState>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+impl<T> IbcApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    /// Client state of `client_id` in protobuf JSON.
+    pub async fn client_state(&self, client_id: &str) -> Result<Value, String> {
+        let endpoint = format!("ibc/core/client/v1/client_states/{}", client_id);
+        self.requester.get_field(&endpoint, "client_state").await
+    }
+
+    pub async fn client_states(
+        &self,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<IdentifiedClientState>, Pagination), String> {
+        let endpoint = with_query(
+            "ibc/core/client/v1/client_states",
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: ClientStatesResponse = self.requester.get(&endpoint).await?;
+        Ok((response.client_states, response.pagination))
+    }
+
+    /// Consensus states stored by `client_id`, one per height it was
+    /// updated to.
+    pub async fn consensus_states(
+        &self,
+        client_id: &str,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<ConsensusStateWithHeight>, Pagination), String> {
+        let endpoint = with_query(
+            &format!("ibc/core/client/v1/consensus_states/{}", client_id),
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: ConsensusStatesResponse = self.requester.get(&endpoint).await?;
+        Ok((response.consensus_states, response.pagination))
+    }
+
+    pub async fn connection(&self, connection_id: &str) -> Result<IdentifiedConnection, String> {
+        let endpoint = format!("ibc/core/connection/v1/connections/{}", connection_id);
+        let mut connection: IdentifiedConnection =
+            self.requester.get_field(&endpoint, "connection").await?;
+        connection.id = connection_id.to_string();
+        Ok(connection)
+    }
+
+    pub async fn connections(
+        &self,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<IdentifiedConnection>, Pagination), String> {
+        let endpoint = with_query(
+            "ibc/core/connection/v1/connections",
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: ConnectionsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.connections, response.pagination))
+    }
+
+    pub async fn channel(&self, port_id: &str, channel_id: &str) -> Result<Channel, String> {
+        let endpoint = format!(
+            "ibc/core/channel/v1/channels/{}/ports/{}",
+            channel_id, port_id
+        );
+        self.requester.get_field(&endpoint, "channel").await
+    }
+
+    pub async fn channels(
+        &self,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<IdentifiedChannel>, Pagination), String> {
+        let endpoint = with_query(
+            "ibc/core/channel/v1/channels",
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: ChannelsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.channels, response.pagination))
+    }
+
+    /// Commitments of packets sent over the channel that have not yet been
+    /// acknowledged or timed out.
+    pub async fn packet_commitments(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<PacketState>, Pagination), String> {
+        let endpoint = with_query(
+            &format!(
+                "ibc/core/channel/v1/channels/{}/ports/{}/packet_commitments",
+                channel_id, port_id
+            ),
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: PacketCommitmentsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.commitments, response.pagination))
+    }
+}
@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use terra_sdk_core::ibc_transfer::{ibc_denom_hash, DenomTrace};
+
+use crate::lcd_client::api::{with_query, ApiRequester, Handle, Pagination, PaginationOptions};
+
+pub struct IbcTransferApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+#[derive(Deserialize)]
+struct DenomTracesResponse {
+    denom_traces: Vec<DenomTrace>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+impl<T> IbcTransferApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    /// Trace of a voucher, given either its hash or its `ibc/{hash}` denom.
+    pub async fn denom_trace(&self, denom: &str) -> Result<DenomTrace, String> {
+        let hash = ibc_denom_hash(denom).unwrap_or(denom);
+        let endpoint = format!("ibc/apps/transfer/v1/denom_traces/{}", hash);
+        self.requester.get_field(&endpoint, "denom_trace").await
+    }
+
+    /// Resolves `denom` to its origin; non-`ibc/` denoms are native.
+    pub async fn resolve_denom(&self, denom: &str) -> Result<DenomTrace, String> {
+        match ibc_denom_hash(denom) {
+            Some(_) => self.denom_trace(denom).await,
+            None => Ok(DenomTrace::new("", denom)),
+        }
+    }
+
+    pub async fn denom_traces(
+        &self,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<DenomTrace>, Pagination), String> {
+        let endpoint = with_query(
+            "ibc/apps/transfer/v1/denom_traces",
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: DenomTracesResponse = self.requester.get(&endpoint).await?;
+        Ok((response.denom_traces, response.pagination))
+    }
+}
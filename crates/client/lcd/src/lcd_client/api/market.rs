@@ -0,0 +1,25 @@
+use terra_sdk_core::market::MarketParams;
+
+use crate::lcd_client::api::{ApiRequester, Handle};
+
+pub struct MarketApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+impl<T> MarketApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    pub async fn parameters(&self) -> Result<MarketParams, String> {
+        self.requester
+            .get_field("terra/market/v1beta1/params", "params")
+            .await
+    }
+}
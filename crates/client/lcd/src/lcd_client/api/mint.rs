@@ -0,0 +1,25 @@
+use terra_sdk_core::mint::MintParams;
+
+use crate::lcd_client::api::{ApiRequester, Handle};
+
+pub struct MintApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+impl<T> MintApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    pub async fn parameters(&self) -> Result<MintParams, String> {
+        self.requester
+            .get_field("cosmos/mint/v1beta1/params", "params")
+            .await
+    }
+}
@@ -1,24 +1,42 @@
+pub(crate) mod distribution;
+pub(crate) mod gov;
+pub(crate) mod ibc;
+pub(crate) mod ibc_transfer;
+pub(crate) mod market;
+pub(crate) mod mint;
+pub(crate) mod oracle;
+pub(crate) mod pagination;
+pub(crate) mod slashing;
+pub(crate) mod staking;
+pub(crate) mod treasury;
+pub(crate) mod tx;
+pub(crate) mod upgrade;
 pub(crate) mod wasm;
 
+pub use pagination::*;
+
 use async_trait::async_trait;
 use reqwest::{
     header::{HeaderMap, HeaderValue, CONTENT_TYPE},
     Client, Request,
 };
 use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
 use std::borrow::Borrow;
-use std::cell::RefCell;
 use std::sync::{Arc, Weak};
+
+use crate::lcd_client::LCDConfig;
 pub struct Handle<T> {
-    inner: Option<Weak<RefCell<T>>>,
+    inner: Option<Weak<T>>,
 }
 
 impl<T> Handle<T> {
-    pub fn new(inner: Option<Weak<RefCell<T>>>) -> Self {
+    pub fn new(inner: Option<Weak<T>>) -> Self {
         Self { inner }
     }
 
-    pub fn hold(&self) -> Result<Arc<RefCell<T>>, String> {
+    pub fn hold(&self) -> Result<Arc<T>, String> {
         if self.inner.is_none() {
             return Err("unable to hold: handle is None".into());
         }
@@ -30,15 +48,77 @@ impl<T> Handle<T> {
     }
 }
 
+impl<T> Handle<T>
+where
+    T: ApiRequester,
+{
+    pub async fn get<R>(&self, endpoint: &str) -> Result<R, String>
+    where
+        R: DeserializeOwned,
+    {
+        self.hold()?
+            .get(endpoint)
+            .await
+            .map_err(|_| format!("request to {} failed", endpoint))
+    }
+
+    /// Fetches `endpoint` and deserializes a single top-level field of the
+    /// response, e.g. `validator` in `{"validator": {...}}`.
+    pub async fn get_field<R>(&self, endpoint: &str, field: &str) -> Result<R, String>
+    where
+        R: DeserializeOwned,
+    {
+        let mut response: Value = self.get(endpoint).await?;
+        let value = response
+            .get_mut(field)
+            .map(Value::take)
+            .ok_or_else(|| format!("response from {} has no {}", endpoint, field))?;
+        serde_json::from_value(value)
+            .map_err(|e| format!("invalid {} from {}: {}", field, endpoint, e))
+    }
+
+    pub async fn post<B, R>(&self, endpoint: &str, body: &B) -> Result<R, String>
+    where
+        B: Serialize + Sync,
+        R: DeserializeOwned,
+    {
+        self.hold()?
+            .post(endpoint, body)
+            .await
+            .map_err(|_| format!("request to {} failed", endpoint))
+    }
+
+    /// Like `get_field`, for a POST request.
+    pub async fn post_field<B, R>(&self, endpoint: &str, body: &B, field: &str) -> Result<R, String>
+    where
+        B: Serialize + Sync,
+        R: DeserializeOwned,
+    {
+        let mut response: Value = self.post(endpoint, body).await?;
+        let value = response
+            .get_mut(field)
+            .map(Value::take)
+            .ok_or_else(|| format!("response from {} has no {}", endpoint, field))?;
+        serde_json::from_value(value)
+            .map_err(|e| format!("invalid {} from {}: {}", field, endpoint, e))
+    }
+}
+
 #[async_trait]
 pub trait ApiRequester {
     async fn get<T>(&self, endpoint: &str) -> Result<T, ()>
     where
         T: DeserializeOwned;
+
+    async fn post<B, T>(&self, endpoint: &str, body: &B) -> Result<T, ()>
+    where
+        B: Serialize + Sync,
+        T: DeserializeOwned;
 }
 
 pub struct BasicApiRequester {
     client: Client,
+    config: LCDConfig,
 }
 
 #[async_trait]
@@ -47,7 +127,22 @@ impl ApiRequester for BasicApiRequester {
     where
         T: DeserializeOwned,
     {
-        let request = self._request_get(&endpoint);
+        let request = self._request_get(endpoint)?;
+        let result = self
+            .client
+            .borrow()
+            .execute(request)
+            .await
+            .map_err(|_| ())?;
+        result.json().await.map_err(|_| ())
+    }
+
+    async fn post<B, T>(&self, endpoint: &str, body: &B) -> Result<T, ()>
+    where
+        B: Serialize + Sync,
+        T: DeserializeOwned,
+    {
+        let request = self._request_post(endpoint, body)?;
         let result = self
             .client
             .borrow()
@@ -59,19 +154,40 @@ impl ApiRequester for BasicApiRequester {
 }
 
 impl BasicApiRequester {
-    pub fn new(client: Client) -> Self {
-        Self { client }
+    pub fn new(client: Client, config: LCDConfig) -> Self {
+        Self { client, config }
     }
 
-    fn _request_get(&self, endpoint: &str) -> Request {
+    /// A requester with an HTTP client built from `config`.
+    pub fn from_config(config: LCDConfig) -> Result<Self, String> {
+        Ok(Self::new(config.http_client()?, config))
+    }
+
+    pub fn config(&self) -> &LCDConfig {
+        &self.config
+    }
+
+    fn _request_get(&self, endpoint: &str) -> Result<Request, ()> {
         let mut headers = HeaderMap::new();
         headers.append(CONTENT_TYPE, HeaderValue::from_str("text/json").unwrap());
 
         self.client
             .borrow()
-            .get(format!("https://lcd.terra.dev/{}", endpoint))
+            .get(self.config.endpoint_url(endpoint).map_err(|_| ())?)
             .headers(headers)
             .build()
-            .unwrap()
+            .map_err(|_| ())
+    }
+
+    fn _request_post<B>(&self, endpoint: &str, body: &B) -> Result<Request, ()>
+    where
+        B: Serialize,
+    {
+        self.client
+            .borrow()
+            .post(self.config.endpoint_url(endpoint).map_err(|_| ())?)
+            .json(body)
+            .build()
+            .map_err(|_| ())
     }
 }
@@ -0,0 +1,25 @@
+use terra_sdk_core::oracle::OracleParams;
+
+use crate::lcd_client::api::{ApiRequester, Handle};
+
+pub struct OracleApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+impl<T> OracleApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    pub async fn parameters(&self) -> Result<OracleParams, String> {
+        self.requester
+            .get_field("terra/oracle/v1beta1/params", "params")
+            .await
+    }
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Deserializer};
+
+/// Options for list queries, sent as `pagination.*` query parameters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaginationOptions {
+    pub key: Option<String>,
+    pub offset: Option<u64>,
+    pub limit: Option<u64>,
+    pub count_total: bool,
+    pub reverse: bool,
+}
+
+impl PaginationOptions {
+    pub fn limit(limit: u64) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Default::default()
+        }
+    }
+
+    /// Options for the page after `pagination`, if there is one.
+    pub fn next(&self, pagination: &Pagination) -> Option<Self> {
+        pagination.next_key.as_ref().map(|key| Self {
+            key: Some(key.clone()),
+            offset: None,
+            ..self.clone()
+        })
+    }
+
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+        if let Some(key) = &self.key {
+            query.push(("pagination.key".into(), key.clone()));
+        }
+        if let Some(offset) = self.offset {
+            query.push(("pagination.offset".into(), offset.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("pagination.limit".into(), limit.to_string()));
+        }
+        if self.count_total {
+            query.push(("pagination.count_total".into(), "true".into()));
+        }
+        if self.reverse {
+            query.push(("pagination.reverse".into(), "true".into()));
+        }
+        query
+    }
+}
+
+/// The `pagination` field of list query responses.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pagination {
+    pub next_key: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_total")]
+    pub total: u64,
+}
+
+fn deserialize_total<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let total = Option::<String>::deserialize(deserializer)?;
+    total
+        .map(|t| t.parse().map_err(serde::de::Error::custom))
+        .unwrap_or(Ok(0))
+}
+
+/// Appends query parameters to an endpoint, percent-encoding the values.
+pub(crate) fn with_query(endpoint: &str, query: &[(String, String)]) -> String {
+    if query.is_empty() {
+        return endpoint.to_string();
+    }
+    let params = query
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, encode_query_value(v)))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}?{}", endpoint, params)
+}
+
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_builds_query() {
+        let mut options = PaginationOptions::limit(10);
+        options.key = Some("ab+/=".into());
+        assert_eq!(
+            with_query("validators", &options.to_query()),
+            "validators?pagination.key=ab%2B%2F%3D&pagination.limit=10"
+        );
+        let page: Pagination = serde_json::from_str(r#"{"next_key": null, "total": "3"}"#).unwrap();
+        assert_eq!(page.total, 3);
+        assert!(options.next(&page).is_none());
+    }
+}
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+use terra_sdk_core::slashing::{SlashingParams, ValidatorSigningInfo};
+use terra_sdk_core::ValConsAddress;
+
+use crate::lcd_client::api::{with_query, ApiRequester, Handle, Pagination, PaginationOptions};
+
+pub struct SlashingApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+#[derive(Deserialize)]
+struct SigningInfosResponse {
+    info: Vec<ValidatorSigningInfo>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+impl<T> SlashingApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    pub async fn signing_info(
+        &self,
+        cons_address: &ValConsAddress,
+    ) -> Result<ValidatorSigningInfo, String> {
+        let endpoint = format!("cosmos/slashing/v1beta1/signing_infos/{}", cons_address);
+        self.requester
+            .get_field(&endpoint, "val_signing_info")
+            .await
+    }
+
+    pub async fn signing_infos(
+        &self,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<ValidatorSigningInfo>, Pagination), String> {
+        let endpoint = with_query(
+            "cosmos/slashing/v1beta1/signing_infos",
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: SigningInfosResponse = self.requester.get(&endpoint).await?;
+        Ok((response.info, response.pagination))
+    }
+
+    pub async fn parameters(&self) -> Result<SlashingParams, String> {
+        self.requester
+            .get_field("cosmos/slashing/v1beta1/params", "params")
+            .await
+    }
+}
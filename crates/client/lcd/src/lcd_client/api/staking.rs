@@ -0,0 +1,149 @@
+use serde::Deserialize;
+use terra_sdk_core::staking::{
+    BondStatus, DelegationResponse, RedelegationResponse, StakingParams, UnbondingDelegation,
+    Validator,
+};
+use terra_sdk_core::{AccAddress, ValAddress};
+
+use crate::lcd_client::api::{with_query, ApiRequester, Handle, Pagination, PaginationOptions};
+
+pub struct StakingApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+#[derive(Deserialize)]
+struct ValidatorsResponse {
+    validators: Vec<Validator>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct DelegationsResponse {
+    delegation_responses: Vec<DelegationResponse>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct UnbondingDelegationsResponse {
+    unbonding_responses: Vec<UnbondingDelegation>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize)]
+struct RedelegationsResponse {
+    redelegation_responses: Vec<RedelegationResponse>,
+    #[serde(default)]
+    pagination: Pagination,
+}
+
+impl<T> StakingApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    pub async fn validator(&self, validator: &ValAddress) -> Result<Validator, String> {
+        let endpoint = format!("cosmos/staking/v1beta1/validators/{}", validator);
+        self.requester.get_field(&endpoint, "validator").await
+    }
+
+    pub async fn validators(
+        &self,
+        status: Option<BondStatus>,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<Validator>, Pagination), String> {
+        let mut query = pagination.unwrap_or_default().to_query();
+        if let Some(status) = status {
+            let status = serde_json::to_value(status).map_err(|e| e.to_string())?;
+            query.push(("status".into(), status.as_str().unwrap_or_default().into()));
+        }
+        let endpoint = with_query("cosmos/staking/v1beta1/validators", &query);
+        let response: ValidatorsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.validators, response.pagination))
+    }
+
+    pub async fn delegation(
+        &self,
+        delegator: &AccAddress,
+        validator: &ValAddress,
+    ) -> Result<DelegationResponse, String> {
+        let endpoint = format!(
+            "cosmos/staking/v1beta1/validators/{}/delegations/{}",
+            validator, delegator
+        );
+        self.requester
+            .get_field(&endpoint, "delegation_response")
+            .await
+    }
+
+    pub async fn delegations(
+        &self,
+        delegator: &AccAddress,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<DelegationResponse>, Pagination), String> {
+        let endpoint = with_query(
+            &format!("cosmos/staking/v1beta1/delegations/{}", delegator),
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: DelegationsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.delegation_responses, response.pagination))
+    }
+
+    pub async fn unbonding_delegation(
+        &self,
+        delegator: &AccAddress,
+        validator: &ValAddress,
+    ) -> Result<UnbondingDelegation, String> {
+        let endpoint = format!(
+            "cosmos/staking/v1beta1/validators/{}/delegations/{}/unbonding_delegation",
+            validator, delegator
+        );
+        self.requester.get_field(&endpoint, "unbond").await
+    }
+
+    pub async fn unbonding_delegations(
+        &self,
+        delegator: &AccAddress,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<UnbondingDelegation>, Pagination), String> {
+        let endpoint = with_query(
+            &format!(
+                "cosmos/staking/v1beta1/delegators/{}/unbonding_delegations",
+                delegator
+            ),
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: UnbondingDelegationsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.unbonding_responses, response.pagination))
+    }
+
+    pub async fn redelegations(
+        &self,
+        delegator: &AccAddress,
+        pagination: Option<PaginationOptions>,
+    ) -> Result<(Vec<RedelegationResponse>, Pagination), String> {
+        let endpoint = with_query(
+            &format!(
+                "cosmos/staking/v1beta1/delegators/{}/redelegations",
+                delegator
+            ),
+            &pagination.unwrap_or_default().to_query(),
+        );
+        let response: RedelegationsResponse = self.requester.get(&endpoint).await?;
+        Ok((response.redelegation_responses, response.pagination))
+    }
+
+    pub async fn parameters(&self) -> Result<StakingParams, String> {
+        self.requester
+            .get_field("cosmos/staking/v1beta1/params", "params")
+            .await
+    }
+}
@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use terra_sdk_core::treasury::{TaxCalculator, TaxCap, TreasuryParams};
+use terra_sdk_core::{Dec, Uint128};
+
+use crate::lcd_client::api::{ApiRequester, Handle};
+
+pub struct TreasuryApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+#[derive(Deserialize)]
+struct TaxCapsResponse {
+    tax_caps: Vec<TaxCap>,
+}
+
+impl<T> TreasuryApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    pub async fn parameters(&self) -> Result<TreasuryParams, String> {
+        self.requester
+            .get_field("terra/treasury/v1beta1/params", "params")
+            .await
+    }
+
+    /// The stability tax rate of the current epoch.
+    pub async fn tax_rate(&self) -> Result<Dec, String> {
+        self.requester
+            .get_field("terra/treasury/v1beta1/tax_rate", "tax_rate")
+            .await
+    }
+
+    pub async fn reward_weight(&self) -> Result<Dec, String> {
+        self.requester
+            .get_field("terra/treasury/v1beta1/reward_weight", "reward_weight")
+            .await
+    }
+
+    pub async fn tax_cap(&self, denom: &str) -> Result<Uint128, String> {
+        let endpoint = format!("terra/treasury/v1beta1/tax_caps/{}", denom);
+        self.requester.get_field(&endpoint, "tax_cap").await
+    }
+
+    pub async fn tax_caps(&self) -> Result<Vec<TaxCap>, String> {
+        let response: TaxCapsResponse = self
+            .requester
+            .get("terra/treasury/v1beta1/tax_caps")
+            .await?;
+        Ok(response.tax_caps)
+    }
+
+    /// A stability tax calculator with the current rate and caps. Denoms
+    /// without a cap of their own use the tax policy's cap, as on chain.
+    pub async fn tax_calculator(&self) -> Result<TaxCalculator, String> {
+        let params = self.parameters().await?;
+        Ok(
+            TaxCalculator::new(self.tax_rate().await?, self.tax_caps().await?)
+                .default_cap(params.tax_policy.cap.amount),
+        )
+    }
+}
@@ -0,0 +1,57 @@
+use serde::Serialize;
+use serde_json::json;
+use terra_sdk_core::auth::{TxRaw, TxResponse};
+use terra_sdk_core::ProtoMsg;
+
+use crate::lcd_client::api::{ApiRequester, Handle};
+
+/// How long `broadcast` waits before returning.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum BroadcastMode {
+    /// Until the transaction passed `CheckTx`.
+    #[default]
+    #[serde(rename = "BROADCAST_MODE_SYNC")]
+    Sync,
+    /// Not at all.
+    #[serde(rename = "BROADCAST_MODE_ASYNC")]
+    Async,
+    /// Until the transaction is included in a block. Removed in newer
+    /// versions of the SDK.
+    #[serde(rename = "BROADCAST_MODE_BLOCK")]
+    Block,
+}
+
+pub struct TxApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+impl<T> TxApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    /// Broadcasts a signed transaction. A failed `CheckTx` is reported in
+    /// the response's `code`; see `TxResponse::into_result`.
+    pub async fn broadcast(&self, tx: &TxRaw, mode: BroadcastMode) -> Result<TxResponse, String> {
+        let body = json!({
+            "tx_bytes": base64::encode(tx.encode_proto()?),
+            "mode": mode,
+        });
+        self.requester
+            .post_field("cosmos/tx/v1beta1/txs", &body, "tx_response")
+            .await
+    }
+
+    /// A transaction included in a block, by hash.
+    pub async fn tx_info(&self, hash: &str) -> Result<TxResponse, String> {
+        self.requester
+            .get_field(&format!("cosmos/tx/v1beta1/txs/{}", hash), "tx_response")
+            .await
+    }
+}
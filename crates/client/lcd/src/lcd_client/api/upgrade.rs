@@ -0,0 +1,49 @@
+use terra_sdk_core::upgrade::{ModuleVersion, Plan};
+
+use crate::lcd_client::api::{with_query, ApiRequester, Handle};
+
+pub struct UpgradeApi<T>
+where
+    T: ApiRequester,
+{
+    requester: Handle<T>,
+}
+
+impl<T> UpgradeApi<T>
+where
+    T: ApiRequester,
+{
+    pub fn new(requester: Handle<T>) -> Self {
+        Self { requester }
+    }
+
+    /// The scheduled upgrade, if any.
+    pub async fn current_plan(&self) -> Result<Option<Plan>, String> {
+        self.requester
+            .get_field("cosmos/upgrade/v1beta1/current_plan", "plan")
+            .await
+    }
+
+    /// Height at which the upgrade `name` was applied, if it has been.
+    pub async fn applied_plan(&self, name: &str) -> Result<Option<u64>, String> {
+        let endpoint = format!("cosmos/upgrade/v1beta1/applied_plan/{}", name);
+        let height: String = self.requester.get_field(&endpoint, "height").await?;
+        let height: u64 = height
+            .parse()
+            .map_err(|e| format!("invalid height from {}: {}", endpoint, e))?;
+        Ok(Some(height).filter(|height| *height > 0))
+    }
+
+    /// Consensus versions of all modules, or only of `module`.
+    pub async fn module_versions(
+        &self,
+        module: Option<&str>,
+    ) -> Result<Vec<ModuleVersion>, String> {
+        let query: Vec<(String, String)> = module
+            .map(|module| ("module_name".to_string(), module.to_string()))
+            .into_iter()
+            .collect();
+        let endpoint = with_query("cosmos/upgrade/v1beta1/module_versions", &query);
+        self.requester.get_field(&endpoint, "module_versions").await
+    }
+}
@@ -1,5 +1,9 @@
-use crate::lcd_client::api::{ApiRequester, Handle};
+use crate::lcd_client::api::{with_query, ApiRequester, Handle};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::Value;
+use terra_sdk_core::cw20::{AllAccountsResponse, BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
+use terra_sdk_core::{AccAddress, Uint128};
 
 pub struct WasmApi<T>
 where
@@ -18,6 +22,52 @@ where
 
     pub async fn parameters(&self) -> Result<Value, String> {
         let endpoint = "wasm/parameters";
-        Ok(self.requester.hold()?.borrow().get(endpoint).await.unwrap())
+        self.requester.get(endpoint).await
+    }
+
+    /// Runs a smart query against `contract` and deserializes its result.
+    pub async fn contract_query<Q, R>(&self, contract: &AccAddress, query: &Q) -> Result<R, String>
+    where
+        Q: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let query = serde_json::to_vec(query).map_err(|e| format!("invalid query: {}", e))?;
+        let endpoint = with_query(
+            &format!("terra/wasm/v1beta1/contracts/{}/store", contract),
+            &[("query_msg".into(), base64::encode(query))],
+        );
+        self.requester.get_field(&endpoint, "query_result").await
+    }
+
+    /// Balance of `address` in the CW20 token at `token`.
+    pub async fn cw20_balance(
+        &self,
+        token: &AccAddress,
+        address: &AccAddress,
+    ) -> Result<Uint128, String> {
+        let query = Cw20QueryMsg::Balance {
+            address: address.clone(),
+        };
+        let response: BalanceResponse = self.contract_query(token, &query).await?;
+        Ok(response.balance)
+    }
+
+    pub async fn cw20_token_info(&self, token: &AccAddress) -> Result<TokenInfoResponse, String> {
+        self.contract_query(token, &Cw20QueryMsg::TokenInfo {})
+            .await
+    }
+
+    /// One page of token holders, ordered by address. Pass the last
+    /// returned address as `start_after` to fetch the next page; an empty
+    /// page means there are no more accounts.
+    pub async fn cw20_all_accounts(
+        &self,
+        token: &AccAddress,
+        start_after: Option<AccAddress>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AccAddress>, String> {
+        let query = Cw20QueryMsg::AllAccounts { start_after, limit };
+        let response: AllAccountsResponse = self.contract_query(token, &query).await?;
+        Ok(response.accounts)
     }
 }
@@ -0,0 +1,223 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Url};
+use terra_sdk_core::auth::Fee;
+use terra_sdk_core::{Dec, DecCoins};
+
+/// Where and how an `LCDClient` talks to a chain.
+///
+/// Start from a preset and adjust it:
+///
+/// ```ignore
+/// let config = LCDConfig::testnet()
+///     .url("http://localhost:1317")?
+///     .timeout(Duration::from_secs(10));
+/// let terra = LCDClient::new(config)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct LCDConfig {
+    pub url: Url,
+    pub chain_id: String,
+    /// Gas prices used to turn gas into fees, one per accepted denom.
+    pub gas_prices: DecCoins,
+    /// Factor applied to simulated gas usage to get the gas limit.
+    pub gas_adjustment: Dec,
+    pub timeout: Option<Duration>,
+    pub user_agent: Option<String>,
+    /// Sent with every request, e.g. API keys of hosted nodes.
+    pub headers: Vec<(String, String)>,
+}
+
+impl LCDConfig {
+    pub fn new(url: &str, chain_id: impl Into<String>) -> Result<Self, String> {
+        Ok(Self {
+            url: parse_base_url(url)?,
+            chain_id: chain_id.into(),
+            gas_prices: DecCoins::new(),
+            gas_adjustment: Dec::one(),
+            timeout: None,
+            user_agent: None,
+            headers: vec![],
+        })
+    }
+
+    /// Terra mainnet, `phoenix-1`.
+    pub fn mainnet() -> Self {
+        Self::preset("https://phoenix-lcd.terra.dev", "phoenix-1", "0.015uluna")
+    }
+
+    /// Terra testnet, `pisco-1`.
+    pub fn testnet() -> Self {
+        Self::preset("https://pisco-lcd.terra.dev", "pisco-1", "0.015uluna")
+    }
+
+    /// Terra classic, `columbus-5`.
+    pub fn classic() -> Self {
+        Self::preset(
+            "https://lcd.terra.dev",
+            "columbus-5",
+            "28.325uluna,0.75uusd",
+        )
+    }
+
+    /// A `localterra` node on this machine.
+    pub fn localterra() -> Self {
+        Self::preset("http://localhost:1317", "localterra", "0.015uluna")
+    }
+
+    fn preset(url: &str, chain_id: &str, gas_prices: &str) -> Self {
+        Self {
+            gas_prices: DecCoins::parse(gas_prices).expect("valid preset gas prices"),
+            gas_adjustment: Dec::from_str("1.75").expect("valid decimal"),
+            ..Self::new(url, chain_id).expect("valid preset url")
+        }
+    }
+
+    pub fn url(mut self, url: &str) -> Result<Self, String> {
+        self.url = parse_base_url(url)?;
+        Ok(self)
+    }
+
+    pub fn chain_id(mut self, chain_id: impl Into<String>) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+
+    pub fn gas_prices(mut self, gas_prices: impl Into<DecCoins>) -> Self {
+        self.gas_prices = gas_prices.into();
+        self
+    }
+
+    pub fn gas_adjustment(mut self, gas_adjustment: Dec) -> Self {
+        self.gas_adjustment = gas_adjustment;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// The URL of `endpoint` under the base URL, keeping any path the base
+    /// URL has (e.g. `https://host/terra/` + `cosmos/...`).
+    pub fn endpoint_url(&self, endpoint: &str) -> Result<Url, String> {
+        self.url
+            .join(endpoint.trim_start_matches('/'))
+            .map_err(|e| format!("invalid endpoint {}: {}", endpoint, e))
+    }
+
+    /// The fee for a transaction that used `gas_used` gas in simulation,
+    /// paid in `denom` at the configured gas price.
+    pub fn estimate_fee(&self, gas_used: u64, denom: &str) -> Result<Fee, String> {
+        let gas_price = self
+            .gas_prices
+            .get(denom)
+            .ok_or_else(|| format!("no gas price for {}", denom))?;
+        Fee::estimate(gas_used, self.gas_adjustment, gas_price)
+    }
+
+    /// An HTTP client with the configured timeout, user agent and headers.
+    pub fn http_client(&self) -> Result<Client, String> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.append(
+                HeaderName::from_str(name)
+                    .map_err(|e| format!("invalid header {}: {}", name, e))?,
+                HeaderValue::from_str(value)
+                    .map_err(|e| format!("invalid value of header {}: {}", name, e))?,
+            );
+        }
+        let mut builder = Client::builder().default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.as_str());
+        }
+        builder.build().map_err(|e| e.to_string())
+    }
+}
+
+/// Terra classic, whose wasm routes `WasmApi` queries.
+impl Default for LCDConfig {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+// Without a trailing slash, joining would replace the last path segment.
+fn parse_base_url(url: &str) -> Result<Url, String> {
+    let mut url = Url::parse(url).map_err(|e| format!("invalid url {}: {}", url, e))?;
+    if url.cannot_be_a_base() {
+        return Err(format!("invalid base url: {}", url));
+    }
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_joins_endpoints() {
+        let config = LCDConfig::localterra();
+        assert_eq!(
+            config
+                .endpoint_url("cosmos/bank/v1beta1/params")
+                .unwrap()
+                .as_str(),
+            "http://localhost:1317/cosmos/bank/v1beta1/params"
+        );
+
+        let config = config.url("https://node.example.com/terra/lcd").unwrap();
+        assert_eq!(
+            config
+                .endpoint_url("/cosmos/tx/v1beta1/txs?pagination.limit=5")
+                .unwrap()
+                .as_str(),
+            "https://node.example.com/terra/lcd/cosmos/tx/v1beta1/txs?pagination.limit=5"
+        );
+        assert!(LCDConfig::new("not a url", "x").is_err());
+    }
+
+    #[test]
+    fn it_defaults_to_classic() {
+        let config = LCDConfig::default();
+        assert_eq!(config.url.as_str(), "https://lcd.terra.dev/");
+        assert_eq!(config.chain_id, "columbus-5");
+    }
+
+    #[test]
+    fn it_estimates_fees_from_presets() {
+        let config = LCDConfig::classic();
+        assert_eq!(config.chain_id, "columbus-5");
+        assert_eq!(
+            config.estimate_fee(100_000, "uusd").unwrap(),
+            Fee::new(175_000, "131250uusd")
+        );
+        assert!(config.estimate_fee(100_000, "ukrw").is_err());
+
+        let config = LCDConfig::mainnet()
+            .header("x-api-key", "secret")
+            .user_agent("indexer/1.0")
+            .timeout(Duration::from_secs(5));
+        assert!(config.http_client().is_ok());
+        assert!(config.header("bad header", "x").http_client().is_err());
+    }
+}
@@ -1,29 +1,89 @@
 mod api;
+mod config;
+pub use api::tx::BroadcastMode;
+pub use api::wasm::WasmApi;
 use api::*;
+pub use api::{ApiRequester, BasicApiRequester, Handle, Pagination, PaginationOptions};
+pub use config::LCDConfig;
 
-use std::cell::RefCell;
 use std::sync::Arc;
 
-use self::api::wasm::WasmApi;
-use reqwest::Client;
+use self::api::distribution::DistributionApi;
+use self::api::gov::GovApi;
+use self::api::ibc::IbcApi;
+use self::api::ibc_transfer::IbcTransferApi;
+use self::api::market::MarketApi;
+use self::api::mint::MintApi;
+use self::api::oracle::OracleApi;
+use self::api::slashing::SlashingApi;
+use self::api::staking::StakingApi;
+use self::api::treasury::TreasuryApi;
+use self::api::tx::TxApi;
+use self::api::upgrade::UpgradeApi;
 
 pub struct LCDClient<T>
 where
     T: ApiRequester,
 {
-    requester: Arc<RefCell<T>>,
+    /// Owns the requester; the API handles only hold weak references to it.
+    #[allow(dead_code)]
+    requester: Arc<T>,
+    pub config: LCDConfig,
     pub wasm: wasm::WasmApi<T>,
+    pub staking: staking::StakingApi<T>,
+    pub distribution: distribution::DistributionApi<T>,
+    pub gov: gov::GovApi<T>,
+    pub slashing: slashing::SlashingApi<T>,
+    pub ibc: ibc::IbcApi<T>,
+    pub ibc_transfer: ibc_transfer::IbcTransferApi<T>,
+    pub market: market::MarketApi<T>,
+    pub mint: mint::MintApi<T>,
+    pub oracle: oracle::OracleApi<T>,
+    pub treasury: treasury::TreasuryApi<T>,
+    pub tx: tx::TxApi<T>,
+    pub upgrade: upgrade::UpgradeApi<T>,
 }
 
 impl LCDClient<BasicApiRequester> {
-    pub fn new() -> Self {
-        let requester = BasicApiRequester::new(Client::new());
-        let arc_req = Arc::new(RefCell::new(requester));
+    pub fn new(config: LCDConfig) -> Result<Self, String> {
+        let requester = BasicApiRequester::from_config(config.clone())?;
+        Ok(Self::with_requester(requester, config))
+    }
+}
+
+impl<T> LCDClient<T>
+where
+    T: ApiRequester,
+{
+    /// A client sending its requests through `requester`, e.g. one with
+    /// retries or caching. `config` is only kept for reference.
+    pub fn with_requester(requester: T, config: LCDConfig) -> Self {
+        let arc_req = Arc::new(requester);
         let weak_req = Arc::downgrade(&arc_req);
 
         Self {
             requester: arc_req,
+            config,
             wasm: WasmApi::new(Handle::new(Some(weak_req.clone()))),
+            staking: StakingApi::new(Handle::new(Some(weak_req.clone()))),
+            distribution: DistributionApi::new(Handle::new(Some(weak_req.clone()))),
+            gov: GovApi::new(Handle::new(Some(weak_req.clone()))),
+            slashing: SlashingApi::new(Handle::new(Some(weak_req.clone()))),
+            ibc: IbcApi::new(Handle::new(Some(weak_req.clone()))),
+            ibc_transfer: IbcTransferApi::new(Handle::new(Some(weak_req.clone()))),
+            market: MarketApi::new(Handle::new(Some(weak_req.clone()))),
+            mint: MintApi::new(Handle::new(Some(weak_req.clone()))),
+            oracle: OracleApi::new(Handle::new(Some(weak_req.clone()))),
+            treasury: TreasuryApi::new(Handle::new(Some(weak_req.clone()))),
+            tx: TxApi::new(Handle::new(Some(weak_req.clone()))),
+            upgrade: UpgradeApi::new(Handle::new(Some(weak_req.clone()))),
         }
     }
 }
+
+/// A client for Terra classic; see `LCDConfig::default`.
+impl Default for LCDClient<BasicApiRequester> {
+    fn default() -> Self {
+        Self::new(LCDConfig::default()).expect("default config builds a client")
+    }
+}
@@ -1,14 +1,107 @@
 pub mod lcd_client;
-pub use lcd_client::LCDClient;
+pub use lcd_client::{ApiRequester, BasicApiRequester, LCDClient, LCDConfig, WasmApi};
+
+/// Runtime dependencies of code generated by `contract_client!`.
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    pub use serde_json;
+    pub use terra_sdk_core;
+    pub use terra_sdk_internal::contract_client;
+}
+
+/// Generates a typed client for a CosmWasm contract from its JSON schema
+/// directory, relative to the calling crate's `Cargo.toml`.
+///
+/// Every `ExecuteMsg` variant becomes an `execute_*` method taking the
+/// sender and the funds to send along and returning a `MsgExecuteContract`,
+/// every `QueryMsg` variant a `query_*` method running the query through a
+/// `WasmApi`. Message fields become parameters in alphabetical order,
+/// optional fields as `Option`s that are left out of the message when
+/// `None`. Names that are not
+/// valid identifiers are escaped: `type` becomes `r#type`, `self` becomes
+/// `self_` and `1st` becomes `_1st`.
+///
+/// ```ignore
+/// terra_sdk_client_lcd::contract_client!(pub Cw20Client, "schema/cw20");
+///
+/// let token = Cw20Client::new("terra1...");
+/// let msg = token.execute_transfer(sender, Coins::new(), "terra1...", Uint128::new(100u32));
+/// let balance: Value = token.query_balance(&lcd.wasm, "terra1...").await?;
+/// ```
+#[macro_export]
+macro_rules! contract_client {
+    ($vis:vis $name:ident, $schema_dir:literal) => {
+        $crate::__private::contract_client!($crate; $vis $name, $schema_dir);
+    };
+}
 
 #[cfg(test)]
 mod tests {
     use crate::lcd_client::LCDClient;
-    use tokio;
+    use serde_json::json;
+    use terra_sdk_core::{Coins, Uint128};
+
+    contract_client!(CounterClient, "testdata/counter");
+    contract_client!(NamesClient, "testdata/names");
+
+    const CONTRACT: &str = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+    const SENDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+
+    #[test]
+    fn it_generates_contract_client() {
+        let counter = CounterClient::new(CONTRACT);
+        let msg = counter.execute_increment(SENDER, "100uluna");
+        assert_eq!(msg.contract.to_string(), CONTRACT);
+        assert_eq!(msg.execute_msg, json!("increment"));
+        assert_eq!(msg.coins, Coins::from("100uluna"));
+
+        let msg = counter.execute_reset(SENDER, Coins::new(), 5);
+        assert_eq!(msg.execute_msg, json!({ "reset": { "count": 5 } }));
+        assert!(msg.coins.is_empty());
+
+        let msg = counter.execute_transfer(SENDER, Coins::new(), Uint128::new(10u32), None, SENDER);
+        assert_eq!(
+            msg.execute_msg,
+            json!({ "transfer": { "amount": "10", "recipient": SENDER } })
+        );
+        let msg = counter.execute_transfer(
+            SENDER,
+            Coins::new(),
+            Uint128::new(10u32),
+            Some("rent".into()),
+            SENDER,
+        );
+        assert_eq!(msg.execute_msg["transfer"]["memo"], "rent");
+
+        let terra = LCDClient::default();
+        // Only checks that the query methods exist with the expected shape.
+        drop(counter.query_balance::<_, serde_json::Value>(&terra.wasm, SENDER));
+        drop(counter.query_get_count::<_, serde_json::Value>(&terra.wasm));
+    }
+
+    #[test]
+    fn it_escapes_schema_names() {
+        let names = NamesClient::new(CONTRACT);
+        let msg = names.execute_new(SENDER, Coins::new(), 1, None, true, CONTRACT, "kind");
+        assert_eq!(msg.sender.to_string(), SENDER);
+        assert_eq!(
+            msg.execute_msg,
+            json!({ "new": {
+                "1st": 1,
+                "self": true,
+                "sender": CONTRACT,
+                "type": "kind"
+            } })
+        );
+
+        let terra = LCDClient::default();
+        drop(names.query_api::<_, serde_json::Value>(&terra.wasm, "api"));
+    }
 
     #[tokio::test]
     async fn it_works() {
-        let terra = LCDClient::new();
+        let terra = LCDClient::default();
         println!("{:?}", terra.wasm.parameters().await);
     }
 }
@@ -0,0 +1,414 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::auth::Tx;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Attribute {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+/// An event emitted while executing a message, as found in transaction logs.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Event {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl Event {
+    /// Value of the first attribute named `key`.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.as_str())
+    }
+
+    /// Values of all attributes named `key`, in order.
+    pub fn attributes_named<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.attributes
+            .iter()
+            .filter(move |a| a.key == key)
+            .map(|a| a.value.as_str())
+    }
+
+    /// The event with base64 attribute keys and values decoded, as needed
+    /// for the top-level events of chains before Tendermint 0.35.
+    pub fn base64_decoded(&self) -> Result<Event, String> {
+        let decode = |s: &str| {
+            base64::decode(s)
+                .map_err(|e| e.to_string())
+                .and_then(|bytes| String::from_utf8(bytes).map_err(|e| e.to_string()))
+                .map_err(|e| format!("invalid base64 attribute {}: {}", s, e))
+        };
+        Ok(Event {
+            kind: self.kind.clone(),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|a| {
+                    Ok(Attribute {
+                        key: decode(&a.key)?,
+                        value: decode(&a.value)?,
+                    })
+                })
+                .collect::<Result<_, String>>()?,
+        })
+    }
+}
+
+/// Log of a single message in a transaction.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct TxLog {
+    #[serde(default)]
+    pub msg_index: u32,
+    #[serde(default)]
+    pub log: String,
+    #[serde(default)]
+    pub events: Vec<Event>,
+}
+
+impl TxLog {
+    pub fn events_by_type<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a Event> {
+        self.events.iter().filter(move |e| e.kind == kind)
+    }
+}
+
+/// The result of a transaction, as returned by the LCD when broadcasting or
+/// querying it.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TxResponse {
+    #[serde(with = "crate::internal::serde_str")]
+    pub height: u64,
+    pub txhash: String,
+    #[serde(default)]
+    pub codespace: String,
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub data: String,
+    #[serde(default)]
+    pub raw_log: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub logs: Vec<TxLog>,
+    #[serde(default)]
+    pub info: String,
+    #[serde(default, with = "crate::internal::serde_str")]
+    pub gas_wanted: u64,
+    #[serde(default, with = "crate::internal::serde_str")]
+    pub gas_used: u64,
+    /// The transaction in protobuf JSON form; see `decode_tx`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx: Option<Value>,
+    /// Block time; empty until the transaction is included in a block.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// All events of the transaction, including fee and ante handler
+    /// events that `logs` lacks. The only events on newer chains, which
+    /// leave `logs` empty.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub events: Vec<Event>,
+}
+
+impl TxResponse {
+    pub fn is_success(&self) -> bool {
+        self.code == 0
+    }
+
+    /// `Ok(self)` if the transaction succeeded, otherwise why it failed.
+    pub fn into_result(self) -> Result<Self, TxError> {
+        if self.is_success() {
+            Ok(self)
+        } else {
+            Err(TxError::from_response(&self))
+        }
+    }
+
+    /// Events of all messages: from `logs` where the chain still fills
+    /// them, otherwise the top-level `events`.
+    pub fn all_events(&self) -> Box<dyn Iterator<Item = &Event> + '_> {
+        if self.logs.is_empty() {
+            Box::new(self.events.iter())
+        } else {
+            Box::new(self.logs.iter().flat_map(|log| log.events.iter()))
+        }
+    }
+
+    /// The first event of type `kind`, e.g.
+    /// `find_event("wasm").and_then(|e| e.attribute("_contract_address"))`.
+    pub fn find_event(&self, kind: &str) -> Option<&Event> {
+        self.all_events().find(|e| e.kind == kind)
+    }
+
+    pub fn events_by_type<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a Event> {
+        self.all_events().filter(move |e| e.kind == kind)
+    }
+
+    /// Decodes base64 top-level event attributes in place; see
+    /// `Event::base64_decoded`.
+    pub fn decode_base64_events(&mut self) -> Result<(), String> {
+        self.events = self
+            .events
+            .iter()
+            .map(Event::base64_decoded)
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    /// The transaction, with messages converted through the
+    /// [registry](crate::registry).
+    pub fn decode_tx(&self) -> Result<Option<Tx>, String> {
+        self.tx
+            .as_ref()
+            .map(|tx| serde_json::from_value(tx.clone()).map_err(|e| format!("invalid tx: {}", e)))
+            .transpose()
+    }
+}
+
+/// Why a transaction failed, for the errors applications usually handle;
+/// anything else is `Other`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TxError {
+    OutOfGas {
+        gas_wanted: u64,
+        gas_used: u64,
+    },
+    InsufficientFee {
+        log: String,
+    },
+    InsufficientFunds {
+        log: String,
+    },
+    /// `expected` and `got` are parsed from the log when possible.
+    SequenceMismatch {
+        expected: Option<u64>,
+        got: Option<u64>,
+    },
+    Unauthorized {
+        log: String,
+    },
+    /// The node already has the transaction in its mempool.
+    AlreadyInMempool,
+    Other {
+        codespace: String,
+        code: u32,
+        log: String,
+    },
+}
+
+// Codes of the `sdk` codespace, from cosmos-sdk `types/errors`.
+const CODE_UNAUTHORIZED: u32 = 4;
+const CODE_INSUFFICIENT_FUNDS: u32 = 5;
+const CODE_OUT_OF_GAS: u32 = 11;
+const CODE_INSUFFICIENT_FEE: u32 = 13;
+const CODE_TX_IN_MEMPOOL_CACHE: u32 = 19;
+const CODE_WRONG_SEQUENCE: u32 = 32;
+
+impl TxError {
+    pub fn from_response(response: &TxResponse) -> Self {
+        let log = response.raw_log.clone();
+        if response.codespace != "sdk" {
+            return TxError::Other {
+                codespace: response.codespace.clone(),
+                code: response.code,
+                log,
+            };
+        }
+        match response.code {
+            CODE_OUT_OF_GAS => TxError::OutOfGas {
+                gas_wanted: response.gas_wanted,
+                gas_used: response.gas_used,
+            },
+            CODE_INSUFFICIENT_FEE => TxError::InsufficientFee { log },
+            CODE_INSUFFICIENT_FUNDS => TxError::InsufficientFunds { log },
+            CODE_WRONG_SEQUENCE => TxError::SequenceMismatch {
+                expected: number_after(&log, "expected "),
+                got: number_after(&log, "got "),
+            },
+            CODE_UNAUTHORIZED => TxError::Unauthorized { log },
+            CODE_TX_IN_MEMPOOL_CACHE => TxError::AlreadyInMempool,
+            code => TxError::Other {
+                codespace: response.codespace.clone(),
+                code,
+                log,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for TxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TxError::OutOfGas {
+                gas_wanted,
+                gas_used,
+            } => write!(f, "out of gas: wanted {}, used {}", gas_wanted, gas_used),
+            TxError::InsufficientFee { log } => write!(f, "insufficient fee: {}", log),
+            TxError::InsufficientFunds { log } => write!(f, "insufficient funds: {}", log),
+            TxError::SequenceMismatch { expected, got } => match (expected, got) {
+                (Some(expected), Some(got)) => write!(
+                    f,
+                    "account sequence mismatch: expected {}, got {}",
+                    expected, got
+                ),
+                _ => write!(f, "account sequence mismatch"),
+            },
+            TxError::Unauthorized { log } => write!(f, "unauthorized: {}", log),
+            TxError::AlreadyInMempool => write!(f, "transaction already in mempool"),
+            TxError::Other {
+                codespace,
+                code,
+                log,
+            } => write!(f, "{} error {}: {}", codespace, code, log),
+        }
+    }
+}
+
+impl From<TxError> for String {
+    fn from(error: TxError) -> Self {
+        error.to_string()
+    }
+}
+
+fn number_after(log: &str, prefix: &str) -> Option<u64> {
+    let start = log.find(prefix)? + prefix.len();
+    let digits: String = log[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+fn empty_as_none<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.is_empty() => s
+            .parse::<DateTime<Utc>>()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn response(code: u32, raw_log: &str) -> TxResponse {
+        serde_json::from_value(json!({
+            "height": "0",
+            "txhash": "6A5F5A4E3B0FDC3A8E2F3B7E4D0F2C1B9A8E7D6C5B4A39281706F5E4D3C2B1A0",
+            "codespace": "sdk",
+            "code": code,
+            "raw_log": raw_log,
+            "logs": null,
+            "gas_wanted": "200000",
+            "gas_used": "210000",
+            "timestamp": ""
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_parses_tx_response() {
+        let response: TxResponse = serde_json::from_value(json!({
+            "height": "4120",
+            "txhash": "6A5F5A4E3B0FDC3A8E2F3B7E4D0F2C1B9A8E7D6C5B4A39281706F5E4D3C2B1A0",
+            "codespace": "",
+            "code": 0,
+            "data": "",
+            "raw_log": "[]",
+            "logs": [{
+                "msg_index": 0,
+                "log": "",
+                "events": [
+                    { "type": "message", "attributes": [{ "key": "action", "value": "/cosmwasm.wasm.v1.MsgExecuteContract" }] },
+                    { "type": "wasm", "attributes": [
+                        { "key": "_contract_address", "value": "terra1contract" },
+                        { "key": "action", "value": "mint" },
+                        { "key": "action", "value": "transfer" }
+                    ] }
+                ]
+            }],
+            "info": "",
+            "gas_wanted": "200000",
+            "gas_used": "150000",
+            "tx": null,
+            "timestamp": "2022-06-01T10:00:00Z",
+            "events": [
+                { "type": "transfer", "attributes": [{ "key": "YW1vdW50", "value": "MTAwdWx1bmE=", "index": true }] }
+            ]
+        }))
+        .unwrap();
+
+        assert!(response.clone().into_result().is_ok());
+        assert_eq!(response.height, 4120);
+        assert_eq!(
+            response.timestamp.unwrap().to_rfc3339(),
+            "2022-06-01T10:00:00+00:00"
+        );
+        let wasm = response.find_event("wasm").unwrap();
+        assert_eq!(wasm.attribute("_contract_address"), Some("terra1contract"));
+        assert_eq!(
+            wasm.attributes_named("action").collect::<Vec<_>>(),
+            vec!["mint", "transfer"]
+        );
+        assert_eq!(response.decode_tx().unwrap(), None);
+
+        let mut response = response;
+        response.logs.clear();
+        response.decode_base64_events().unwrap();
+        assert_eq!(
+            response.find_event("transfer").unwrap().attribute("amount"),
+            Some("100uluna")
+        );
+    }
+
+    #[test]
+    fn it_maps_error_codes() {
+        let error = response(
+            32,
+            "account sequence mismatch, expected 10, got 9: incorrect account sequence",
+        )
+        .into_result()
+        .unwrap_err();
+        assert_eq!(
+            error,
+            TxError::SequenceMismatch {
+                expected: Some(10),
+                got: Some(9)
+            }
+        );
+        assert_eq!(
+            response(11, "out of gas").into_result().unwrap_err(),
+            TxError::OutOfGas {
+                gas_wanted: 200_000,
+                gas_used: 210_000
+            }
+        );
+        assert!(matches!(
+            response(13, "insufficient fees; got: 1uluna required: 30000uluna")
+                .into_result()
+                .unwrap_err(),
+            TxError::InsufficientFee { .. }
+        ));
+        let other = response(7, "invalid address").into_result().unwrap_err();
+        assert_eq!(String::from(other), "sdk error 7: invalid address");
+    }
+}
@@ -0,0 +1,245 @@
+//! Legacy Amino JSON transactions (`StdTx`), as stored by chains before
+//! the move to protobuf and still returned by some archive nodes, and the
+//! `StdSignDoc` signed in `SignMode::LegacyAminoJson` (e.g. by Ledger).
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::auth::{AuthInfo, Fee, SignMode, SignerInfo, Tx, TxBody};
+use crate::registry::{any_from_amino, any_to_amino};
+use crate::PublicKey;
+
+/// What a `SignMode::LegacyAminoJson` signer signs. `msgs` are in Amino
+/// JSON form.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StdSignDoc {
+    pub account_number: u64,
+    pub chain_id: String,
+    pub fee: Fee,
+    pub memo: String,
+    pub msgs: Vec<Value>,
+    pub sequence: u64,
+    pub timeout_height: u64,
+}
+
+impl StdSignDoc {
+    pub fn new(
+        tx: &Tx,
+        chain_id: &str,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            account_number,
+            chain_id: chain_id.to_string(),
+            fee: tx.auth_info.fee.clone(),
+            memo: tx.body.memo.clone(),
+            msgs: tx
+                .body
+                .messages
+                .iter()
+                .map(any_to_amino)
+                .collect::<Result<_, _>>()?,
+            sequence,
+            timeout_height: tx.body.timeout_height,
+        })
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut fee = Map::new();
+        fee.insert("amount".into(), json!(self.fee.amount));
+        fee.insert("gas".into(), json!(self.fee.gas_limit.to_string()));
+        if let Some(payer) = &self.fee.payer {
+            fee.insert("payer".into(), json!(payer));
+        }
+        if let Some(granter) = &self.fee.granter {
+            fee.insert("granter".into(), json!(granter));
+        }
+        let mut doc = json!({
+            "account_number": self.account_number.to_string(),
+            "chain_id": self.chain_id,
+            "fee": fee,
+            "memo": self.memo,
+            "msgs": self.msgs,
+            "sequence": self.sequence.to_string(),
+        });
+        if self.timeout_height != 0 {
+            doc["timeout_height"] = json!(self.timeout_height.to_string());
+        }
+        doc
+    }
+
+    /// The bytes signed: compact JSON with sorted keys and `<`, `>` and `&`
+    /// escaped, as Go's `encoding/json` writes it.
+    pub fn sign_bytes(&self) -> Vec<u8> {
+        // Objects are `BTreeMap`s, so keys come out sorted.
+        serde_json::to_string(&self.to_json())
+            .expect("sign doc serializes")
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .replace('&', "\\u0026")
+            .into_bytes()
+    }
+}
+
+#[derive(Deserialize)]
+struct StdTxJson {
+    #[serde(default)]
+    msg: Vec<Value>,
+    fee: Fee,
+    #[serde(default)]
+    signatures: Option<Vec<StdSignatureJson>>,
+    #[serde(default)]
+    memo: String,
+    #[serde(default, with = "crate::internal::serde_str")]
+    timeout_height: u64,
+}
+
+#[derive(Deserialize)]
+struct StdSignatureJson {
+    pub_key: Option<PublicKey>,
+    signature: String,
+    // Only present on the oldest chains.
+    #[serde(default, with = "crate::internal::serde_str")]
+    sequence: u64,
+}
+
+impl Tx {
+    /// Decodes an Amino JSON `StdTx`, with or without its
+    /// `{"type": "core/StdTx", "value": ..}` wrapper. Messages are converted
+    /// through the [registry](crate::registry); signer infos get
+    /// `SignMode::LegacyAminoJson`.
+    pub fn from_amino_json(json: &Value) -> Result<Self, String> {
+        let json = match json.get("type").and_then(Value::as_str) {
+            Some(_) => json.get("value").ok_or("StdTx has no value")?,
+            None => json,
+        };
+        let std_tx = StdTxJson::deserialize(json).map_err(|e| format!("invalid StdTx: {}", e))?;
+
+        let messages = std_tx
+            .msg
+            .iter()
+            .map(any_from_amino)
+            .collect::<Result<_, _>>()?;
+        let mut signer_infos = vec![];
+        let mut signatures = vec![];
+        for signature in std_tx.signatures.unwrap_or_default() {
+            signer_infos.push(SignerInfo {
+                public_key: signature.pub_key,
+                mode_info: SignMode::LegacyAminoJson.into(),
+                sequence: signature.sequence,
+            });
+            signatures.push(
+                base64::decode(&signature.signature)
+                    .map_err(|e| format!("invalid signature: {}", e))?,
+            );
+        }
+        Ok(Tx {
+            body: TxBody {
+                messages,
+                memo: std_tx.memo,
+                timeout_height: std_tx.timeout_height,
+
+                ..Default::default()
+            },
+            auth_info: AuthInfo {
+                signer_infos,
+                fee: std_tx.fee,
+            },
+            signatures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bank::MsgSend;
+    use crate::Msg;
+
+    #[test]
+    fn it_decodes_std_tx() {
+        let tx = Tx::from_amino_json(&json!({
+            "type": "core/StdTx",
+            "value": {
+                "msg": [{
+                    "type": "bank/MsgSend",
+                    "value": {
+                        "from_address": "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+                        "to_address": "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+                        "amount": [{ "denom": "uluna", "amount": "1000" }]
+                    }
+                }],
+                "fee": {
+                    "amount": [{ "denom": "uusd", "amount": "4500" }],
+                    "gas": "300000"
+                },
+                "signatures": [{
+                    "pub_key": {
+                        "type": "tendermint/PubKeySecp256k1",
+                        "value": "A0vSnr5QydaBJXfvbaH1tdiGMptp0TAm7o0chPeK5ujT"
+                    },
+                    "signature": "AQID"
+                }],
+                "memo": "legacy",
+                "timeout_height": "0"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(tx.body.memo, "legacy");
+        assert_eq!(tx.auth_info.fee, Fee::new(300_000, "4500uusd"));
+        assert_eq!(
+            tx.auth_info.signer_infos[0].mode_info,
+            SignMode::LegacyAminoJson.into()
+        );
+        assert_eq!(tx.signatures, vec![vec![1, 2, 3]]);
+        let send = MsgSend::from_any(&tx.body.messages[0]).unwrap();
+        assert_eq!(send.amount, crate::Coins::from("1000uluna"));
+
+        // Unwrapped, unsigned.
+        let unsigned = Tx::from_amino_json(&json!({
+            "msg": [],
+            "fee": { "amount": [], "gas": "0" },
+            "signatures": null,
+            "memo": ""
+        }))
+        .unwrap();
+        assert!(unsigned.signatures.is_empty());
+    }
+
+    #[test]
+    fn it_writes_sorted_sign_bytes() {
+        let send = MsgSend::new(
+            "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+            "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+            crate::Coins::from("1uluna"),
+        );
+        let tx = Tx {
+            body: TxBody {
+                messages: vec![send.to_any().unwrap()],
+                memo: "<&>".into(),
+                timeout_height: 0,
+                ..Default::default()
+            },
+            auth_info: AuthInfo {
+                signer_infos: vec![],
+                fee: Fee::new(100_000, "2uluna"),
+            },
+            signatures: vec![],
+        };
+        let doc = StdSignDoc::new(&tx, "columbus-5", 7, 3).unwrap();
+        assert_eq!(
+            String::from_utf8(doc.sign_bytes()).unwrap(),
+            concat!(
+                r#"{"account_number":"7","chain_id":"columbus-5","#,
+                r#""fee":{"amount":[{"amount":"2","denom":"uluna"}],"gas":"100000"},"#,
+                r#""memo":"\u003c\u0026\u003e","#,
+                r#""msgs":[{"type":"bank/MsgSend","value":{"amount":[{"amount":"1","denom":"uluna"}],"#,
+                r#""from_address":"terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4","#,
+                r#""to_address":"terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw"}}],"#,
+                r#""sequence":"3"}"#
+            )
+        );
+    }
+}
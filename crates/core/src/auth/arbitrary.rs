@@ -0,0 +1,84 @@
+//! Signatures over arbitrary data (ADR-036), e.g. for login challenges or
+//! off-chain orders. The data is wrapped in a `sign/MsgSignData` message in
+//! an otherwise empty `StdSignDoc`, so it can never be replayed as a
+//! transaction.
+
+use serde_json::json;
+
+use crate::auth::{Fee, StdSignDoc};
+use crate::{AccAddress, Coins, PublicKey};
+
+pub const MSG_SIGN_DATA_AMINO_TYPE: &str = "sign/MsgSignData";
+
+/// What `signer` signs to sign `data`.
+pub fn arbitrary_sign_doc(signer: &AccAddress, data: &[u8]) -> StdSignDoc {
+    StdSignDoc {
+        account_number: 0,
+        chain_id: String::new(),
+        fee: Fee::new(0, Coins::new()),
+        memo: String::new(),
+        msgs: vec![json!({
+            "type": MSG_SIGN_DATA_AMINO_TYPE,
+            "value": {
+                "data": base64::encode(data),
+                "signer": signer,
+            },
+        })],
+        sequence: 0,
+        timeout_height: 0,
+    }
+}
+
+/// Checks that `signature` was made over `data` by `signer`, whose key is
+/// `public_key`.
+pub fn verify_arbitrary(
+    signer: &AccAddress,
+    public_key: &PublicKey,
+    data: &[u8],
+    signature: &[u8],
+) -> Result<(), String> {
+    if &public_key.acc_address() != signer {
+        return Err(format!("public key does not belong to {}", signer));
+    }
+    public_key.verify(&arbitrary_sign_doc(signer, data).sign_bytes(), signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{PrivateKey, Signer};
+
+    #[test]
+    fn it_signs_and_verifies_arbitrary_data() {
+        let key = PrivateKey::from_hex(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )
+        .unwrap();
+        let signer = key.acc_address();
+        assert_eq!(
+            String::from_utf8(arbitrary_sign_doc(&signer, b"hello").sign_bytes()).unwrap(),
+            format!(
+                concat!(
+                    r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"#,
+                    r#""memo":"","msgs":[{{"type":"sign/MsgSignData","#,
+                    r#""value":{{"data":"aGVsbG8=","signer":"{}"}}}}],"sequence":"0"}}"#
+                ),
+                signer
+            )
+        );
+
+        let signature = key.sign_arbitrary(b"hello").unwrap();
+        assert!(verify_arbitrary(&signer, &key.public_key(), b"hello", &signature).is_ok());
+        assert!(verify_arbitrary(&signer, &key.public_key(), b"hellO", &signature).is_err());
+
+        let other = PrivateKey::random();
+        assert!(verify_arbitrary(&signer, &other.public_key(), b"hello", &signature).is_err());
+        assert!(verify_arbitrary(
+            &other.acc_address(),
+            &other.public_key(),
+            b"hello",
+            &signature
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,369 @@
+use crate::auth::{AuthInfo, Fee, SignDoc, SignMode, Signer, SignerInfo, Tx, TxBody, TxRaw};
+use crate::treasury::TaxCalculator;
+use crate::{AccAddress, Any, Dec, DecCoin, Msg, ProtoMsg, PublicKey};
+
+/// Longest memo the chain accepts, in characters.
+pub const MAX_MEMO_CHARACTERS: usize = 256;
+
+/// An account signing a transaction, with the number and sequence the
+/// chain currently has for it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SignerData {
+    pub public_key: PublicKey,
+    pub account_number: u64,
+    pub sequence: u64,
+}
+
+impl SignerData {
+    pub fn new(public_key: PublicKey, account_number: u64, sequence: u64) -> Self {
+        Self {
+            public_key,
+            account_number,
+            sequence,
+        }
+    }
+}
+
+/// `TxBuilder` state before a fee has been chosen.
+#[derive(Debug, Clone, Default)]
+pub struct NoFee;
+
+/// Builds a transaction step by step. A fee must be set (`fee` or
+/// `estimated_fee`) before the transaction can be built or signed:
+///
+/// ```ignore
+/// let tx = TxBuilder::new()
+///     .msg(&MsgSend::new(from, to, "1000000uluna"))?
+///     .memo("rent")
+///     .fee(Fee::new(100_000, "15000uluna"))
+///     .signer(SignerData::new(key.public_key(), 12, 7))
+///     .sign("columbus-5", &[&key])?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TxBuilder<F = NoFee> {
+    messages: Vec<Any>,
+    memo: String,
+    timeout_height: u64,
+    signers: Vec<SignerData>,
+    payer: Option<AccAddress>,
+    granter: Option<AccAddress>,
+    fee: F,
+}
+
+impl TxBuilder<NoFee> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fee(self, fee: Fee) -> TxBuilder<Fee> {
+        TxBuilder {
+            messages: self.messages,
+            memo: self.memo,
+            timeout_height: self.timeout_height,
+            signers: self.signers,
+            payer: self.payer,
+            granter: self.granter,
+            fee,
+        }
+    }
+
+    /// Sets the fee from simulated gas usage; see `Fee::estimate`.
+    pub fn estimated_fee(
+        self,
+        gas_used: u64,
+        adjustment: Dec,
+        gas_price: &DecCoin,
+    ) -> Result<TxBuilder<Fee>, String> {
+        Ok(self.fee(Fee::estimate(gas_used, adjustment, gas_price)?))
+    }
+}
+
+impl<F> TxBuilder<F> {
+    /// Fails if the message cannot be packed; see `ProtoMsg::to_proto`.
+    pub fn msg<M: Msg>(mut self, msg: &M) -> Result<Self, String> {
+        self.messages.push(msg.to_any()?);
+        Ok(self)
+    }
+
+    /// Adds an already packed message, e.g. one decoded from another
+    /// transaction.
+    pub fn any(mut self, msg: Any) -> Self {
+        self.messages.push(msg);
+        self
+    }
+
+    pub fn msgs(mut self, msgs: impl IntoIterator<Item = Any>) -> Self {
+        self.messages.extend(msgs);
+        self
+    }
+
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+
+    pub fn timeout_height(mut self, height: u64) -> Self {
+        self.timeout_height = height;
+        self
+    }
+
+    /// Adds a signer. The first signer pays the fee unless a payer is set.
+    pub fn signer(mut self, signer: SignerData) -> Self {
+        self.signers.push(signer);
+        self
+    }
+
+    /// Sets the fee payer, who must also be one of the signers.
+    pub fn payer(mut self, payer: impl Into<AccAddress>) -> Self {
+        self.payer = Some(payer.into());
+        self
+    }
+
+    /// Pays the fee from a fee allowance granted by `granter`.
+    pub fn granter(mut self, granter: impl Into<AccAddress>) -> Self {
+        self.granter = Some(granter.into());
+        self
+    }
+}
+
+impl TxBuilder<Fee> {
+    /// Adds the stability tax due on the messages added so far to the fee.
+    pub fn tax(mut self, calculator: &TaxCalculator) -> Result<Self, String> {
+        let tax = calculator.compute(&self.messages)?;
+        self.fee.amount = self
+            .fee
+            .amount
+            .checked_add(&tax)
+            .ok_or("fee amount overflows")?;
+        Ok(self)
+    }
+
+    /// The transaction without signatures, e.g. for offline signing.
+    pub fn build(self) -> Result<UnsignedTx, String> {
+        if self.messages.is_empty() {
+            return Err("transaction has no messages".into());
+        }
+        if self.signers.is_empty() {
+            return Err("transaction has no signers".into());
+        }
+        if self.memo.chars().count() > MAX_MEMO_CHARACTERS {
+            return Err(format!(
+                "memo is longer than {} characters",
+                MAX_MEMO_CHARACTERS
+            ));
+        }
+        let mut fee = self.fee;
+        if let Some(payer) = self.payer {
+            if !self
+                .signers
+                .iter()
+                .any(|s| s.public_key.acc_address() == payer)
+            {
+                return Err(format!("fee payer {} is not a signer", payer));
+            }
+            fee.payer = Some(payer);
+        }
+        fee.granter = self.granter.or(fee.granter);
+
+        let tx = Tx {
+            body: TxBody {
+                messages: self.messages,
+                memo: self.memo,
+                timeout_height: self.timeout_height,
+
+                ..Default::default()
+            },
+            auth_info: AuthInfo {
+                signer_infos: self
+                    .signers
+                    .iter()
+                    .map(|signer| SignerInfo {
+                        public_key: Some(signer.public_key.clone()),
+                        mode_info: SignMode::Direct.into(),
+                        sequence: signer.sequence,
+                    })
+                    .collect(),
+                fee,
+            },
+            signatures: vec![],
+        };
+        Ok(UnsignedTx {
+            tx,
+            account_numbers: self.signers.iter().map(|s| s.account_number).collect(),
+        })
+    }
+
+    /// Builds the transaction and signs it with one key per signer, in the
+    /// order the signers were added.
+    pub fn sign(self, chain_id: &str, keys: &[&dyn Signer]) -> Result<TxRaw, String> {
+        self.build()?.sign(chain_id, keys)
+    }
+}
+
+/// A built transaction awaiting signatures. Only a `TxRaw` can be
+/// broadcast, and one is obtained from this by signing.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnsignedTx {
+    tx: Tx,
+    account_numbers: Vec<u64>,
+}
+
+impl UnsignedTx {
+    /// Wraps a transaction built elsewhere, with the account number of each
+    /// of its signers. Any signatures it carries are dropped.
+    pub fn new(tx: Tx, account_numbers: Vec<u64>) -> Result<Self, String> {
+        if tx.auth_info.signer_infos.len() != account_numbers.len() {
+            return Err(format!(
+                "transaction has {} signers but {} account numbers were given",
+                tx.auth_info.signer_infos.len(),
+                account_numbers.len()
+            ));
+        }
+        Ok(Self {
+            tx: Tx {
+                signatures: vec![],
+                ..tx
+            },
+            account_numbers,
+        })
+    }
+
+    pub fn tx(&self) -> &Tx {
+        &self.tx
+    }
+
+    pub fn account_numbers(&self) -> &[u64] {
+        &self.account_numbers
+    }
+
+    /// What the signer at `index` signs.
+    pub fn sign_doc(&self, chain_id: &str, index: usize) -> Result<SignDoc, String> {
+        let account_number = *self
+            .account_numbers
+            .get(index)
+            .ok_or_else(|| format!("no signer at index {}", index))?;
+        Ok(SignDoc {
+            body_bytes: self.tx.body.encode_proto()?,
+            auth_info_bytes: self.tx.auth_info.encode_proto()?,
+            chain_id: chain_id.to_string(),
+            account_number,
+        })
+    }
+
+    pub fn sign(self, chain_id: &str, keys: &[&dyn Signer]) -> Result<TxRaw, String> {
+        if keys.len() != self.account_numbers.len() {
+            return Err(format!(
+                "expected {} keys, got {}",
+                self.account_numbers.len(),
+                keys.len()
+            ));
+        }
+        let mut signatures = vec![];
+        for (index, key) in keys.iter().enumerate() {
+            let info = &self.tx.auth_info.signer_infos[index];
+            if info.public_key.as_ref() != Some(&key.public_key()) {
+                return Err(format!("key {} does not match signer {}", index, index));
+            }
+            let sign_doc = self.sign_doc(chain_id, index)?;
+            signatures.push(key.sign(&sign_doc.encode_proto()?)?);
+        }
+        self.with_signatures(signatures)
+    }
+
+    /// Attaches signatures produced elsewhere, one per signer.
+    pub fn with_signatures(self, signatures: Vec<Vec<u8>>) -> Result<TxRaw, String> {
+        if signatures.len() != self.account_numbers.len() {
+            return Err(format!(
+                "expected {} signatures, got {}",
+                self.account_numbers.len(),
+                signatures.len()
+            ));
+        }
+        Tx {
+            signatures,
+            ..self.tx
+        }
+        .to_raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::PrivateKey;
+    use crate::bank::MsgSend;
+    use crate::Coins;
+    use k256::ecdsa::signature::Verifier;
+    use k256::ecdsa::{Signature, VerifyingKey};
+
+    fn key(n: u8) -> PrivateKey {
+        let mut bytes = [0u8; 32];
+        bytes[31] = n;
+        PrivateKey::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn it_builds_and_signs() {
+        let (alice, bob) = (key(1), key(2));
+        let send = MsgSend::new(alice.acc_address(), bob.acc_address(), "1000000uusd");
+        let calculator = TaxCalculator::new(Dec::new(5 * 10i128.pow(15)), vec![]);
+
+        let builder = TxBuilder::new()
+            .msg(&send)
+            .unwrap()
+            .memo("hello")
+            .timeout_height(100)
+            .signer(SignerData::new(alice.public_key(), 12, 3))
+            .estimated_fee(
+                100_000,
+                Dec::one(),
+                &DecCoin::new("uusd", Dec::new(15 * 10i128.pow(16))),
+            )
+            .unwrap()
+            .tax(&calculator)
+            .unwrap();
+        let unsigned = builder.clone().build().unwrap();
+        assert_eq!(unsigned.tx().auth_info.fee.amount, Coins::from("20000uusd"));
+        assert_eq!(unsigned.tx().body.timeout_height, 100);
+
+        let raw = builder.sign("columbus-5", &[&alice]).unwrap();
+        let tx = raw.to_tx().unwrap();
+        assert_eq!(tx.body.messages, vec![send.to_any().unwrap()]);
+        assert_eq!(tx.auth_info.signer_infos[0].sequence, 3);
+
+        let sign_doc = unsigned.sign_doc("columbus-5", 0).unwrap();
+        let verifying_key =
+            VerifyingKey::from_sec1_bytes(alice.public_key().key().unwrap()).unwrap();
+        let signature = Signature::from_slice(&raw.signatures[0]).unwrap();
+        assert!(verifying_key
+            .verify(&sign_doc.encode_proto().unwrap(), &signature)
+            .is_ok());
+
+        assert!(unsigned.clone().sign("columbus-5", &[&bob]).is_err());
+        assert!(unsigned.with_signatures(vec![]).is_err());
+    }
+
+    #[test]
+    fn it_rejects_invalid_txs() {
+        let alice = key(1);
+        let fee = Fee::new(100_000, "15000uluna");
+        assert!(TxBuilder::new().fee(fee.clone()).build().is_err());
+
+        let send = MsgSend::new(alice.acc_address(), key(2).acc_address(), "1uluna");
+        let builder = TxBuilder::new()
+            .msg(&send)
+            .unwrap()
+            .fee(fee)
+            .signer(SignerData::new(alice.public_key(), 0, 0));
+        assert!(builder.clone().payer(key(2).acc_address()).build().is_err());
+        assert!(builder.clone().memo("x".repeat(257)).build().is_err());
+
+        let tx = builder
+            .payer(alice.acc_address())
+            .granter(key(3).acc_address())
+            .build()
+            .unwrap();
+        assert_eq!(tx.tx().auth_info.fee.payer, Some(alice.acc_address()));
+        assert_eq!(tx.tx().auth_info.fee.granter, Some(key(3).acc_address()));
+    }
+}
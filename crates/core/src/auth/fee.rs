@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+use crate::auth::proto;
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::wasm::json::{optional_address, optional_address_to_string};
+use crate::{AccAddress, Coin, Coins, Dec, DecCoin, ProtoMsg};
+
+/// The fee and gas limit of a transaction.
+///
+/// With `granter` set, the fee is deducted from a fee allowance the granter
+/// gave to the fee payer (see `crate::feegrant`) instead of from the payer.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct Fee {
+    pub amount: Coins,
+    #[serde(alias = "gas", with = "crate::internal::serde_str")]
+    pub gas_limit: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payer: Option<AccAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub granter: Option<AccAddress>,
+}
+
+impl Fee {
+    pub fn new(gas_limit: u64, amount: impl Into<Coins>) -> Self {
+        Self {
+            amount: amount.into(),
+            gas_limit,
+            payer: None,
+            granter: None,
+        }
+    }
+
+    /// A fee for a transaction that used `gas_used` gas in simulation:
+    /// the gas limit is `gas_used * adjustment` and the amount pays
+    /// `gas_price` per unit of it, both rounded up. Fails if either
+    /// overflows.
+    pub fn estimate(gas_used: u64, adjustment: Dec, gas_price: &DecCoin) -> Result<Self, String> {
+        let gas_limit = ceil_mul(gas_used as u128, adjustment)
+            .and_then(|gas_limit| u64::try_from(gas_limit).ok())
+            .ok_or("gas limit overflows")?;
+        let amount = ceil_mul(gas_limit as u128, gas_price.amount).ok_or("fee amount overflows")?;
+        Ok(Self::new(
+            gas_limit,
+            Coins::from(vec![Coin::new(gas_price.denom.clone(), amount)]),
+        ))
+    }
+
+    pub fn payer(mut self, payer: impl Into<AccAddress>) -> Self {
+        self.payer = Some(payer.into());
+        self
+    }
+
+    pub fn granter(mut self, granter: impl Into<AccAddress>) -> Self {
+        self.granter = Some(granter.into());
+        self
+    }
+}
+
+fn ceil_mul(value: u128, dec: Dec) -> Option<u128> {
+    let one = Dec::one().raw() as u128;
+    let product = value.checked_mul(dec.raw().max(0) as u128)?;
+    Some(product / one + u128::from(!product.is_multiple_of(one)))
+}
+
+impl ProtoMsg for Fee {
+    type Proto = proto::Fee;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::Fee {
+            amount: coins_to_proto(&self.amount),
+            gas_limit: self.gas_limit,
+            payer: optional_address_to_string(&self.payer),
+            granter: optional_address_to_string(&self.granter),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            amount: coins_from_proto(proto.amount)?,
+            gas_limit: proto.gas_limit,
+            payer: optional_address(proto.payer),
+            granter: optional_address(proto.granter),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_sets_fee_granter() {
+        let fee =
+            Fee::new(200000, "30000uluna").granter("terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4");
+        assert_eq!(
+            serde_json::to_value(&fee).unwrap(),
+            json!({
+                "amount": [{ "denom": "uluna", "amount": "30000" }],
+                "gas_limit": "200000",
+                "granter": "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4"
+            })
+        );
+        assert_eq!(
+            Fee::decode_proto(&fee.encode_proto().unwrap()).unwrap(),
+            fee
+        );
+        assert_eq!(
+            Fee::from_proto(Fee::new(1, Coins::new()).to_proto().unwrap())
+                .unwrap()
+                .granter,
+            None
+        );
+
+        let fee = Fee::estimate(
+            123_456,
+            Dec::new(14 * 10i128.pow(17)),
+            &DecCoin::new("uluna", Dec::new(28_325 * 10i128.pow(15))),
+        )
+        .unwrap();
+        assert_eq!(fee, Fee::new(172_839, "4895665uluna"));
+
+        let price = DecCoin::new("uluna", Dec::one());
+        assert!(Fee::estimate(u64::MAX, Dec::new(2 * 10i128.pow(18)), &price).is_err());
+        let price = DecCoin::new("uluna", Dec::new(i128::MAX));
+        assert!(Fee::estimate(u64::MAX, Dec::one(), &price).is_err());
+    }
+}
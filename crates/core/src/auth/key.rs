@@ -0,0 +1,106 @@
+use k256::ecdsa::signature::Signer as _;
+use k256::ecdsa::{Signature, SigningKey};
+
+use crate::auth::arbitrary_sign_doc;
+use crate::{AccAddress, PublicKey};
+
+/// Something that can sign transactions for an account: a `PrivateKey`
+/// held in memory, or e.g. a hardware wallet or remote signer.
+pub trait Signer {
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs `message`, the serialized sign doc. secp256k1 signers hash it
+    /// with SHA-256 and return the 64-byte `r || s` signature.
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, String>;
+
+    fn acc_address(&self) -> AccAddress {
+        self.public_key().acc_address()
+    }
+
+    /// Signs arbitrary data as described in ADR-036; see
+    /// [`verify_arbitrary`](crate::auth::verify_arbitrary).
+    fn sign_arbitrary(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        self.sign(&arbitrary_sign_doc(&self.acc_address(), data).sign_bytes())
+    }
+}
+
+/// A secp256k1 account key.
+#[derive(Clone)]
+pub struct PrivateKey(SigningKey);
+
+impl PrivateKey {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        SigningKey::from_slice(bytes)
+            .map(Self)
+            .map_err(|e| format!("invalid private key: {}", e))
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        Self::from_bytes(&hex::decode(hex).map_err(|e| format!("invalid private key: {}", e))?)
+    }
+
+    pub fn random() -> Self {
+        Self(SigningKey::random(&mut rand::thread_rng()))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+}
+
+impl Signer for PrivateKey {
+    /// The compressed public key.
+    fn public_key(&self) -> PublicKey {
+        let point = self.0.verifying_key().to_encoded_point(true);
+        PublicKey::Secp256k1(point.as_bytes().to_vec())
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        let signature: Signature = self.0.sign(message);
+        // The chain rejects signatures with a high S value.
+        let signature = signature.normalize_s().unwrap_or(signature);
+        Ok(signature.to_bytes().to_vec())
+    }
+}
+
+impl std::fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "PrivateKey({})", self.acc_address())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::signature::Verifier;
+    use k256::ecdsa::VerifyingKey;
+
+    #[test]
+    fn it_signs_with_low_s() {
+        let key = PrivateKey::from_hex(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(key.public_key().address_bytes()),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert_eq!(
+            PrivateKey::from_bytes(&key.to_bytes())
+                .unwrap()
+                .public_key(),
+            key.public_key()
+        );
+        assert_eq!(key.public_key().key().unwrap().len(), 33);
+        assert!(key.acc_address().is_valid());
+
+        let signature = key.sign(b"sign doc").unwrap();
+        assert_eq!(signature.len(), 64);
+        assert_eq!(signature, key.sign(b"sign doc").unwrap());
+        let signature = Signature::from_slice(&signature).unwrap();
+        assert!(signature.normalize_s().is_none());
+        let verifying_key = VerifyingKey::from_sec1_bytes(key.public_key().key().unwrap()).unwrap();
+        assert!(verifying_key.verify(b"sign doc", &signature).is_ok());
+        assert!(PrivateKey::from_hex("00").is_err());
+    }
+}
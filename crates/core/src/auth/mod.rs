@@ -1 +1,27 @@
+pub mod abci;
+pub use abci::*;
 
+pub mod amino;
+pub use amino::*;
+
+pub mod arbitrary;
+pub use arbitrary::*;
+
+pub mod builder;
+pub use builder::*;
+
+pub mod fee;
+pub use fee::*;
+
+pub mod key;
+pub use key::*;
+
+pub mod offline;
+pub use offline::*;
+
+pub mod tx;
+pub use tx::*;
+
+pub mod verify;
+
+pub mod proto;
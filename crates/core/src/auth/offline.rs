@@ -0,0 +1,487 @@
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{SignMode, Signer, SignerInfo, Tx, TxRaw, UnsignedTx};
+use crate::{AccAddress, ProtoMsg, PublicKey};
+
+/// What `terrad tx sign --offline` takes as `--account-number` and
+/// `--sequence` for one signer.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct SignerMetadata {
+    pub address: AccAddress,
+    #[serde(with = "crate::internal::serde_str")]
+    pub account_number: u64,
+    #[serde(with = "crate::internal::serde_str")]
+    pub sequence: u64,
+    /// Needed for transactions without signer infos, unless the signer is
+    /// the one signing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<PublicKey>,
+}
+
+/// An unsigned transaction together with everything needed to sign it on a
+/// machine without network access.
+///
+/// `tx` is in the `--generate-only` format, so it can also be extracted and
+/// signed with `terrad tx sign --offline` using `chain_id` and `signers`.
+/// Signatures made for it, by [`OfflineTx::sign`] or `terrad`, are combined
+/// into a broadcastable `TxRaw` with [`OfflineTx::merge`].
+///
+/// `tx` may also be raw `--generate-only` output, which has no signer
+/// infos; they are then built from `signers`.
+///
+/// Signatures are taken with the sign mode of the signed copy they come
+/// from, so `terrad tx sign --sign-mode amino-json` (e.g. with a Ledger)
+/// works too. A direct mode signature covers every signer info, so with
+/// several signers only those made with [`OfflineTx::sign`] combine;
+/// `terrad` signers should use amino-json mode instead.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct OfflineTx {
+    pub chain_id: String,
+    pub signers: Vec<SignerMetadata>,
+    pub tx: Tx,
+}
+
+impl OfflineTx {
+    pub fn new(unsigned: &UnsignedTx, chain_id: impl Into<String>) -> Result<Self, String> {
+        let tx = unsigned.tx().clone();
+        let signers = tx
+            .auth_info
+            .signer_infos
+            .iter()
+            .zip(unsigned.account_numbers())
+            .map(|(info, &account_number)| {
+                let public_key = info
+                    .public_key
+                    .as_ref()
+                    .ok_or("signer info has no public key")?;
+                Ok(SignerMetadata {
+                    address: public_key.acc_address(),
+                    account_number,
+                    sequence: info.sequence,
+                    public_key: Some(public_key.clone()),
+                })
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Self {
+            chain_id: chain_id.into(),
+            signers,
+            tx,
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("invalid offline tx: {}", e))
+    }
+
+    /// The transaction to sign, after checking that `signers` agrees with
+    /// its signer infos.
+    pub fn unsigned(&self) -> Result<UnsignedTx, String> {
+        self.unsigned_with_keys(&[])
+    }
+
+    /// Like `unsigned`, with `keys` filling in public keys missing from
+    /// `signers` when the transaction has no signer infos.
+    fn unsigned_with_keys(&self, keys: &[PublicKey]) -> Result<UnsignedTx, String> {
+        let mut tx = self.tx.clone();
+        tx.auth_info.signer_infos = self.signer_infos(keys)?;
+        UnsignedTx::new(tx, self.signers.iter().map(|s| s.account_number).collect())
+    }
+
+    fn signer_infos(&self, keys: &[PublicKey]) -> Result<Vec<SignerInfo>, String> {
+        let infos = &self.tx.auth_info.signer_infos;
+        if infos.is_empty() {
+            return self
+                .signers
+                .iter()
+                .map(|signer| {
+                    let public_key = signer
+                        .public_key
+                        .clone()
+                        .or_else(|| {
+                            keys.iter()
+                                .find(|key| key.acc_address() == signer.address)
+                                .cloned()
+                        })
+                        .ok_or_else(|| format!("public key of {} is unknown", signer.address))?;
+                    Ok(SignerInfo {
+                        public_key: Some(public_key),
+                        mode_info: SignMode::Direct.into(),
+                        sequence: signer.sequence,
+                    })
+                })
+                .collect();
+        }
+        if infos.len() != self.signers.len() {
+            return Err(format!(
+                "transaction has {} signers but metadata for {}",
+                infos.len(),
+                self.signers.len()
+            ));
+        }
+        for (index, (info, signer)) in infos.iter().zip(&self.signers).enumerate() {
+            let address = info.public_key.as_ref().map(|key| key.acc_address());
+            if address.as_ref() != Some(&signer.address) || info.sequence != signer.sequence {
+                return Err(format!("metadata of signer {} does not match", index));
+            }
+        }
+        Ok(infos.clone())
+    }
+
+    /// Signs as whichever signer `key` belongs to. The result carries only
+    /// that signature, with empty placeholders for the other signers.
+    pub fn sign(&self, key: &dyn Signer) -> Result<Tx, String> {
+        let public_key = key.public_key();
+        let unsigned = self.unsigned_with_keys(std::slice::from_ref(&public_key))?;
+        let index = unsigned
+            .tx()
+            .auth_info
+            .signer_infos
+            .iter()
+            .position(|info| info.public_key.as_ref() == Some(&public_key))
+            .ok_or_else(|| format!("{} is not a signer", public_key.acc_address()))?;
+
+        let sign_doc = unsigned.sign_doc(&self.chain_id, index)?;
+        let mut signatures = vec![vec![]; self.signers.len()];
+        signatures[index] = key.sign(&sign_doc.encode_proto()?)?;
+        Ok(Tx {
+            signatures,
+            ..unsigned.tx().clone()
+        })
+    }
+
+    /// Combines signed copies of the transaction into one with a signature
+    /// from every signer, and checks the signatures.
+    ///
+    /// A signed copy must have the same body and fee. Its signer infos may
+    /// differ, as `terrad` replaces or appends to them, but each must
+    /// belong to a signer and have that signer's sequence.
+    pub fn merge(&self, signed: &[Tx]) -> Result<TxRaw, String> {
+        let keys = signed
+            .iter()
+            .flat_map(|tx| &tx.auth_info.signer_infos)
+            .filter_map(|info| info.public_key.clone())
+            .collect::<Vec<_>>();
+        let unsigned = self.unsigned_with_keys(&keys)?;
+        let mut signer_infos = unsigned.tx().auth_info.signer_infos.clone();
+        let mut signatures = vec![vec![]; self.signers.len()];
+        for tx in signed {
+            if tx.body != unsigned.tx().body || tx.auth_info.fee != unsigned.tx().auth_info.fee {
+                return Err("signed transaction differs from the one to sign".into());
+            }
+            let infos = &tx.auth_info.signer_infos;
+            if tx.signatures.len() != infos.len() {
+                return Err(format!(
+                    "signed transaction has {} signer infos but {} signatures; \
+                     sign with `terrad tx sign --overwrite`",
+                    infos.len(),
+                    tx.signatures.len()
+                ));
+            }
+            for (info, signature) in infos.iter().zip(&tx.signatures) {
+                let index = self.signer_index(info)?;
+                if signature.is_empty() {
+                    continue;
+                }
+                if !signatures[index].is_empty() && &signatures[index] != signature {
+                    return Err(format!("conflicting signatures for signer {}", index));
+                }
+                signatures[index] = signature.clone();
+                signer_infos[index].mode_info = info.mode_info.clone();
+            }
+        }
+        if let Some(index) = signatures.iter().position(Vec::is_empty) {
+            return Err(format!(
+                "missing signature of {}",
+                self.signers[index].address
+            ));
+        }
+
+        let mut tx = unsigned.tx().clone();
+        tx.auth_info.signer_infos = signer_infos;
+        tx.signatures = signatures;
+        let raw = tx.to_raw()?;
+        raw.verify_signatures(&self.chain_id, unsigned.account_numbers())?;
+        Ok(raw)
+    }
+
+    /// The signer a signed copy's signer info belongs to.
+    fn signer_index(&self, info: &SignerInfo) -> Result<usize, String> {
+        let address = info
+            .public_key
+            .as_ref()
+            .map(PublicKey::acc_address)
+            .ok_or("signed transaction has a signer info without public key")?;
+        let index = self
+            .signers
+            .iter()
+            .position(|signer| signer.address == address)
+            .ok_or_else(|| format!("{} is not a signer", address))?;
+        if info.sequence != self.signers[index].sequence {
+            return Err(format!(
+                "signature of {} is for sequence {}, expected {}",
+                address, info.sequence, self.signers[index].sequence
+            ));
+        }
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{Fee, PrivateKey, SignerData, StdSignDoc, TxBuilder};
+    use crate::bank::MsgSend;
+    use serde_json::{json, Value};
+
+    fn key(n: u8) -> PrivateKey {
+        let mut bytes = [0u8; 32];
+        bytes[31] = n;
+        PrivateKey::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn it_signs_offline_and_merges() {
+        let (alice, bob) = (key(1), key(2));
+        let builder = TxBuilder::new()
+            .msg(&MsgSend::new(
+                alice.acc_address(),
+                bob.acc_address(),
+                "1000uluna",
+            ))
+            .unwrap()
+            .msg(&MsgSend::new(
+                bob.acc_address(),
+                alice.acc_address(),
+                "2000uluna",
+            ))
+            .unwrap()
+            .fee(Fee::new(200_000, "30000uluna"))
+            .signer(SignerData::new(alice.public_key(), 1, 4))
+            .signer(SignerData::new(bob.public_key(), 2, 0));
+
+        let offline = OfflineTx::new(&builder.clone().build().unwrap(), "pisco-1").unwrap();
+        let json: Value = serde_json::from_str(&offline.to_json().unwrap()).unwrap();
+        assert_eq!(json["signers"][0]["account_number"], "1");
+        assert_eq!(
+            json["tx"]["body"]["messages"][0]["@type"],
+            "/cosmos.bank.v1beta1.MsgSend"
+        );
+        assert_eq!(
+            json["tx"]["auth_info"]["signer_infos"][1]["mode_info"],
+            json!({ "single": { "mode": "SIGN_MODE_DIRECT" } })
+        );
+        assert_eq!(json["tx"]["auth_info"]["fee"]["gas_limit"], "200000");
+        assert_eq!(json["tx"]["signatures"], json!([]));
+
+        // On the air-gapped machines.
+        let offline = OfflineTx::from_json(&offline.to_json().unwrap()).unwrap();
+        let signed_by_alice = offline.sign(&alice).unwrap();
+        let signed_by_bob: Tx =
+            serde_json::from_value(serde_json::to_value(offline.sign(&bob).unwrap()).unwrap())
+                .unwrap();
+        assert!(offline.sign(&key(3)).is_err());
+
+        assert!(offline
+            .merge(std::slice::from_ref(&signed_by_alice))
+            .is_err());
+        let merged = offline.merge(&[signed_by_alice, signed_by_bob]).unwrap();
+        assert_eq!(merged, builder.sign("pisco-1", &[&alice, &bob]).unwrap());
+    }
+
+    const SIGNER: &str = "terra1w508d6qejxtdg4y5r3zarvary0c5xw7kued6dc";
+
+    fn body() -> Value {
+        json!({
+            "messages": [{
+                "@type": "/cosmos.bank.v1beta1.MsgSend",
+                "from_address": SIGNER,
+                "to_address": "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+                "amount": [{ "denom": "uluna", "amount": "1000" }]
+            }],
+            "memo": "",
+            "timeout_height": "0",
+            "extension_options": [],
+            "non_critical_extension_options": []
+        })
+    }
+
+    /// `terrad tx bank send .. --generate-only` output.
+    fn generated() -> Tx {
+        serde_json::from_value(json!({
+            "body": body(),
+            "auth_info": {
+                "signer_infos": [],
+                "fee": {
+                    "amount": [{ "denom": "uluna", "amount": "3000" }],
+                    "gas_limit": "200000",
+                    "payer": "",
+                    "granter": ""
+                },
+                "tip": null
+            },
+            "signatures": []
+        }))
+        .unwrap()
+    }
+
+    fn generated_offline(public_key: Option<PublicKey>) -> OfflineTx {
+        OfflineTx {
+            chain_id: "pisco-1".into(),
+            signers: vec![SignerMetadata {
+                address: SIGNER.into(),
+                account_number: 12,
+                sequence: 3,
+                public_key,
+            }],
+            tx: generated(),
+        }
+    }
+
+    #[test]
+    fn it_reads_generate_only_output() {
+        let tx = generated();
+        assert_eq!(tx.body.messages.len(), 1);
+        assert_eq!(tx.auth_info.fee, Fee::new(200_000, "3000uluna"));
+
+        // Signer infos come from the metadata, which lacks the public key.
+        assert!(generated_offline(None).unsigned().is_err());
+        let unsigned = generated_offline(Some(key(1).public_key()))
+            .unsigned()
+            .unwrap();
+        assert_eq!(unsigned.tx().auth_info.signer_infos[0].sequence, 3);
+        assert_eq!(unsigned.account_numbers(), &[12]);
+
+        let signed = generated_offline(None).sign(&key(1)).unwrap();
+        assert_eq!(signed.auth_info.signer_infos.len(), 1);
+        assert!(generated_offline(None).sign(&key(2)).is_err());
+    }
+
+    #[test]
+    fn it_merges_terrad_signed_generate_only_output() {
+        // Signed as by `terrad tx sign --offline --account-number 12
+        // --sequence 3`, which adds its signer info to the generated tx.
+        let signed: Tx = serde_json::from_value(json!({
+            "body": body(),
+            "auth_info": {
+                "signer_infos": [{
+                    "public_key": {
+                        "@type": "/cosmos.crypto.secp256k1.PubKey",
+                        "key": "Anm+Zn753LusVaBilc6HCwcCm/zbLc4o2VnygVsW+BeY"
+                    },
+                    "mode_info": { "single": { "mode": "SIGN_MODE_DIRECT" } },
+                    "sequence": "3"
+                }],
+                "fee": {
+                    "amount": [{ "denom": "uluna", "amount": "3000" }],
+                    "gas_limit": "200000",
+                    "payer": "",
+                    "granter": ""
+                },
+                "tip": null
+            },
+            "signatures": [
+                "3JrOIw5vrp2fKo+3C9QTTwghjjM2a8lXz4l1MZXtWNsdzKk070b5AdYBs3V26lStYMwnIIh4ZEAww3NaxLnzrA=="
+            ]
+        }))
+        .unwrap();
+
+        let offline = generated_offline(None);
+        let merged = offline.merge(std::slice::from_ref(&signed)).unwrap();
+        merged.verify_signatures("pisco-1", &[12]).unwrap();
+        assert_eq!(signed, offline.sign(&key(1)).unwrap());
+
+        // Without `--overwrite`, terrad appends its signer info to the
+        // ones already there.
+        let prefilled = OfflineTx::new(
+            &offline.unsigned_with_keys(&[key(1).public_key()]).unwrap(),
+            "pisco-1",
+        )
+        .unwrap();
+        let mut appended = signed;
+        appended
+            .auth_info
+            .signer_infos
+            .insert(0, prefilled.tx.auth_info.signer_infos[0].clone());
+        let error = prefilled.merge(&[appended]).unwrap_err();
+        assert!(error.contains("--overwrite"));
+    }
+
+    /// Signs as `terrad tx sign --overwrite --sign-mode <mode>` does: the
+    /// result has only the signer's own signer info.
+    fn terrad_sign(offline: &OfflineTx, index: usize, key: &PrivateKey, mode: SignMode) -> Tx {
+        let signer = &offline.signers[index];
+        let mut tx = offline.tx.clone();
+        tx.auth_info.signer_infos = vec![SignerInfo {
+            public_key: Some(key.public_key()),
+            mode_info: mode.into(),
+            sequence: signer.sequence,
+        }];
+        let sign_bytes = match mode {
+            SignMode::Direct => UnsignedTx::new(tx.clone(), vec![signer.account_number])
+                .unwrap()
+                .sign_doc(&offline.chain_id, 0)
+                .unwrap()
+                .encode_proto()
+                .unwrap(),
+            _ => StdSignDoc::new(
+                &tx,
+                &offline.chain_id,
+                signer.account_number,
+                signer.sequence,
+            )
+            .unwrap()
+            .sign_bytes(),
+        };
+        tx.signatures = vec![key.sign(&sign_bytes).unwrap()];
+        tx
+    }
+
+    #[test]
+    fn it_merges_amino_json_signatures() {
+        let offline = generated_offline(None);
+        let signed = terrad_sign(&offline, 0, &key(1), SignMode::LegacyAminoJson);
+        let merged = offline.merge(&[signed]).unwrap();
+        assert_eq!(
+            merged.to_tx().unwrap().auth_info.signer_infos[0].mode_info,
+            SignMode::LegacyAminoJson.into()
+        );
+        merged.verify_signatures("pisco-1", &[12]).unwrap();
+
+        let mut wrong_sequence = terrad_sign(&offline, 0, &key(1), SignMode::LegacyAminoJson);
+        wrong_sequence.auth_info.signer_infos[0].sequence = 4;
+        assert!(offline.merge(&[wrong_sequence]).is_err());
+    }
+
+    #[test]
+    fn it_merges_terrad_signatures_of_two_signers() {
+        let (alice, bob) = (key(1), key(2));
+        let mut offline = generated_offline(None);
+        offline.signers.push(SignerMetadata {
+            address: bob.acc_address(),
+            account_number: 13,
+            sequence: 0,
+            public_key: None,
+        });
+
+        let signed_by_alice = terrad_sign(&offline, 0, &alice, SignMode::LegacyAminoJson);
+        let signed_by_bob = terrad_sign(&offline, 1, &bob, SignMode::LegacyAminoJson);
+        let merged = offline
+            .merge(&[signed_by_bob, signed_by_alice.clone()])
+            .unwrap();
+        let tx = merged.to_tx().unwrap();
+        assert_eq!(tx.auth_info.signer_infos.len(), 2);
+        assert_eq!(
+            tx.auth_info.signer_infos[1].public_key,
+            Some(bob.public_key())
+        );
+        merged.verify_signatures("pisco-1", &[12, 13]).unwrap();
+
+        // Bob's direct signature covers only his own signer info.
+        let signed_by_bob = terrad_sign(&offline, 1, &bob, SignMode::Direct);
+        assert!(offline.merge(&[signed_by_alice, signed_by_bob]).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+//! Protobuf counterparts of the `cosmos.tx.v1beta1` types.
+
+use prost::Message;
+
+use crate::sdk::proto::Coin;
+use crate::Any;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Fee {
+    #[prost(message, repeated, tag = "1")]
+    pub amount: Vec<Coin>,
+    #[prost(uint64, tag = "2")]
+    pub gas_limit: u64,
+    #[prost(string, tag = "3")]
+    pub payer: String,
+    #[prost(string, tag = "4")]
+    pub granter: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct TxBody {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+    #[prost(string, tag = "2")]
+    pub memo: String,
+    #[prost(uint64, tag = "3")]
+    pub timeout_height: u64,
+    #[prost(message, repeated, tag = "1023")]
+    pub extension_options: Vec<Any>,
+    #[prost(message, repeated, tag = "2047")]
+    pub non_critical_extension_options: Vec<Any>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ModeInfoSingle {
+    #[prost(int32, tag = "1")]
+    pub mode: i32,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ModeInfoMulti {
+    #[prost(message, optional, tag = "1")]
+    pub bitarray: Option<CompactBitArray>,
+    #[prost(message, repeated, tag = "2")]
+    pub mode_infos: Vec<ModeInfo>,
+}
+
+/// `ModeInfo`, whose `sum` oneof encodes like two optional fields of which
+/// one is set.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ModeInfo {
+    #[prost(message, optional, tag = "1")]
+    pub single: Option<ModeInfoSingle>,
+    #[prost(message, optional, tag = "2")]
+    pub multi: Option<ModeInfoMulti>,
+}
+
+/// `cosmos.crypto.multisig.v1beta1.CompactBitArray`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CompactBitArray {
+    #[prost(uint32, tag = "1")]
+    pub extra_bits_stored: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub elems: Vec<u8>,
+}
+
+/// `cosmos.crypto.multisig.v1beta1.MultiSignature`, the signature of a
+/// multisig signer.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MultiSignature {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub signatures: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct SignerInfo {
+    #[prost(message, optional, tag = "1")]
+    pub public_key: Option<Any>,
+    #[prost(message, optional, tag = "2")]
+    pub mode_info: Option<ModeInfo>,
+    #[prost(uint64, tag = "3")]
+    pub sequence: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct AuthInfo {
+    #[prost(message, repeated, tag = "1")]
+    pub signer_infos: Vec<SignerInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub fee: Option<Fee>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Tx {
+    #[prost(message, optional, tag = "1")]
+    pub body: Option<TxBody>,
+    #[prost(message, optional, tag = "2")]
+    pub auth_info: Option<AuthInfo>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub signatures: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct TxRaw {
+    #[prost(bytes = "vec", tag = "1")]
+    pub body_bytes: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub auth_info_bytes: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub signatures: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct SignDoc {
+    #[prost(bytes = "vec", tag = "1")]
+    pub body_bytes: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub auth_info_bytes: Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub chain_id: String,
+    #[prost(uint64, tag = "4")]
+    pub account_number: u64,
+}
@@ -1,5 +1,681 @@
-pub struct Tx {}
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 
-pub struct TxResult {
-    pub height: u128,
+use crate::auth::{proto, Fee};
+use crate::registry::{any_from_data, any_to_data};
+use crate::wasm::json::{optional_address, optional_address_to_string};
+use crate::{Any, Coins, Msg, ProtoMsg, PublicKey};
+
+/// How a signer's signature was produced.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum SignMode {
+    /// Over the protobuf `SignDoc`.
+    #[default]
+    #[serde(rename = "SIGN_MODE_DIRECT")]
+    Direct,
+    /// Over the Amino JSON `StdSignDoc`, as used by Ledger.
+    #[serde(rename = "SIGN_MODE_LEGACY_AMINO_JSON")]
+    LegacyAminoJson,
+    // The remaining modes decode, but `verify_signatures` cannot check them.
+    #[serde(rename = "SIGN_MODE_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "SIGN_MODE_TEXTUAL")]
+    Textual,
+    #[serde(rename = "SIGN_MODE_DIRECT_AUX")]
+    DirectAux,
+    #[serde(rename = "SIGN_MODE_EIP_191")]
+    Eip191,
+}
+
+impl SignMode {
+    pub fn to_i32(self) -> i32 {
+        match self {
+            SignMode::Unspecified => 0,
+            SignMode::Direct => 1,
+            SignMode::Textual => 2,
+            SignMode::DirectAux => 3,
+            SignMode::LegacyAminoJson => 127,
+            SignMode::Eip191 => 191,
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(SignMode::Unspecified),
+            1 => Ok(SignMode::Direct),
+            2 => Ok(SignMode::Textual),
+            3 => Ok(SignMode::DirectAux),
+            127 => Ok(SignMode::LegacyAminoJson),
+            191 => Ok(SignMode::Eip191),
+            other => Err(format!("unknown sign mode: {}", other)),
+        }
+    }
+}
+
+/// The messages of a transaction and what applies to all of them.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TxBody {
+    pub messages: Vec<Any>,
+    pub memo: String,
+    /// Block height after which the transaction is no longer valid; zero
+    /// for none.
+    pub timeout_height: u64,
+    /// Rejected by the chain unless it knows them.
+    pub extension_options: Vec<Any>,
+    /// Ignored by the chain unless it knows them.
+    pub non_critical_extension_options: Vec<Any>,
+}
+
+impl ProtoMsg for TxBody {
+    type Proto = proto::TxBody;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::TxBody {
+            messages: self.messages.clone(),
+            memo: self.memo.clone(),
+            timeout_height: self.timeout_height,
+            extension_options: self.extension_options.clone(),
+            non_critical_extension_options: self.non_critical_extension_options.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            messages: proto.messages,
+            memo: proto.memo,
+            timeout_height: proto.timeout_height,
+            extension_options: proto.extension_options,
+            non_critical_extension_options: proto.non_critical_extension_options,
+        })
+    }
+}
+
+/// Which keys of a multisig signed, one bit per key.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct CompactBitArray {
+    /// Number of bits used in the last byte of `elems`, or zero if all are.
+    pub extra_bits_stored: u32,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub elems: Vec<u8>,
+}
+
+impl CompactBitArray {
+    /// An array of `bits` with those at `set` set.
+    pub fn new(bits: usize, set: &[usize]) -> Self {
+        let mut elems = vec![0u8; bits.div_ceil(8)];
+        for &index in set.iter().filter(|&&index| index < bits) {
+            elems[index / 8] |= 0x80 >> (index % 8);
+        }
+        Self {
+            extra_bits_stored: (bits % 8) as u32,
+            elems,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match (self.elems.len(), self.extra_bits_stored) {
+            (0, _) => 0,
+            (n, 0) => n * 8,
+            (n, extra) => (n - 1) * 8 + extra as usize,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        index < self.len() && self.elems[index / 8] & (0x80 >> (index % 8)) != 0
+    }
+}
+
+impl ProtoMsg for CompactBitArray {
+    type Proto = proto::CompactBitArray;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::CompactBitArray {
+            extra_bits_stored: self.extra_bits_stored,
+            elems: self.elems.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            extra_bits_stored: proto.extra_bits_stored,
+            elems: proto.elems,
+        })
+    }
+}
+
+/// How a signer's signature was produced: one mode for a single key, or,
+/// for a multisig, which of its keys signed and how.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ModeInfo {
+    Single {
+        mode: SignMode,
+    },
+    Multi {
+        bitarray: CompactBitArray,
+        /// One per signing key, in key order.
+        mode_infos: Vec<ModeInfo>,
+    },
+}
+
+impl From<SignMode> for ModeInfo {
+    fn from(mode: SignMode) -> Self {
+        ModeInfo::Single { mode }
+    }
+}
+
+impl ProtoMsg for ModeInfo {
+    type Proto = proto::ModeInfo;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(match self {
+            ModeInfo::Single { mode } => proto::ModeInfo {
+                single: Some(proto::ModeInfoSingle {
+                    mode: mode.to_i32(),
+                }),
+                multi: None,
+            },
+            ModeInfo::Multi {
+                bitarray,
+                mode_infos,
+            } => proto::ModeInfo {
+                single: None,
+                multi: Some(proto::ModeInfoMulti {
+                    bitarray: Some(bitarray.to_proto()?),
+                    mode_infos: mode_infos
+                        .iter()
+                        .map(ProtoMsg::to_proto)
+                        .collect::<Result<_, _>>()?,
+                }),
+            },
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        match (proto.single, proto.multi) {
+            (Some(single), None) => Ok(ModeInfo::Single {
+                mode: SignMode::from_i32(single.mode)?,
+            }),
+            (None, Some(multi)) => Ok(ModeInfo::Multi {
+                bitarray: CompactBitArray::from_proto(multi.bitarray.unwrap_or_default())?,
+                mode_infos: multi
+                    .mode_infos
+                    .into_iter()
+                    .map(ModeInfo::from_proto)
+                    .collect::<Result<_, _>>()?,
+            }),
+            _ => Err("mode info must be either single or multi".into()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SignerInfo {
+    /// May be omitted once the account's key is known on chain.
+    pub public_key: Option<PublicKey>,
+    pub mode_info: ModeInfo,
+    pub sequence: u64,
+}
+
+impl ProtoMsg for SignerInfo {
+    type Proto = proto::SignerInfo;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::SignerInfo {
+            public_key: self.public_key.as_ref().map(PublicKey::to_any),
+            mode_info: Some(self.mode_info.to_proto()?),
+            sequence: self.sequence,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            public_key: proto
+                .public_key
+                .as_ref()
+                .map(PublicKey::from_any)
+                .transpose()?,
+            mode_info: ModeInfo::from_proto(proto.mode_info.ok_or("missing mode info")?)?,
+            sequence: proto.sequence,
+        })
+    }
+}
+
+/// Who signs a transaction and what it pays.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AuthInfo {
+    pub signer_infos: Vec<SignerInfo>,
+    pub fee: Fee,
+}
+
+impl ProtoMsg for AuthInfo {
+    type Proto = proto::AuthInfo;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::AuthInfo {
+            signer_infos: self
+                .signer_infos
+                .iter()
+                .map(ProtoMsg::to_proto)
+                .collect::<Result<_, _>>()?,
+            fee: Some(self.fee.to_proto()?),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            signer_infos: proto
+                .signer_infos
+                .into_iter()
+                .map(SignerInfo::from_proto)
+                .collect::<Result<_, _>>()?,
+            fee: Fee::from_proto(proto.fee.unwrap_or_default())?,
+        })
+    }
+}
+
+/// A transaction with one signature per signer, in `signer_infos` order.
+///
+/// Serializes to the protobuf JSON printed by `terrad tx ... --generate-only`
+/// and `terrad tx sign`, with messages converted through the
+/// [registry](crate::registry).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Tx {
+    pub body: TxBody,
+    pub auth_info: AuthInfo,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl Tx {
+    /// Decodes a transaction as found in blocks and broadcast requests, i.e.
+    /// encoded `TxRaw` bytes.
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        TxRaw::decode_proto(bytes)?.to_tx()
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, String> {
+        self.to_raw()?.encode_proto()
+    }
+
+    /// Hash of `encode()`. For a decoded transaction this only matches the
+    /// chain's if re-encoding reproduces the original bytes; hash those with
+    /// [`tx_hash`] where available.
+    pub fn tx_hash(&self) -> Result<String, String> {
+        Ok(tx_hash(&self.encode()?))
+    }
+
+    /// The messages in protobuf JSON form, converted through the
+    /// [registry](crate::registry).
+    pub fn messages_data(&self) -> Result<Vec<Value>, String> {
+        self.body.messages.iter().map(any_to_data).collect()
+    }
+
+    /// The messages of type `M`, in order.
+    pub fn messages_of<M: Msg>(&self) -> Result<Vec<M>, String> {
+        self.body
+            .messages
+            .iter()
+            .filter(|any| any.type_url == M::TYPE_URL)
+            .map(M::from_any)
+            .collect()
+    }
+
+    /// The form in which the transaction is broadcast.
+    pub fn to_raw(&self) -> Result<TxRaw, String> {
+        Ok(TxRaw {
+            body_bytes: self.body.encode_proto()?,
+            auth_info_bytes: self.auth_info.encode_proto()?,
+            signatures: self.signatures.clone(),
+        })
+    }
+}
+
+impl ProtoMsg for Tx {
+    type Proto = proto::Tx;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::Tx {
+            body: Some(self.body.to_proto()?),
+            auth_info: Some(self.auth_info.to_proto()?),
+            signatures: self.signatures.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            body: TxBody::from_proto(proto.body.unwrap_or_default())?,
+            auth_info: AuthInfo::from_proto(proto.auth_info.unwrap_or_default())?,
+            signatures: proto.signatures,
+        })
+    }
+}
+
+impl Serialize for Tx {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let messages = self
+            .body
+            .messages
+            .iter()
+            .map(any_to_data)
+            .collect::<Result<_, _>>()
+            .map_err(S::Error::custom)?;
+        let fee = &self.auth_info.fee;
+        TxJson {
+            body: TxBodyJson {
+                messages,
+                memo: self.body.memo.clone(),
+                timeout_height: self.body.timeout_height,
+                extension_options: self
+                    .body
+                    .extension_options
+                    .iter()
+                    .map(any_to_data)
+                    .collect::<Result<_, _>>()
+                    .map_err(S::Error::custom)?,
+                non_critical_extension_options: self
+                    .body
+                    .non_critical_extension_options
+                    .iter()
+                    .map(any_to_data)
+                    .collect::<Result<_, _>>()
+                    .map_err(S::Error::custom)?,
+            },
+            auth_info: AuthInfoJson {
+                signer_infos: self
+                    .auth_info
+                    .signer_infos
+                    .iter()
+                    .map(|info| SignerInfoJson {
+                        public_key: info.public_key.as_ref().map(PublicKey::to_data),
+                        mode_info: info.mode_info.clone(),
+                        sequence: info.sequence,
+                    })
+                    .collect(),
+                fee: FeeJson {
+                    amount: fee.amount.clone(),
+                    gas_limit: fee.gas_limit,
+                    payer: optional_address_to_string(&fee.payer),
+                    granter: optional_address_to_string(&fee.granter),
+                },
+            },
+            signatures: self.signatures.iter().map(base64::encode).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tx {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = TxJson::deserialize(deserializer)?;
+        let decode_anys = |data: &[Value]| {
+            data.iter()
+                .map(any_from_data)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(D::Error::custom)
+        };
+        let signer_infos = json
+            .auth_info
+            .signer_infos
+            .into_iter()
+            .map(|info| {
+                Ok(SignerInfo {
+                    public_key: info
+                        .public_key
+                        .map(serde_json::from_value)
+                        .transpose()
+                        .map_err(D::Error::custom)?,
+                    mode_info: info.mode_info,
+                    sequence: info.sequence,
+                })
+            })
+            .collect::<Result<_, D::Error>>()?;
+        let fee = json.auth_info.fee;
+        let signatures = json
+            .signatures
+            .iter()
+            .map(base64::decode)
+            .collect::<Result<_, _>>()
+            .map_err(D::Error::custom)?;
+        Ok(Tx {
+            body: TxBody {
+                messages: decode_anys(&json.body.messages)?,
+                memo: json.body.memo,
+                timeout_height: json.body.timeout_height,
+                extension_options: decode_anys(&json.body.extension_options)?,
+                non_critical_extension_options: decode_anys(
+                    &json.body.non_critical_extension_options,
+                )?,
+            },
+            auth_info: AuthInfo {
+                signer_infos,
+                fee: Fee {
+                    amount: fee.amount,
+                    gas_limit: fee.gas_limit,
+                    payer: optional_address(fee.payer),
+                    granter: optional_address(fee.granter),
+                },
+            },
+            signatures,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct TxJson {
+    body: TxBodyJson,
+    auth_info: AuthInfoJson,
+    #[serde(default)]
+    signatures: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TxBodyJson {
+    messages: Vec<Value>,
+    #[serde(default)]
+    memo: String,
+    #[serde(default, with = "crate::internal::serde_str")]
+    timeout_height: u64,
+    #[serde(default)]
+    extension_options: Vec<Value>,
+    #[serde(default)]
+    non_critical_extension_options: Vec<Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AuthInfoJson {
+    #[serde(default)]
+    signer_infos: Vec<SignerInfoJson>,
+    fee: FeeJson,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignerInfoJson {
+    public_key: Option<Value>,
+    mode_info: ModeInfo,
+    #[serde(with = "crate::internal::serde_str")]
+    sequence: u64,
+}
+
+// Unlike `Fee`'s own serialization, empty payer and granter are written
+// out, as `terrad` does.
+#[derive(Serialize, Deserialize)]
+struct FeeJson {
+    amount: Coins,
+    #[serde(with = "crate::internal::serde_str")]
+    gas_limit: u64,
+    #[serde(default)]
+    payer: String,
+    #[serde(default)]
+    granter: String,
+}
+
+/// A signed transaction as broadcast, keeping the exact body and auth info
+/// bytes that were signed.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TxRaw {
+    pub body_bytes: Vec<u8>,
+    pub auth_info_bytes: Vec<u8>,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl TxRaw {
+    pub fn tx_hash(&self) -> Result<String, String> {
+        Ok(tx_hash(&self.encode_proto()?))
+    }
+
+    pub fn to_tx(&self) -> Result<Tx, String> {
+        Ok(Tx {
+            body: TxBody::decode_proto(&self.body_bytes)?,
+            auth_info: AuthInfo::decode_proto(&self.auth_info_bytes)?,
+            signatures: self.signatures.clone(),
+        })
+    }
+}
+
+impl ProtoMsg for TxRaw {
+    type Proto = proto::TxRaw;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::TxRaw {
+            body_bytes: self.body_bytes.clone(),
+            auth_info_bytes: self.auth_info_bytes.clone(),
+            signatures: self.signatures.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            body_bytes: proto.body_bytes,
+            auth_info_bytes: proto.auth_info_bytes,
+            signatures: proto.signatures,
+        })
+    }
+}
+
+/// The id of a transaction on chain: the uppercase hex SHA-256 of its
+/// encoded `TxRaw` bytes.
+pub fn tx_hash(tx_bytes: &[u8]) -> String {
+    hex::encode_upper(Sha256::digest(tx_bytes))
+}
+
+/// What a `SignMode::Direct` signer signs.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SignDoc {
+    pub body_bytes: Vec<u8>,
+    pub auth_info_bytes: Vec<u8>,
+    pub chain_id: String,
+    pub account_number: u64,
+}
+
+impl ProtoMsg for SignDoc {
+    type Proto = proto::SignDoc;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::SignDoc {
+            body_bytes: self.body_bytes.clone(),
+            auth_info_bytes: self.auth_info_bytes.clone(),
+            chain_id: self.chain_id.clone(),
+            account_number: self.account_number,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            body_bytes: proto.body_bytes,
+            auth_info_bytes: proto.auth_info_bytes,
+            chain_id: proto.chain_id,
+            account_number: proto.account_number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bank::MsgSend;
+    use crate::Coins;
+
+    #[test]
+    fn it_decodes_and_hashes() {
+        assert_eq!(
+            tx_hash(b""),
+            "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855"
+        );
+
+        let send = MsgSend::new(
+            "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+            "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+            Coins::from("1000uluna"),
+        );
+        let tx = Tx {
+            body: TxBody {
+                messages: vec![send.to_any().unwrap()],
+                memo: "memo".into(),
+                timeout_height: 0,
+                ..Default::default()
+            },
+            auth_info: AuthInfo {
+                signer_infos: vec![],
+                fee: Fee::new(200_000, "3000uluna"),
+            },
+            signatures: vec![vec![1; 64]],
+        };
+        let bytes = tx.encode().unwrap();
+        let decoded = Tx::decode(&bytes).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded.tx_hash().unwrap(), tx_hash(&bytes));
+        assert_eq!(
+            TxRaw::decode_proto(&bytes).unwrap().tx_hash().unwrap(),
+            tx.tx_hash().unwrap()
+        );
+        assert_eq!(
+            decoded.messages_of::<MsgSend>().unwrap(),
+            vec![send.clone()]
+        );
+        assert_eq!(
+            decoded.messages_data().unwrap(),
+            vec![send.to_data().unwrap()]
+        );
+        assert!(Tx::decode(b"not a tx").is_err());
+    }
+
+    #[test]
+    fn it_keeps_extension_options() {
+        let option = Any {
+            type_url: "/terra.ext.v1.Option".into(),
+            value: vec![1, 2, 3],
+        };
+        let body = TxBody {
+            memo: "memo".into(),
+            non_critical_extension_options: vec![option.clone()],
+            ..Default::default()
+        };
+        let decoded = TxBody::decode_proto(&body.encode_proto().unwrap()).unwrap();
+        assert_eq!(decoded.non_critical_extension_options, vec![option]);
+        assert_eq!(decoded, body);
+    }
+
+    #[test]
+    fn it_packs_bit_arrays() {
+        let bits = CompactBitArray::new(10, &[0, 9]);
+        assert_eq!(bits.elems, vec![0x80, 0x40]);
+        assert_eq!(bits.len(), 10);
+        assert!(bits.get(0) && bits.get(9));
+        assert!(!bits.get(1) && !bits.get(10));
+        assert_eq!(CompactBitArray::new(8, &[7]).len(), 8);
+        assert!(CompactBitArray::default().is_empty());
+    }
 }
@@ -0,0 +1,243 @@
+use prost::Message;
+
+use crate::auth::{proto, ModeInfo, SignDoc, SignMode, StdSignDoc, Tx, TxRaw};
+use crate::{ProtoMsg, PublicKey};
+
+impl TxRaw {
+    /// Checks every signature against its signer info's public key and sign
+    /// mode. `account_numbers` has one entry per signer, as found on chain.
+    ///
+    /// Direct mode signatures are checked against the exact bytes in this
+    /// `TxRaw`, so transactions decoded from blocks verify even when
+    /// re-encoding them would not reproduce those bytes. A multisig signer
+    /// needs valid signatures from at least `threshold` of its keys.
+    pub fn verify_signatures(&self, chain_id: &str, account_numbers: &[u64]) -> Result<(), String> {
+        let tx = self.to_tx()?;
+        let signer_infos = &tx.auth_info.signer_infos;
+        if tx.signatures.len() != signer_infos.len() {
+            return Err(format!(
+                "transaction has {} signers but {} signatures",
+                signer_infos.len(),
+                tx.signatures.len()
+            ));
+        }
+        if account_numbers.len() != signer_infos.len() {
+            return Err(format!(
+                "transaction has {} signers but {} account numbers were given",
+                signer_infos.len(),
+                account_numbers.len()
+            ));
+        }
+
+        for (index, info) in signer_infos.iter().enumerate() {
+            let public_key = info
+                .public_key
+                .as_ref()
+                .ok_or_else(|| format!("signer {} has no public key", index))?;
+            let sign_bytes = |mode| match mode {
+                SignMode::Direct => SignDoc {
+                    body_bytes: self.body_bytes.clone(),
+                    auth_info_bytes: self.auth_info_bytes.clone(),
+                    chain_id: chain_id.to_string(),
+                    account_number: account_numbers[index],
+                }
+                .encode_proto(),
+                SignMode::LegacyAminoJson => {
+                    Ok(
+                        StdSignDoc::new(&tx, chain_id, account_numbers[index], info.sequence)?
+                            .sign_bytes(),
+                    )
+                }
+                other => Err(format!("cannot verify {:?} mode signatures", other)),
+            };
+            verify(
+                public_key,
+                &info.mode_info,
+                &tx.signatures[index],
+                &sign_bytes,
+            )
+            .map_err(|e| format!("signer {}: {}", index, e))?;
+        }
+        Ok(())
+    }
+}
+
+fn verify(
+    public_key: &PublicKey,
+    mode_info: &ModeInfo,
+    signature: &[u8],
+    sign_bytes: &dyn Fn(SignMode) -> Result<Vec<u8>, String>,
+) -> Result<(), String> {
+    let (threshold, public_keys, bitarray, mode_infos) = match (public_key, mode_info) {
+        (_, ModeInfo::Single { mode }) => return public_key.verify(&sign_bytes(*mode)?, signature),
+        (
+            PublicKey::Multisig {
+                threshold,
+                public_keys,
+            },
+            ModeInfo::Multi {
+                bitarray,
+                mode_infos,
+            },
+        ) => (threshold, public_keys, bitarray, mode_infos),
+        _ => return Err("multisig mode info for a single key".into()),
+    };
+    if bitarray.len() != public_keys.len() {
+        return Err(format!(
+            "bit array of {} bits for {} keys",
+            bitarray.len(),
+            public_keys.len()
+        ));
+    }
+    let signatures = proto::MultiSignature::decode(signature)
+        .map_err(|e| format!("invalid multisig signature: {}", e))?
+        .signatures;
+    let signing_keys = public_keys
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| bitarray.get(*index))
+        .map(|(_, key)| key)
+        .collect::<Vec<_>>();
+    if signing_keys.len() != signatures.len() || signing_keys.len() != mode_infos.len() {
+        return Err(format!(
+            "{} keys signed but there are {} signatures and {} mode infos",
+            signing_keys.len(),
+            signatures.len(),
+            mode_infos.len()
+        ));
+    }
+    if signing_keys.len() < *threshold as usize {
+        return Err(format!(
+            "{} of {} required signatures",
+            signing_keys.len(),
+            threshold
+        ));
+    }
+    for ((key, mode_info), signature) in signing_keys.into_iter().zip(mode_infos).zip(&signatures) {
+        verify(key, mode_info, signature, sign_bytes)?;
+    }
+    Ok(())
+}
+
+impl Tx {
+    /// See [`TxRaw::verify_signatures`].
+    pub fn verify_signatures(&self, chain_id: &str, account_numbers: &[u64]) -> Result<(), String> {
+        self.to_raw()?.verify_signatures(chain_id, account_numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use serde_json::json;
+
+    use crate::auth::{
+        proto, AuthInfo, CompactBitArray, Fee, ModeInfo, PrivateKey, SignMode, Signer, SignerData,
+        SignerInfo, StdSignDoc, Tx, TxBody, TxBuilder,
+    };
+    use crate::bank::MsgSend;
+    use crate::{Msg, ProtoMsg, PublicKey};
+
+    #[test]
+    fn it_verifies_tx_signatures() {
+        let (alice, bob) = (PrivateKey::random(), PrivateKey::random());
+        let raw = TxBuilder::new()
+            .msg(&MsgSend::new(
+                alice.acc_address(),
+                bob.acc_address(),
+                "1uluna",
+            ))
+            .unwrap()
+            .fee(Fee::new(100_000, "2uluna"))
+            .signer(SignerData::new(alice.public_key(), 5, 1))
+            .signer(SignerData::new(bob.public_key(), 6, 0))
+            .sign("phoenix-1", &[&alice, &bob])
+            .unwrap();
+        assert!(raw.verify_signatures("phoenix-1", &[5, 6]).is_ok());
+        assert!(raw.verify_signatures("pisco-1", &[5, 6]).is_err());
+        assert!(raw.verify_signatures("phoenix-1", &[6, 5]).is_err());
+        assert!(raw.verify_signatures("phoenix-1", &[5]).is_err());
+
+        // A Ledger-style signature over the Amino JSON sign doc.
+        let mut tx = raw.to_tx().unwrap();
+        tx.auth_info.signer_infos.truncate(1);
+        tx.auth_info.signer_infos[0].mode_info = SignMode::LegacyAminoJson.into();
+        let sign_doc = StdSignDoc::new(&tx, "phoenix-1", 5, 1).unwrap();
+        tx.signatures = vec![alice.sign(&sign_doc.sign_bytes()).unwrap()];
+        assert!(tx.verify_signatures("phoenix-1", &[5]).is_ok());
+        tx.body.memo = "changed".into();
+        assert!(tx.verify_signatures("phoenix-1", &[5]).is_err());
+    }
+
+    #[test]
+    fn it_verifies_multisig_signatures() {
+        let keys = [
+            PrivateKey::random(),
+            PrivateKey::random(),
+            PrivateKey::random(),
+        ];
+        let multisig = PublicKey::Multisig {
+            threshold: 2,
+            public_keys: keys.iter().map(Signer::public_key).collect(),
+        };
+        let amino = ModeInfo::from(SignMode::LegacyAminoJson);
+        let mut tx = Tx {
+            body: TxBody {
+                messages: vec![MsgSend::new(
+                    multisig.acc_address(),
+                    keys[0].acc_address(),
+                    "1uluna",
+                )
+                .to_any()
+                .unwrap()],
+                ..Default::default()
+            },
+            auth_info: AuthInfo {
+                signer_infos: vec![SignerInfo {
+                    public_key: Some(multisig),
+                    mode_info: ModeInfo::Multi {
+                        bitarray: CompactBitArray::new(3, &[0, 2]),
+                        mode_infos: vec![amino.clone(), amino],
+                    },
+                    sequence: 3,
+                }],
+                fee: Fee::new(100_000, "2uluna"),
+            },
+            signatures: vec![],
+        };
+        let sign_bytes = StdSignDoc::new(&tx, "phoenix-1", 9, 3)
+            .unwrap()
+            .sign_bytes();
+        let sign = |key: &PrivateKey| key.sign(&sign_bytes).unwrap();
+        tx.signatures = vec![proto::MultiSignature {
+            signatures: vec![sign(&keys[0]), sign(&keys[2])],
+        }
+        .encode_to_vec()];
+
+        let raw = tx.to_raw().unwrap();
+        assert_eq!(Tx::decode(&raw.encode_proto().unwrap()).unwrap(), tx);
+        let json = serde_json::to_value(&tx).unwrap();
+        assert_eq!(
+            json["auth_info"]["signer_infos"][0]["mode_info"]["multi"]["bitarray"],
+            json!({ "extra_bits_stored": 3, "elems": "oA==" })
+        );
+        assert_eq!(
+            json["auth_info"]["signer_infos"][0]["public_key"]["@type"],
+            "/cosmos.crypto.multisig.LegacyAminoPubKey"
+        );
+        assert_eq!(serde_json::from_value::<Tx>(json).unwrap(), tx);
+        raw.verify_signatures("phoenix-1", &[9]).unwrap();
+
+        // One signature is below the threshold.
+        tx.auth_info.signer_infos[0].mode_info = ModeInfo::Multi {
+            bitarray: CompactBitArray::new(3, &[1]),
+            mode_infos: vec![SignMode::LegacyAminoJson.into()],
+        };
+        tx.signatures = vec![proto::MultiSignature {
+            signatures: vec![sign(&keys[1])],
+        }
+        .encode_to_vec()];
+        let error = tx.verify_signatures("phoenix-1", &[9]).unwrap_err();
+        assert!(error.contains("1 of 2"), "{}", error);
+    }
+}
@@ -0,0 +1,343 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::authz::proto;
+use crate::sdk::proto::{
+    coin_from_proto, coins_from_proto, coins_to_proto, timestamp_from_proto, timestamp_to_proto,
+};
+use crate::staking::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
+use crate::{bank, AccAddress, Coin, Coins, MsgType, ProtoMsg, ValAddress};
+
+/// Grants unrestricted permission to execute one message type.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("msgauth/GenericAuthorization")]
+#[msgtype_pb("/cosmos.authz.v1beta1.GenericAuthorization")]
+pub struct GenericAuthorization {
+    /// Type URL of the authorized message.
+    pub msg: String,
+}
+
+impl GenericAuthorization {
+    pub fn new(msg_type_url: impl Into<String>) -> Self {
+        Self {
+            msg: msg_type_url.into(),
+        }
+    }
+
+    /// Authorizes every message of type `M`.
+    pub fn of<M: MsgType>() -> Self {
+        Self::new(M::TYPE_URL)
+    }
+}
+
+impl ProtoMsg for GenericAuthorization {
+    type Proto = proto::GenericAuthorization;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::GenericAuthorization {
+            msg: self.msg.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self { msg: proto.msg })
+    }
+}
+
+/// Allows the grantee to send up to `spend_limit` from the granter's account.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("msgauth/SendAuthorization")]
+#[msgtype_pb("/cosmos.bank.v1beta1.SendAuthorization")]
+pub struct SendAuthorization {
+    pub spend_limit: Coins,
+}
+
+impl SendAuthorization {
+    pub fn new(spend_limit: impl Into<Coins>) -> Self {
+        Self {
+            spend_limit: spend_limit.into(),
+        }
+    }
+}
+
+impl ProtoMsg for SendAuthorization {
+    type Proto = proto::SendAuthorization;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::SendAuthorization {
+            spend_limit: coins_to_proto(&self.spend_limit),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            spend_limit: coins_from_proto(proto.spend_limit)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum AuthorizationType {
+    #[default]
+    #[serde(rename = "AUTHORIZATION_TYPE_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "AUTHORIZATION_TYPE_DELEGATE")]
+    Delegate,
+    #[serde(rename = "AUTHORIZATION_TYPE_UNDELEGATE")]
+    Undelegate,
+    #[serde(rename = "AUTHORIZATION_TYPE_REDELEGATE")]
+    Redelegate,
+}
+
+impl AuthorizationType {
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(AuthorizationType::Unspecified),
+            1 => Ok(AuthorizationType::Delegate),
+            2 => Ok(AuthorizationType::Undelegate),
+            3 => Ok(AuthorizationType::Redelegate),
+            other => Err(format!("invalid authorization type: {}", other)),
+        }
+    }
+
+    /// Type URL of the staking message this authorization covers.
+    pub fn msg_type_url(&self) -> Option<&'static str> {
+        match self {
+            AuthorizationType::Unspecified => None,
+            AuthorizationType::Delegate => Some(MsgDelegate::TYPE_URL),
+            AuthorizationType::Undelegate => Some(MsgUndelegate::TYPE_URL),
+            AuthorizationType::Redelegate => Some(MsgBeginRedelegate::TYPE_URL),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct Validators {
+    pub address: Vec<ValAddress>,
+}
+
+/// Validators a `StakeAuthorization` is restricted to or excluded from.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeValidators {
+    AllowList(Validators),
+    DenyList(Validators),
+}
+
+impl StakeValidators {
+    pub fn allow(validators: Vec<ValAddress>) -> Self {
+        StakeValidators::AllowList(Validators {
+            address: validators,
+        })
+    }
+
+    pub fn deny(validators: Vec<ValAddress>) -> Self {
+        StakeValidators::DenyList(Validators {
+            address: validators,
+        })
+    }
+
+    pub fn permits(&self, validator: &ValAddress) -> bool {
+        match self {
+            StakeValidators::AllowList(list) => list.address.contains(validator),
+            StakeValidators::DenyList(list) => !list.address.contains(validator),
+        }
+    }
+}
+
+/// Allows the grantee to delegate, undelegate or redelegate on the granter's
+/// behalf, optionally capped at `max_tokens`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("msgauth/StakeAuthorization")]
+#[msgtype_pb("/cosmos.staking.v1beta1.StakeAuthorization")]
+pub struct StakeAuthorization {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<Coin>,
+    #[serde(flatten)]
+    pub validators: StakeValidators,
+    pub authorization_type: AuthorizationType,
+}
+
+impl StakeAuthorization {
+    pub fn new(
+        authorization_type: AuthorizationType,
+        validators: StakeValidators,
+        max_tokens: Option<Coin>,
+    ) -> Self {
+        Self {
+            max_tokens,
+            validators,
+            authorization_type,
+        }
+    }
+}
+
+impl ProtoMsg for StakeAuthorization {
+    type Proto = proto::StakeAuthorization;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        let list = |list: &Validators| proto::Validators {
+            address: list.address.iter().map(ToString::to_string).collect(),
+        };
+        let (allow_list, deny_list) = match &self.validators {
+            StakeValidators::AllowList(validators) => (Some(list(validators)), None),
+            StakeValidators::DenyList(validators) => (None, Some(list(validators))),
+        };
+        Ok(proto::StakeAuthorization {
+            max_tokens: self.max_tokens.as_ref().map(Into::into),
+            allow_list,
+            deny_list,
+            authorization_type: self.authorization_type as i32,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        let list = |list: proto::Validators| Validators {
+            address: list.address.into_iter().map(Into::into).collect(),
+        };
+        let validators = match (proto.allow_list, proto.deny_list) {
+            (Some(allow), None) => StakeValidators::AllowList(list(allow)),
+            (None, Some(deny)) => StakeValidators::DenyList(list(deny)),
+            _ => return Err("expected exactly one of allow_list and deny_list".into()),
+        };
+        Ok(Self {
+            max_tokens: proto
+                .max_tokens
+                .map(|coin| coin_from_proto(Some(coin)))
+                .transpose()?,
+            validators,
+            authorization_type: AuthorizationType::from_i32(proto.authorization_type)?,
+        })
+    }
+}
+
+any_enum! {
+    /// An authorization carried by a `Grant`.
+    pub enum Authorization: "authorization" {
+        Generic(GenericAuthorization),
+        Send(SendAuthorization),
+        Stake(StakeAuthorization),
+    }
+}
+
+impl Authorization {
+    /// Type URL of the message this authorization lets the grantee execute,
+    /// as passed to `MsgRevoke`.
+    pub fn msg_type_url(&self) -> Option<&str> {
+        match self {
+            Authorization::Generic(a) => Some(&a.msg),
+            Authorization::Send(_) => Some(bank::MsgSend::TYPE_URL),
+            Authorization::Stake(a) => a.authorization_type.msg_type_url(),
+        }
+    }
+}
+
+/// An authorization together with the time it expires, if ever.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Grant {
+    pub authorization: Authorization,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl Grant {
+    pub fn new(authorization: impl Into<Authorization>, expiration: Option<DateTime<Utc>>) -> Self {
+        Self {
+            authorization: authorization.into(),
+            expiration,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expiration.is_some_and(|expiration| expiration <= now)
+    }
+
+    pub(crate) fn to_proto(&self) -> Result<proto::Grant, String> {
+        Ok(proto::Grant {
+            authorization: Some(self.authorization.to_any()?),
+            expiration: self.expiration.as_ref().map(timestamp_to_proto),
+        })
+    }
+
+    pub(crate) fn from_proto(proto: proto::Grant) -> Result<Self, String> {
+        Ok(Self {
+            authorization: Authorization::from_any(
+                &proto.authorization.ok_or("missing authorization")?,
+            )?,
+            expiration: proto.expiration.map(timestamp_from_proto).transpose()?,
+        })
+    }
+}
+
+/// A grant as returned by the `cosmos/authz/v1beta1/grants` queries.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct GrantAuthorization {
+    #[serde(default)]
+    pub granter: Option<AccAddress>,
+    #[serde(default)]
+    pub grantee: Option<AccAddress>,
+    pub authorization: Authorization,
+    #[serde(default)]
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coin, CoinMacroAmount, Msg};
+    use serde_json::json;
+
+    const VALIDATOR: &str = "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk";
+
+    #[test]
+    fn it_serializes_stake_authorization() {
+        let authorization = StakeAuthorization::new(
+            AuthorizationType::Delegate,
+            StakeValidators::allow(vec![VALIDATOR.into()]),
+            Some(coin!("uluna", 1000000)),
+        );
+        assert_eq!(
+            authorization.to_data().unwrap(),
+            json!({
+                "@type": "/cosmos.staking.v1beta1.StakeAuthorization",
+                "max_tokens": { "denom": "uluna", "amount": "1000000" },
+                "allow_list": { "address": [VALIDATOR] },
+                "authorization_type": "AUTHORIZATION_TYPE_DELEGATE"
+            })
+        );
+        assert_eq!(
+            StakeAuthorization::from_data(&authorization.to_data().unwrap()).unwrap(),
+            authorization
+        );
+        assert!(authorization.validators.permits(&VALIDATOR.into()));
+
+        let authorization: Authorization = authorization.into();
+        assert_eq!(
+            authorization.msg_type_url(),
+            Some("/cosmos.staking.v1beta1.MsgDelegate")
+        );
+        assert_eq!(
+            Authorization::from_any(&authorization.to_any().unwrap()).unwrap(),
+            authorization
+        );
+    }
+
+    #[test]
+    fn it_packs_grants() {
+        let expiration = "2023-01-01T00:00:00Z".parse().unwrap();
+        let grant = Grant::new(SendAuthorization::new("100uusd"), Some(expiration));
+        assert_eq!(Grant::from_proto(grant.to_proto().unwrap()).unwrap(), grant);
+        assert!(grant.is_expired(expiration));
+        assert_eq!(
+            serde_json::to_value(&grant).unwrap(),
+            json!({
+                "authorization": {
+                    "type": "msgauth/SendAuthorization",
+                    "value": { "spend_limit": [{ "denom": "uusd", "amount": "100" }] }
+                },
+                "expiration": "2023-01-01T00:00:00Z"
+            })
+        );
+    }
+}
@@ -1,2 +1,7 @@
+pub mod authorization;
+pub use authorization::*;
+
 pub mod msgs;
 pub use msgs::*;
+
+pub mod proto;
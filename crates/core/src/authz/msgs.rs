@@ -1,17 +1,188 @@
-pub struct MsgRevokeAuthorization {
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::authz::{proto, Grant};
+use crate::{AccAddress, Any, Msg, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("msgauth/MsgGrantAuthorization")]
+#[msgtype_pb("/cosmos.authz.v1beta1.MsgGrant")]
+pub struct MsgGrant {
     pub granter: AccAddress,
     pub grantee: AccAddress,
-    pub msg_type_url: String,
+    pub grant: Grant,
+}
+
+impl MsgGrant {
+    pub fn new(
+        granter: impl Into<AccAddress>,
+        grantee: impl Into<AccAddress>,
+        grant: Grant,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            grant,
+        }
+    }
+}
+
+impl ProtoMsg for MsgGrant {
+    type Proto = proto::MsgGrant;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgGrant {
+            granter: self.granter.to_string(),
+            grantee: self.grantee.to_string(),
+            grant: Some(self.grant.to_proto()?),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            granter: proto.granter.into(),
+            grantee: proto.grantee.into(),
+            grant: Grant::from_proto(proto.grant.ok_or("missing grant")?)?,
+        })
+    }
 }
 
-pub struct MsgGrantAuthorization {
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("msgauth/MsgRevokeAuthorization")]
+#[msgtype_pb("/cosmos.authz.v1beta1.MsgRevoke")]
+pub struct MsgRevoke {
     pub granter: AccAddress,
     pub grantee: AccAddress,
-    /// TODO: type
-    pub grant: String,
+    pub msg_type_url: String,
+}
+
+impl MsgRevoke {
+    pub fn new(
+        granter: impl Into<AccAddress>,
+        grantee: impl Into<AccAddress>,
+        msg_type_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            msg_type_url: msg_type_url.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgRevoke {
+    type Proto = proto::MsgRevoke;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgRevoke {
+            granter: self.granter.to_string(),
+            grantee: self.grantee.to_string(),
+            msg_type_url: self.msg_type_url.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            granter: proto.granter.into(),
+            grantee: proto.grantee.into(),
+            msg_type_url: proto.msg_type_url,
+        })
+    }
 }
 
-pub struct MsgExecAuthorized {
+/// Executes `msgs` as the grantee, each signed for by its granter through an
+/// existing grant. JSON conversion of the packed messages goes through
+/// `crate::registry`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("msgauth/MsgExecAuthorized")]
+#[msgtype_pb("/cosmos.authz.v1beta1.MsgExec")]
+pub struct MsgExec {
     pub grantee: AccAddress,
-    pub msgs: Vec<dyn Msg>,
+    #[serde(with = "crate::registry::serde_msgs")]
+    pub msgs: Vec<Any>,
+}
+
+impl MsgExec {
+    pub fn new(grantee: impl Into<AccAddress>, msgs: Vec<Any>) -> Self {
+        Self {
+            grantee: grantee.into(),
+            msgs,
+        }
+    }
+
+    /// Wraps a message built for the granter so `grantee` can execute it.
+    pub fn wrap<M: Msg>(grantee: impl Into<AccAddress>, msg: &M) -> Result<Self, String> {
+        Ok(Self::new(grantee, vec![msg.to_any()?]))
+    }
+
+    pub fn push<M: Msg>(mut self, msg: &M) -> Result<Self, String> {
+        self.msgs.push(msg.to_any()?);
+        Ok(self)
+    }
+}
+
+impl ProtoMsg for MsgExec {
+    type Proto = proto::MsgExec;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgExec {
+            grantee: self.grantee.to_string(),
+            msgs: self.msgs.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            grantee: proto.grantee.into(),
+            msgs: proto.msgs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authz::GenericAuthorization;
+    use crate::bank::MsgSend;
+    use crate::{Coins, JsonSer};
+    use serde_json::json;
+
+    const GRANTER: &str = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+    const GRANTEE: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+
+    #[test]
+    fn it_wraps_messages_in_exec() {
+        let send = MsgSend::new(GRANTER, GRANTEE, Coins::from("1000uluna"));
+        let exec = MsgExec::wrap(GRANTEE, &send).unwrap();
+        assert_eq!(MsgSend::from_any(&exec.msgs[0]).unwrap(), send);
+        assert_eq!(MsgExec::from_any(&exec.to_any().unwrap()).unwrap(), exec);
+
+        let json = exec.to_json().unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "type": "msgauth/MsgExecAuthorized",
+                "value": { "grantee": GRANTEE, "msgs": [send.to_json().unwrap()] }
+            })
+        );
+        assert_eq!(MsgExec::from_amino(&json).unwrap(), exec);
+
+        let data = exec.to_data().unwrap();
+        assert_eq!(data["msgs"][0]["@type"], "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(data["msgs"][0], send.to_data().unwrap());
+        assert_eq!(MsgExec::from_data(&data).unwrap(), exec);
+        // Amino JSON is unaffected by an enclosing `to_data`.
+        assert_eq!(exec.to_json().unwrap(), json);
+
+        let grant = MsgGrant::new(
+            GRANTER,
+            GRANTEE,
+            Grant::new(GenericAuthorization::of::<MsgSend>(), None),
+        );
+        assert_eq!(MsgGrant::from_any(&grant.to_any().unwrap()).unwrap(), grant);
+        assert_eq!(
+            MsgGrant::from_data(&grant.to_data().unwrap()).unwrap(),
+            grant
+        );
+    }
 }
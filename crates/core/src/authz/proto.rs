@@ -0,0 +1,77 @@
+//! Protobuf counterparts of the `cosmos.authz.v1beta1` types, plus the
+//! authorizations defined by the bank and staking modules.
+
+use prost::Message;
+
+use crate::sdk::proto::{Coin, Timestamp};
+use crate::Any;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct GenericAuthorization {
+    #[prost(string, tag = "1")]
+    pub msg: String,
+}
+
+/// `cosmos.bank.v1beta1.SendAuthorization`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct SendAuthorization {
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<Coin>,
+}
+
+/// `cosmos.staking.v1beta1.StakeAuthorization.Validators`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Validators {
+    #[prost(string, repeated, tag = "1")]
+    pub address: Vec<String>,
+}
+
+/// `cosmos.staking.v1beta1.StakeAuthorization`. At most one of `allow_list`
+/// and `deny_list` is set (a `oneof` on the wire).
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct StakeAuthorization {
+    #[prost(message, optional, tag = "1")]
+    pub max_tokens: Option<Coin>,
+    #[prost(message, optional, tag = "2")]
+    pub allow_list: Option<Validators>,
+    #[prost(message, optional, tag = "3")]
+    pub deny_list: Option<Validators>,
+    #[prost(int32, tag = "4")]
+    pub authorization_type: i32,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Grant {
+    #[prost(message, optional, tag = "1")]
+    pub authorization: Option<Any>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgGrant {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub grant: Option<Grant>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgRevoke {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(string, tag = "3")]
+    pub msg_type_url: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: Vec<Any>,
+}
@@ -1,2 +1,7 @@
 pub mod msgs;
 pub use msgs::*;
+
+pub mod multi_send;
+pub use multi_send::*;
+
+pub mod proto;
@@ -1,7 +1,12 @@
-use crate::{AccAddress, Coins, JsonSer};
+use crate::bank::proto;
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::{AccAddress, Coins, ProtoMsg};
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+use terra_sdk_internal::MsgType;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("bank/MsgSend")]
+#[msgtype_pb("/cosmos.bank.v1beta1.MsgSend")]
 pub struct MsgSend {
     pub from_address: AccAddress,
     pub to_address: AccAddress,
@@ -22,39 +27,225 @@ impl MsgSend {
     }
 }
 
-impl JsonSer for MsgSend {
-    fn to_json(&self) -> Result<Value, String> {
-        Ok(json!(
-            {
-                "type": "bank/send",
-                "value": serde_json::to_value(self).map_err(|_| "failed to serialize MsgSend")?,
-            }
-        ))
+impl ProtoMsg for MsgSend {
+    type Proto = proto::MsgSend;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgSend {
+            from_address: self.from_address.to_string(),
+            to_address: self.to_address.to_string(),
+            amount: coins_to_proto(&self.amount),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            from_address: proto.from_address.into(),
+            to_address: proto.to_address.into(),
+            amount: coins_from_proto(proto.amount)?,
+        })
     }
 }
 
-// pub struct MsgMultiSend {
-//     pub inputs: MsgMultiSendInput,
-//     pub outputs: MsgMultiSendOutput,
-// }
+/// Coins leaving an account in a `MsgMultiSend`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Input {
+    pub address: AccAddress,
+    pub coins: Coins,
+}
+
+/// Coins arriving at an account in a `MsgMultiSend`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Output {
+    pub address: AccAddress,
+    pub coins: Coins,
+}
+
+impl Input {
+    pub fn new(address: impl Into<AccAddress>, coins: impl Into<Coins>) -> Self {
+        Self {
+            address: address.into(),
+            coins: coins.into(),
+        }
+    }
+}
+
+impl Output {
+    pub fn new(address: impl Into<AccAddress>, coins: impl Into<Coins>) -> Self {
+        Self {
+            address: address.into(),
+            coins: coins.into(),
+        }
+    }
+}
+
+fn validate_io(address: &AccAddress, coins: &Coins) -> Result<(), String> {
+    if !address.is_valid() {
+        return Err(format!("invalid address: {}", address));
+    }
+    if coins.is_empty() {
+        return Err(format!("no coins for {}", address));
+    }
+    if let Some(coin) = coins.iter().find(|c| c.amount.u128() == 0) {
+        return Err(format!("zero amount of {} for {}", coin.denom, address));
+    }
+    Ok(())
+}
+
+impl ProtoMsg for Input {
+    type Proto = proto::Input;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::Input {
+            address: self.address.to_string(),
+            coins: coins_to_proto(&self.coins),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.address, coins_from_proto(proto.coins)?))
+    }
+}
+
+impl ProtoMsg for Output {
+    type Proto = proto::Output;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::Output {
+            address: self.address.to_string(),
+            coins: coins_to_proto(&self.coins),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.address, coins_from_proto(proto.coins)?))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("bank/MsgMultiSend")]
+#[msgtype_pb("/cosmos.bank.v1beta1.MsgMultiSend")]
+pub struct MsgMultiSend {
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+}
+
+impl MsgMultiSend {
+    pub fn new(inputs: Vec<Input>, outputs: Vec<Output>) -> Self {
+        Self { inputs, outputs }
+    }
+
+    /// `None` if a denom overflows.
+    pub fn total_inputs(&self) -> Option<Coins> {
+        self.inputs
+            .iter()
+            .try_fold(Coins::new(), |acc, input| acc.checked_add(&input.coins))
+    }
+
+    /// `None` if a denom overflows.
+    pub fn total_outputs(&self) -> Option<Coins> {
+        self.outputs
+            .iter()
+            .try_fold(Coins::new(), |acc, output| acc.checked_add(&output.coins))
+    }
+
+    /// Performs the same checks as the chain's `ValidateBasic`: at least one
+    /// input and output, valid addresses, positive amounts, and input totals
+    /// equal to output totals.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.inputs.is_empty() {
+            return Err("MsgMultiSend has no inputs".into());
+        }
+        if self.outputs.is_empty() {
+            return Err("MsgMultiSend has no outputs".into());
+        }
+        for input in self.inputs.iter() {
+            validate_io(&input.address, &input.coins)?;
+        }
+        for output in self.outputs.iter() {
+            validate_io(&output.address, &output.coins)?;
+        }
+        let total_in = self.total_inputs().ok_or("coin overflow")?;
+        let total_out = self.total_outputs().ok_or("coin overflow")?;
+        if total_in != total_out {
+            return Err(format!(
+                "sum inputs != sum outputs: {} != {}",
+                total_in, total_out
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl ProtoMsg for MsgMultiSend {
+    type Proto = proto::MsgMultiSend;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgMultiSend {
+            inputs: self
+                .inputs
+                .iter()
+                .map(Input::to_proto)
+                .collect::<Result<_, _>>()?,
+            outputs: self
+                .outputs
+                .iter()
+                .map(Output::to_proto)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            inputs: proto
+                .inputs
+                .into_iter()
+                .map(Input::from_proto)
+                .collect::<Result<_, _>>()?,
+            outputs: proto
+                .outputs
+                .into_iter()
+                .map(Output::from_proto)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use serde::*;
-    use serde_json::*;
+    use crate::{Coin, JsonSer, Msg};
+    use serde_json::json;
 
-    const MSG: &MsgSend = &MsgSend {
-        from_address: "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
-        to_address: "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
-        amount: "1000000uluna,2000000uusd",
-    };
+    fn msg() -> MsgSend {
+        MsgSend::new(
+            "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+            "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+            "1000000uluna,2000000uusd",
+        )
+    }
 
     #[test]
     fn it_serializes_to_json() {
-        let item = MSG.to_json().as_str();
+        let item = msg().to_json().unwrap();
+        assert_eq!(
+            item,
+            json!({
+                "type": "bank/MsgSend",
+                "value": {
+                    "from_address": "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+                    "to_address": "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+                    "amount": [
+                        { "denom": "uluna", "amount": "1000000" },
+                        { "denom": "uusd", "amount": "2000000" }
+                    ]
+                }
+            })
+        );
+        assert_eq!(MsgSend::from_amino(&item).unwrap(), msg());
     }
+
     #[test]
     fn it_serializes() {
         let msg = MsgSend {
@@ -80,4 +271,54 @@ mod tests {
         .unwrap();
         assert_eq!(msg, msg2);
     }
+
+    #[test]
+    fn it_packs_into_any() {
+        let any = msg().to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(MsgSend::from_any(&any).unwrap(), msg());
+        assert_eq!(
+            msg().to_data().unwrap()["@type"],
+            "/cosmos.bank.v1beta1.MsgSend"
+        );
+    }
+
+    #[test]
+    fn it_validates_multi_send() {
+        let alice = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+        let bob = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+        let mut msg = MsgMultiSend::new(
+            vec![Input::new(alice, "300uluna,10uusd")],
+            vec![
+                Output::new(bob, "100uluna"),
+                Output::new(alice, "200uluna,10uusd"),
+            ],
+        );
+        assert!(msg.validate().is_ok());
+
+        let any = msg.to_any().unwrap();
+        assert_eq!(MsgMultiSend::from_any(&any).unwrap(), msg);
+
+        msg.outputs.pop();
+        assert!(msg.validate().is_err());
+        msg.outputs.clear();
+        assert!(msg.validate().is_err());
+    }
+
+    #[test]
+    fn it_rejects_overflowing_multi_send() {
+        let alice = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+        let bob = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+        let max = Coins::from(vec![Coin::new("uluna", u128::MAX)]);
+        // Wrapped around, the inputs would add up to the outputs.
+        let msg = MsgMultiSend::new(
+            vec![Input::new(alice, max.clone()), Input::new(bob, max)],
+            vec![Output::new(
+                bob,
+                Coins::from(vec![Coin::new("uluna", u128::MAX - 1)]),
+            )],
+        );
+        assert_eq!(msg.total_inputs(), None);
+        assert_eq!(msg.validate(), Err("coin overflow".into()));
+    }
 }
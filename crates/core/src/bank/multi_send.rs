@@ -0,0 +1,183 @@
+use prost::encoding::encoded_len_varint;
+use prost::Message;
+
+use crate::bank::{Input, MsgMultiSend, Output};
+use crate::{AccAddress, Coins, ProtoMsg};
+
+/// Linear gas estimate for a `MsgMultiSend`: `base + per_output * outputs`
+/// must not exceed `limit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasBudget {
+    pub base: u64,
+    pub per_output: u64,
+    pub limit: u64,
+}
+
+impl GasBudget {
+    pub fn max_outputs(&self) -> usize {
+        if self.per_output == 0 {
+            return usize::MAX;
+        }
+        (self.limit.saturating_sub(self.base) / self.per_output) as usize
+    }
+}
+
+/// Splits a payout list from a single sender into as few `MsgMultiSend`
+/// messages as possible, each within the configured output count, encoded
+/// size and gas budget. Outputs keep their original order.
+#[derive(Debug, Clone)]
+pub struct MultiSendBuilder {
+    sender: AccAddress,
+    outputs: Vec<Output>,
+    max_outputs: Option<usize>,
+    max_bytes: Option<usize>,
+    gas_budget: Option<GasBudget>,
+}
+
+/// Length of a message when embedded as a length-delimited field.
+fn field_len<M: Message>(msg: &M) -> usize {
+    let len = msg.encoded_len();
+    1 + encoded_len_varint(len as u64) + len
+}
+
+impl MultiSendBuilder {
+    pub fn new(sender: impl Into<AccAddress>) -> Self {
+        Self {
+            sender: sender.into(),
+            outputs: vec![],
+            max_outputs: None,
+            max_bytes: None,
+            gas_budget: None,
+        }
+    }
+
+    pub fn add_output(mut self, address: impl Into<AccAddress>, coins: impl Into<Coins>) -> Self {
+        self.outputs.push(Output::new(address, coins));
+        self
+    }
+
+    pub fn add_outputs(mut self, outputs: impl IntoIterator<Item = Output>) -> Self {
+        self.outputs.extend(outputs);
+        self
+    }
+
+    /// Maximum number of outputs in a single message.
+    pub fn max_outputs(mut self, max_outputs: usize) -> Self {
+        self.max_outputs = Some(max_outputs);
+        self
+    }
+
+    /// Maximum protobuf-encoded size of a single message, in bytes.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    pub fn gas_budget(mut self, gas_budget: GasBudget) -> Self {
+        self.gas_budget = Some(gas_budget);
+        self
+    }
+
+    fn fits(&self, outputs: usize, outputs_len: usize, total: &Coins) -> Result<bool, String> {
+        if let Some(max) = self.max_outputs {
+            if outputs > max {
+                return Ok(false);
+            }
+        }
+        if let Some(budget) = self.gas_budget {
+            if outputs > budget.max_outputs() {
+                return Ok(false);
+            }
+        }
+        if let Some(max) = self.max_bytes {
+            let input = Input::new(self.sender.clone(), total.clone()).to_proto()?;
+            if field_len(&input) + outputs_len > max {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn flush(&self, batch: &mut Vec<Output>, total: &mut Coins) -> Result<MsgMultiSend, String> {
+        let msg = MsgMultiSend::new(
+            vec![Input::new(self.sender.clone(), std::mem::take(total))],
+            std::mem::take(batch),
+        );
+        msg.validate()?;
+        Ok(msg)
+    }
+
+    pub fn build(self) -> Result<Vec<MsgMultiSend>, String> {
+        let mut msgs = vec![];
+        let mut batch: Vec<Output> = vec![];
+        let mut total = Coins::new();
+        let mut outputs_len = 0;
+
+        for output in self.outputs.iter() {
+            let len = field_len(&output.to_proto()?);
+            let next_total = total.checked_add(&output.coins).ok_or("coin overflow")?;
+            if !batch.is_empty() && !self.fits(batch.len() + 1, outputs_len + len, &next_total)? {
+                msgs.push(self.flush(&mut batch, &mut total)?);
+                outputs_len = 0;
+            }
+            if batch.is_empty() && !self.fits(1, len, &output.coins)? {
+                return Err(format!(
+                    "output to {} does not fit in a single MsgMultiSend",
+                    output.address
+                ));
+            }
+            total = total.checked_add(&output.coins).ok_or("coin overflow")?;
+            outputs_len += len;
+            batch.push(output.clone());
+        }
+
+        if !batch.is_empty() {
+            msgs.push(self.flush(&mut batch, &mut total)?);
+        }
+        Ok(msgs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+    const RECIPIENT: &str = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+
+    fn payouts(n: usize) -> MultiSendBuilder {
+        (0..n).fold(MultiSendBuilder::new(SENDER), |b, i| {
+            b.add_output(RECIPIENT, format!("{}uusd", i + 1).as_str())
+        })
+    }
+
+    #[test]
+    fn it_splits_by_output_count() {
+        let msgs = payouts(10).max_outputs(4).build().unwrap();
+        assert_eq!(
+            msgs.iter().map(|m| m.outputs.len()).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert_eq!(msgs[0].inputs[0].coins, Coins::from("10uusd"));
+    }
+
+    #[test]
+    fn it_splits_by_gas_and_size() {
+        let budget = GasBudget {
+            base: 100_000,
+            per_output: 20_000,
+            limit: 200_000,
+        };
+        let msgs = payouts(12).gas_budget(budget).build().unwrap();
+        assert_eq!(msgs.len(), 3);
+
+        let msgs = payouts(12).max_bytes(400).build().unwrap();
+        for msg in msgs.iter() {
+            assert!(msg.to_proto().unwrap().encoded_len() <= 400);
+        }
+        let outputs: usize = msgs.iter().map(|m| m.outputs.len()).sum();
+        assert_eq!(outputs, 12);
+
+        assert!(payouts(1).max_bytes(10).build().is_err());
+    }
+}
@@ -0,0 +1,39 @@
+//! Protobuf counterparts of the `cosmos.bank.v1beta1` messages.
+
+use prost::Message;
+
+use crate::sdk::proto::Coin;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgSend {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Input {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    #[prost(message, repeated, tag = "2")]
+    pub coins: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Output {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    #[prost(message, repeated, tag = "2")]
+    pub coins: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgMultiSend {
+    #[prost(message, repeated, tag = "1")]
+    pub inputs: Vec<Input>,
+    #[prost(message, repeated, tag = "2")]
+    pub outputs: Vec<Output>,
+}
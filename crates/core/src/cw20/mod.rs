@@ -0,0 +1,5 @@
+pub mod msgs;
+pub use msgs::*;
+
+pub mod token;
+pub use token::*;
@@ -0,0 +1,177 @@
+//! Message and response types of the CW20 token standard.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AccAddress, Uint128};
+
+/// When an allowance expires.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Expiration {
+    AtHeight(u64),
+    /// Nanoseconds since the Unix epoch.
+    AtTime(#[serde(with = "crate::internal::serde_str")] u64),
+    Never {},
+}
+
+impl Default for Expiration {
+    fn default() -> Self {
+        Expiration::Never {}
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20ExecuteMsg {
+    Transfer {
+        recipient: AccAddress,
+        amount: Uint128,
+    },
+    Burn {
+        amount: Uint128,
+    },
+    /// Transfers to a contract and calls its `receive` hook with `msg`.
+    Send {
+        contract: AccAddress,
+        amount: Uint128,
+        #[serde(with = "crate::internal::serde_base64")]
+        msg: Vec<u8>,
+    },
+    IncreaseAllowance {
+        spender: AccAddress,
+        amount: Uint128,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        expires: Option<Expiration>,
+    },
+    DecreaseAllowance {
+        spender: AccAddress,
+        amount: Uint128,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        expires: Option<Expiration>,
+    },
+    TransferFrom {
+        owner: AccAddress,
+        recipient: AccAddress,
+        amount: Uint128,
+    },
+    SendFrom {
+        owner: AccAddress,
+        contract: AccAddress,
+        amount: Uint128,
+        #[serde(with = "crate::internal::serde_base64")]
+        msg: Vec<u8>,
+    },
+    BurnFrom {
+        owner: AccAddress,
+        amount: Uint128,
+    },
+    Mint {
+        recipient: AccAddress,
+        amount: Uint128,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20QueryMsg {
+    Balance {
+        address: AccAddress,
+    },
+    TokenInfo {},
+    Minter {},
+    Allowance {
+        owner: AccAddress,
+        spender: AccAddress,
+    },
+    AllAllowances {
+        owner: AccAddress,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_after: Option<AccAddress>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
+    },
+    AllAccounts {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_after: Option<AccAddress>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct BalanceResponse {
+    pub balance: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct TokenInfoResponse {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct MinterResponse {
+    pub minter: AccAddress,
+    #[serde(default)]
+    pub cap: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct AllowanceResponse {
+    pub allowance: Uint128,
+    #[serde(default)]
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct AllowanceInfo {
+    pub spender: AccAddress,
+    pub allowance: Uint128,
+    #[serde(default)]
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct AllAllowancesResponse {
+    pub allowances: Vec<AllowanceInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct AllAccountsResponse {
+    pub accounts: Vec<AccAddress>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_cw20_messages() {
+        let msg = Cw20ExecuteMsg::IncreaseAllowance {
+            spender: "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4".into(),
+            amount: Uint128::new(100u32),
+            expires: Some(Expiration::AtTime(1_600_000_000_000_000_000)),
+        };
+        assert_eq!(
+            serde_json::to_value(&msg).unwrap(),
+            json!({
+                "increase_allowance": {
+                    "spender": "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+                    "amount": "100",
+                    "expires": { "at_time": "1600000000000000000" }
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(Cw20QueryMsg::TokenInfo {}).unwrap(),
+            json!({ "token_info": {} })
+        );
+        assert_eq!(
+            serde_json::to_value(Expiration::default()).unwrap(),
+            json!({ "never": {} })
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use serde::Serialize;
+
+use crate::cw20::{Cw20ExecuteMsg, Expiration};
+use crate::wasm::MsgExecuteContract;
+use crate::{AccAddress, Coins, Uint128};
+
+/// Builds `MsgExecuteContract`s against a CW20 token contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cw20Token {
+    pub contract: AccAddress,
+}
+
+impl Cw20Token {
+    pub fn new(contract: impl Into<AccAddress>) -> Self {
+        Self {
+            contract: contract.into(),
+        }
+    }
+
+    pub fn execute(
+        &self,
+        sender: impl Into<AccAddress>,
+        msg: Cw20ExecuteMsg,
+    ) -> MsgExecuteContract<Cw20ExecuteMsg> {
+        MsgExecuteContract::new(sender, self.contract.clone(), msg, Coins::new())
+    }
+
+    pub fn transfer(
+        &self,
+        sender: impl Into<AccAddress>,
+        recipient: impl Into<AccAddress>,
+        amount: impl Into<Uint128>,
+    ) -> MsgExecuteContract<Cw20ExecuteMsg> {
+        self.execute(
+            sender,
+            Cw20ExecuteMsg::Transfer {
+                recipient: recipient.into(),
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Sends tokens to `contract`, which receives `hook` in its `receive`
+    /// handler.
+    pub fn send<H: Serialize + ?Sized>(
+        &self,
+        sender: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        amount: impl Into<Uint128>,
+        hook: &H,
+    ) -> Result<MsgExecuteContract<Cw20ExecuteMsg>, String> {
+        let msg = serde_json::to_vec(hook).map_err(|e| format!("invalid hook message: {}", e))?;
+        Ok(self.execute(
+            sender,
+            Cw20ExecuteMsg::Send {
+                contract: contract.into(),
+                amount: amount.into(),
+                msg,
+            },
+        ))
+    }
+
+    pub fn increase_allowance(
+        &self,
+        sender: impl Into<AccAddress>,
+        spender: impl Into<AccAddress>,
+        amount: impl Into<Uint128>,
+        expires: Option<Expiration>,
+    ) -> MsgExecuteContract<Cw20ExecuteMsg> {
+        self.execute(
+            sender,
+            Cw20ExecuteMsg::IncreaseAllowance {
+                spender: spender.into(),
+                amount: amount.into(),
+                expires,
+            },
+        )
+    }
+
+    pub fn decrease_allowance(
+        &self,
+        sender: impl Into<AccAddress>,
+        spender: impl Into<AccAddress>,
+        amount: impl Into<Uint128>,
+        expires: Option<Expiration>,
+    ) -> MsgExecuteContract<Cw20ExecuteMsg> {
+        self.execute(
+            sender,
+            Cw20ExecuteMsg::DecreaseAllowance {
+                spender: spender.into(),
+                amount: amount.into(),
+                expires,
+            },
+        )
+    }
+
+    pub fn burn(
+        &self,
+        sender: impl Into<AccAddress>,
+        amount: impl Into<Uint128>,
+    ) -> MsgExecuteContract<Cw20ExecuteMsg> {
+        self.execute(
+            sender,
+            Cw20ExecuteMsg::Burn {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Mints new tokens; `sender` must be the token's minter.
+    pub fn mint(
+        &self,
+        sender: impl Into<AccAddress>,
+        recipient: impl Into<AccAddress>,
+        amount: impl Into<Uint128>,
+    ) -> MsgExecuteContract<Cw20ExecuteMsg> {
+        self.execute(
+            sender,
+            Cw20ExecuteMsg::Mint {
+                recipient: recipient.into(),
+                amount: amount.into(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+    use serde_json::json;
+
+    const TOKEN: &str = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+    const SENDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+
+    #[test]
+    fn it_builds_send_with_hook() {
+        let token = Cw20Token::new(TOKEN);
+        let msg = token
+            .send(SENDER, TOKEN, 1000u32, &json!({ "swap": {} }))
+            .unwrap();
+        let json = msg.to_json().unwrap();
+        assert_eq!(json["value"]["contract"], TOKEN);
+        assert_eq!(
+            json["value"]["execute_msg"],
+            json!({ "send": { "contract": TOKEN, "amount": "1000", "msg": "eyJzd2FwIjp7fX0=" } })
+        );
+        assert_eq!(
+            MsgExecuteContract::<Cw20ExecuteMsg>::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+
+        let transfer = token.transfer(SENDER, SENDER, 5u32);
+        assert_eq!(
+            serde_json::to_value(&transfer.execute_msg).unwrap(),
+            json!({ "transfer": { "recipient": SENDER, "amount": "5" } })
+        );
+    }
+}
@@ -1,2 +1,13 @@
 pub mod msgs;
 pub use msgs::*;
+
+pub mod proposals;
+pub use proposals::*;
+
+pub mod rewards;
+pub use rewards::*;
+
+pub mod params;
+pub use params::*;
+
+pub mod proto;
@@ -1,18 +1,166 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::distribution::proto;
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::{AccAddress, Coins, ProtoMsg, ValAddress};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("distribution/MsgWithdrawValidatorCommission")]
+#[msgtype_pb("/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission")]
 pub struct MsgWithdrawValidatorCommission {
     pub validator_address: ValAddress,
 }
 
+impl MsgWithdrawValidatorCommission {
+    pub fn new(validator_address: impl Into<ValAddress>) -> Self {
+        Self {
+            validator_address: validator_address.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgWithdrawValidatorCommission {
+    type Proto = proto::MsgWithdrawValidatorCommission;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgWithdrawValidatorCommission {
+            validator_address: self.validator_address.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.validator_address))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("distribution/MsgWithdrawDelegationReward")]
+#[msgtype_pb("/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward")]
 pub struct MsgWithdrawDelegatorReward {
     pub delegator_address: AccAddress,
     pub validator_address: ValAddress,
 }
 
+impl MsgWithdrawDelegatorReward {
+    pub fn new(
+        delegator_address: impl Into<AccAddress>,
+        validator_address: impl Into<ValAddress>,
+    ) -> Self {
+        Self {
+            delegator_address: delegator_address.into(),
+            validator_address: validator_address.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgWithdrawDelegatorReward {
+    type Proto = proto::MsgWithdrawDelegatorReward;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgWithdrawDelegatorReward {
+            delegator_address: self.delegator_address.to_string(),
+            validator_address: self.validator_address.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.delegator_address, proto.validator_address))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("distribution/MsgModifyWithdrawAddress")]
+#[msgtype_pb("/cosmos.distribution.v1beta1.MsgSetWithdrawAddress")]
 pub struct MsgSetWithdrawAddress {
     pub delegator_address: AccAddress,
     pub withdraw_address: AccAddress,
 }
 
+impl MsgSetWithdrawAddress {
+    pub fn new(
+        delegator_address: impl Into<AccAddress>,
+        withdraw_address: impl Into<AccAddress>,
+    ) -> Self {
+        Self {
+            delegator_address: delegator_address.into(),
+            withdraw_address: withdraw_address.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgSetWithdrawAddress {
+    type Proto = proto::MsgSetWithdrawAddress;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgSetWithdrawAddress {
+            delegator_address: self.delegator_address.to_string(),
+            withdraw_address: self.withdraw_address.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.delegator_address, proto.withdraw_address))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("distribution/MsgFundCommunityPool")]
+#[msgtype_pb("/cosmos.distribution.v1beta1.MsgFundCommunityPool")]
 pub struct MsgFundCommunityPool {
     pub depositor: AccAddress,
     pub amount: Coins,
 }
+
+impl MsgFundCommunityPool {
+    pub fn new(depositor: impl Into<AccAddress>, amount: impl Into<Coins>) -> Self {
+        Self {
+            depositor: depositor.into(),
+            amount: amount.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgFundCommunityPool {
+    type Proto = proto::MsgFundCommunityPool;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgFundCommunityPool {
+            amount: coins_to_proto(&self.amount),
+            depositor: self.depositor.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.depositor, coins_from_proto(proto.amount)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+
+    #[test]
+    fn it_encodes_withdraw_reward() {
+        let msg = MsgWithdrawDelegatorReward::new(
+            "terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0tmam9",
+            "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk",
+        );
+        assert_eq!(
+            msg.to_json().unwrap()["type"],
+            "distribution/MsgWithdrawDelegationReward"
+        );
+        assert_eq!(
+            MsgWithdrawDelegatorReward::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+
+        let fund =
+            MsgFundCommunityPool::new("terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0tmam9", "100uluna");
+        assert_eq!(
+            MsgFundCommunityPool::from_any(&fund.to_any().unwrap()).unwrap(),
+            fund
+        );
+    }
+}
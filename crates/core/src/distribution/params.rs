@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Dec;
+
+/// Parameters of the distribution module.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct DistributionParams {
+    /// Share of fees and inflation sent to the community pool.
+    pub community_tax: Dec,
+    pub base_proposer_reward: Dec,
+    pub bonus_proposer_reward: Dec,
+    pub withdraw_addr_enabled: bool,
+}
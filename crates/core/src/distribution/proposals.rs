@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::distribution::proto;
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::{AccAddress, Coins, ProtoMsg};
+
+/// Proposal to pay `amount` from the community pool to `recipient`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("distribution/CommunityPoolSpendProposal")]
+#[msgtype_pb("/cosmos.distribution.v1beta1.CommunityPoolSpendProposal")]
+pub struct CommunityPoolSpendProposal {
+    pub title: String,
+    pub description: String,
+    pub recipient: AccAddress,
+    pub amount: Coins,
+}
+
+impl ProtoMsg for CommunityPoolSpendProposal {
+    type Proto = proto::CommunityPoolSpendProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::CommunityPoolSpendProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            recipient: self.recipient.to_string(),
+            amount: coins_to_proto(&self.amount),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            recipient: proto.recipient.into(),
+            amount: coins_from_proto(proto.amount)?,
+        })
+    }
+}
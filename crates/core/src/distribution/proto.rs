@@ -0,0 +1,47 @@
+//! Protobuf counterparts of the `cosmos.distribution.v1beta1` messages.
+
+use prost::Message;
+
+use crate::sdk::proto::Coin;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgSetWithdrawAddress {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub withdraw_address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgWithdrawDelegatorReward {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgWithdrawValidatorCommission {
+    #[prost(string, tag = "1")]
+    pub validator_address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgFundCommunityPool {
+    #[prost(message, repeated, tag = "1")]
+    pub amount: Vec<Coin>,
+    #[prost(string, tag = "2")]
+    pub depositor: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CommunityPoolSpendProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub recipient: String,
+    #[prost(message, repeated, tag = "4")]
+    pub amount: Vec<Coin>,
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{NoFee, TxBuilder};
+use crate::distribution::MsgWithdrawDelegatorReward;
+use crate::{AccAddress, DecCoins, ValAddress};
+
+/// Rewards accrued by a delegation to a single validator.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct DelegationRewards {
+    pub validator_address: ValAddress,
+    pub reward: DecCoins,
+}
+
+/// Response of `/cosmos/distribution/v1beta1/delegators/{delegator}/rewards`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct DelegatorTotalRewards {
+    pub rewards: Vec<DelegationRewards>,
+    pub total: DecCoins,
+}
+
+impl DelegatorTotalRewards {
+    /// One `MsgWithdrawDelegatorReward` per validator with at least one
+    /// whole coin of reward; fractional dust stays on chain.
+    pub fn withdraw_all_msgs(
+        &self,
+        delegator: impl Into<AccAddress>,
+    ) -> Vec<MsgWithdrawDelegatorReward> {
+        let delegator = delegator.into();
+        self.rewards
+            .iter()
+            .filter(|r| !r.reward.truncate().0.is_empty())
+            .map(|r| {
+                MsgWithdrawDelegatorReward::new(delegator.clone(), r.validator_address.clone())
+            })
+            .collect()
+    }
+
+    /// A transaction withdrawing all rewards, as in `withdraw_all_msgs`.
+    /// Set the fee and signer before signing.
+    pub fn withdraw_all_tx(
+        &self,
+        delegator: impl Into<AccAddress>,
+    ) -> Result<TxBuilder<NoFee>, String> {
+        self.withdraw_all_msgs(delegator)
+            .iter()
+            .try_fold(TxBuilder::new(), |builder, msg| builder.msg(msg))
+    }
+}
+
+/// Rewards held for a validator: outstanding rewards still owed to its
+/// delegators, and its own withdrawable commission.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ValidatorRewards {
+    pub outstanding_rewards: DecCoins,
+    pub commission: DecCoins,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CommunityPool {
+    pub pool: DecCoins,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{Fee, PrivateKey, Signer, SignerData};
+    use crate::Msg;
+    use serde_json::json;
+
+    #[test]
+    fn it_builds_withdraw_all() {
+        let rewards: DelegatorTotalRewards = serde_json::from_value(json!({
+            "rewards": [
+                {
+                    "validator_address": "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk",
+                    "reward": [{ "denom": "uluna", "amount": "12.500000000000000000" }]
+                },
+                {
+                    "validator_address": "terravaloper1v9ku44wycfnsucez6fp085f5fsksp47uxqqtd0",
+                    "reward": [{ "denom": "uusd", "amount": "0.300000000000000000" }]
+                }
+            ],
+            "total": [
+                { "denom": "uluna", "amount": "12.500000000000000000" },
+                { "denom": "uusd", "amount": "0.300000000000000000" }
+            ]
+        }))
+        .unwrap();
+        let msgs = rewards.withdraw_all_msgs("terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0tmam9");
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(
+            msgs[0].validator_address.as_str(),
+            "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk"
+        );
+
+        let key = PrivateKey::from_bytes(&[1; 32]).unwrap();
+        let unsigned = rewards
+            .withdraw_all_tx(key.acc_address())
+            .unwrap()
+            .fee(Fee::new(200_000, "30000uluna"))
+            .signer(SignerData::new(key.public_key(), 1, 0))
+            .build()
+            .unwrap();
+        let messages = &unsigned.tx().body.messages;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            MsgWithdrawDelegatorReward::from_any(&messages[0]).unwrap(),
+            MsgWithdrawDelegatorReward::new(
+                key.acc_address(),
+                "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk"
+            )
+        );
+    }
+}
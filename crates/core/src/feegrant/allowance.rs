@@ -0,0 +1,375 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::feegrant::proto;
+use crate::sdk::proto::{
+    coins_from_proto, coins_to_proto, duration_from_proto, duration_to_proto, timestamp_from_proto,
+    timestamp_to_proto,
+};
+use crate::{Coins, ProtoMsg};
+
+/// Pays fees up to `spend_limit` (unlimited if empty) until `expiration`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default, MsgType)]
+#[msgtype("feegrant/BasicAllowance")]
+#[msgtype_pb("/cosmos.feegrant.v1beta1.BasicAllowance")]
+pub struct BasicAllowance {
+    #[serde(default)]
+    pub spend_limit: Coins,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl BasicAllowance {
+    pub fn new(spend_limit: impl Into<Coins>, expiration: Option<DateTime<Utc>>) -> Self {
+        Self {
+            spend_limit: spend_limit.into(),
+            expiration,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expiration.is_some_and(|expiration| expiration < now)
+    }
+
+    /// Deducts `fee` the way the chain does when the allowance is used at
+    /// `now`, failing if it has expired or the limit does not cover the fee.
+    pub fn accept(&mut self, fee: &Coins, now: DateTime<Utc>) -> Result<(), String> {
+        if self.is_expired(now) {
+            return Err("fee allowance expired".into());
+        }
+        if !self.spend_limit.is_empty() {
+            self.spend_limit = self
+                .spend_limit
+                .checked_sub(fee)
+                .ok_or("fee limit exceeded")?;
+        }
+        Ok(())
+    }
+}
+
+impl ProtoMsg for BasicAllowance {
+    type Proto = proto::BasicAllowance;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::BasicAllowance {
+            spend_limit: coins_to_proto(&self.spend_limit),
+            expiration: self.expiration.as_ref().map(timestamp_to_proto),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            spend_limit: coins_from_proto(proto.spend_limit)?,
+            expiration: proto.expiration.map(timestamp_from_proto).transpose()?,
+        })
+    }
+}
+
+/// A `BasicAllowance` that can additionally spend at most
+/// `period_spend_limit` per `period`. `period_can_spend` is what is left in
+/// the current period, which ends at `period_reset`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("feegrant/PeriodicAllowance")]
+#[msgtype_pb("/cosmos.feegrant.v1beta1.PeriodicAllowance")]
+pub struct PeriodicAllowance {
+    pub basic: BasicAllowance,
+    #[serde(with = "crate::internal::serde_duration")]
+    pub period: Duration,
+    pub period_spend_limit: Coins,
+    pub period_can_spend: Coins,
+    pub period_reset: DateTime<Utc>,
+}
+
+impl PeriodicAllowance {
+    /// A fresh allowance whose first period starts at `start`.
+    pub fn new(
+        basic: BasicAllowance,
+        period: Duration,
+        period_spend_limit: impl Into<Coins>,
+        start: DateTime<Utc>,
+    ) -> Self {
+        let period_spend_limit = period_spend_limit.into();
+        Self {
+            basic,
+            period,
+            period_can_spend: period_spend_limit.clone(),
+            period_spend_limit,
+            period_reset: add_period(start, period),
+        }
+    }
+
+    /// Starts a new period if `now` is past `period_reset`, refilling
+    /// `period_can_spend` to the lesser of the period and overall limits.
+    /// A reset that was missed by more than a period restarts from `now`.
+    pub fn try_reset_period(&mut self, now: DateTime<Utc>) {
+        if now < self.period_reset {
+            return;
+        }
+        self.period_can_spend = self.capped(&self.period_spend_limit);
+        self.period_reset = add_period(self.period_reset, self.period);
+        if now > self.period_reset {
+            self.period_reset = add_period(now, self.period);
+        }
+    }
+
+    /// What can still be spent in the period containing `now`.
+    pub fn remaining(&self, now: DateTime<Utc>) -> Coins {
+        let mut allowance = self.clone();
+        allowance.try_reset_period(now);
+        allowance.capped(&allowance.period_can_spend)
+    }
+
+    /// When the period containing `now` ends.
+    pub fn next_reset(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let mut allowance = self.clone();
+        allowance.try_reset_period(now);
+        allowance.period_reset
+    }
+
+    pub fn accept(&mut self, fee: &Coins, now: DateTime<Utc>) -> Result<(), String> {
+        if self.basic.is_expired(now) {
+            return Err("fee allowance expired".into());
+        }
+        self.try_reset_period(now);
+        self.period_can_spend = self
+            .period_can_spend
+            .checked_sub(fee)
+            .ok_or("period limit exceeded")?;
+        self.basic.accept(fee, now)
+    }
+
+    // The chain takes the overall limit instead of `coins` whenever the
+    // overall limit is lower in any denom.
+    fn capped(&self, coins: &Coins) -> Coins {
+        let limit = &self.basic.spend_limit;
+        if !limit.is_empty() && limit.checked_sub(coins).is_none() {
+            limit.clone()
+        } else {
+            coins.clone()
+        }
+    }
+}
+
+fn add_period(time: DateTime<Utc>, period: Duration) -> DateTime<Utc> {
+    chrono::Duration::from_std(period)
+        .ok()
+        .and_then(|period| time.checked_add_signed(period))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+impl ProtoMsg for PeriodicAllowance {
+    type Proto = proto::PeriodicAllowance;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::PeriodicAllowance {
+            basic: Some(self.basic.to_proto()?),
+            period: Some(duration_to_proto(&self.period)),
+            period_spend_limit: coins_to_proto(&self.period_spend_limit),
+            period_can_spend: coins_to_proto(&self.period_can_spend),
+            period_reset: Some(timestamp_to_proto(&self.period_reset)),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            basic: BasicAllowance::from_proto(proto.basic.unwrap_or_default())?,
+            period: duration_from_proto(proto.period.unwrap_or_default())?,
+            period_spend_limit: coins_from_proto(proto.period_spend_limit)?,
+            period_can_spend: coins_from_proto(proto.period_can_spend)?,
+            period_reset: timestamp_from_proto(proto.period_reset.unwrap_or_default())?,
+        })
+    }
+}
+
+/// Restricts another allowance to transactions made up only of the given
+/// message types.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("feegrant/AllowedMsgAllowance")]
+#[msgtype_pb("/cosmos.feegrant.v1beta1.AllowedMsgAllowance")]
+pub struct AllowedMsgAllowance {
+    pub allowance: Box<Allowance>,
+    pub allowed_messages: Vec<String>,
+}
+
+impl AllowedMsgAllowance {
+    pub fn new(allowance: impl Into<Allowance>, allowed_messages: Vec<String>) -> Self {
+        Self {
+            allowance: Box::new(allowance.into()),
+            allowed_messages,
+        }
+    }
+
+    pub fn allows(&self, msg_type_url: &str) -> bool {
+        self.allowed_messages.iter().any(|m| m == msg_type_url)
+    }
+}
+
+impl ProtoMsg for AllowedMsgAllowance {
+    type Proto = proto::AllowedMsgAllowance;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::AllowedMsgAllowance {
+            allowance: Some(self.allowance.to_any()?),
+            allowed_messages: self.allowed_messages.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            allowance: Box::new(Allowance::from_any(
+                &proto.allowance.ok_or("missing allowance")?,
+            )?),
+            allowed_messages: proto.allowed_messages,
+        })
+    }
+}
+
+any_enum! {
+    /// A fee allowance granted through `MsgGrantAllowance`.
+    pub enum Allowance: "fee allowance" {
+        Basic(BasicAllowance),
+        Periodic(PeriodicAllowance),
+        AllowedMsg(AllowedMsgAllowance),
+    }
+}
+
+impl Allowance {
+    pub fn expiration(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Allowance::Basic(a) => a.expiration,
+            Allowance::Periodic(a) => a.basic.expiration,
+            Allowance::AllowedMsg(a) => a.allowance.expiration(),
+        }
+    }
+
+    /// Deducts `fee` for a transaction containing messages of the given
+    /// types, as the chain would at `now`.
+    pub fn accept(
+        &mut self,
+        fee: &Coins,
+        msg_type_urls: &[&str],
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        match self {
+            Allowance::Basic(a) => a.accept(fee, now),
+            Allowance::Periodic(a) => a.accept(fee, now),
+            Allowance::AllowedMsg(a) => {
+                if let Some(url) = msg_type_urls.iter().find(|url| !a.allows(url)) {
+                    return Err(format!("message not allowed by fee allowance: {}", url));
+                }
+                a.allowance.accept(fee, msg_type_urls, now)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+
+    fn time(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn it_expires_after_expiration() {
+        let expiration = time("2023-01-01T00:00:00Z");
+        let allowance = BasicAllowance::new("10uluna", Some(expiration));
+        assert!(!allowance.is_expired(expiration));
+        assert!(allowance.is_expired(time("2023-01-01T00:00:01Z")));
+    }
+
+    #[test]
+    fn it_resets_periods() {
+        let start = time("2023-01-01T00:00:00Z");
+        let mut allowance = PeriodicAllowance::new(
+            BasicAllowance::new("1500uluna", None),
+            Duration::from_secs(3600),
+            "1000uluna",
+            start,
+        );
+        allowance
+            .accept(&Coins::from("800uluna"), time("2023-01-01T00:30:00Z"))
+            .unwrap();
+        assert_eq!(allowance.period_can_spend, Coins::from("200uluna"));
+        assert!(allowance
+            .clone()
+            .accept(&Coins::from("300uluna"), time("2023-01-01T00:45:00Z"))
+            .is_err());
+
+        // The next period is capped by the 700uluna left overall.
+        let later = time("2023-01-01T01:10:00Z");
+        assert_eq!(allowance.remaining(later), Coins::from("700uluna"));
+        assert_eq!(allowance.next_reset(later), time("2023-01-01T02:00:00Z"));
+        // A missed reset restarts the period from now.
+        let much_later = time("2023-01-01T05:30:00Z");
+        assert_eq!(
+            allowance.next_reset(much_later),
+            time("2023-01-01T06:30:00Z")
+        );
+    }
+
+    #[test]
+    fn it_packs_allowances() {
+        let start = time("2023-01-01T00:00:00Z");
+        let periodic = PeriodicAllowance::new(
+            BasicAllowance::new(Coins::new(), Some(time("2024-01-01T00:00:00Z"))),
+            Duration::from_secs(86400),
+            "10uusd",
+            start,
+        );
+        let allowance: Allowance = AllowedMsgAllowance::new(
+            periodic.clone(),
+            vec!["/cosmos.bank.v1beta1.MsgSend".into()],
+        )
+        .into();
+        assert_eq!(
+            Allowance::from_any(&allowance.to_any().unwrap()).unwrap(),
+            allowance
+        );
+        assert_eq!(
+            serde_json::from_value::<Allowance>(allowance.to_data().unwrap()).unwrap(),
+            allowance
+        );
+        assert_eq!(periodic.to_data().unwrap()["period"], "86400s");
+        assert_eq!(
+            periodic.to_json().unwrap()["value"]["period"],
+            "86400000000000"
+        );
+        let data = allowance.to_data().unwrap();
+        assert_eq!(
+            data["allowance"]["@type"],
+            "/cosmos.feegrant.v1beta1.PeriodicAllowance"
+        );
+        assert!(allowance
+            .clone()
+            .accept(
+                &Coins::from("1uusd"),
+                &["/cosmos.staking.v1beta1.MsgDelegate"],
+                start
+            )
+            .is_err());
+        let mut allowance = allowance;
+        allowance
+            .accept(
+                &Coins::from("4uusd"),
+                &["/cosmos.bank.v1beta1.MsgSend"],
+                start,
+            )
+            .unwrap();
+        match allowance {
+            Allowance::AllowedMsg(a) => assert_eq!(
+                *a.allowance,
+                Allowance::Periodic(PeriodicAllowance {
+                    period_can_spend: Coins::from("6uusd"),
+                    ..periodic
+                })
+            ),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -1,2 +1,7 @@
+pub mod allowance;
+pub use allowance::*;
+
 pub mod msgs;
 pub use msgs::*;
+
+pub mod proto;
@@ -1,10 +1,83 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::feegrant::{proto, Allowance};
+use crate::{AccAddress, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("feegrant/MsgGrantAllowance")]
+#[msgtype_pb("/cosmos.feegrant.v1beta1.MsgGrantAllowance")]
 pub struct MsgGrantAllowance {
     pub granter: AccAddress,
     pub grantee: AccAddress,
     pub allowance: Allowance,
 }
 
+impl MsgGrantAllowance {
+    pub fn new(
+        granter: impl Into<AccAddress>,
+        grantee: impl Into<AccAddress>,
+        allowance: impl Into<Allowance>,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            allowance: allowance.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgGrantAllowance {
+    type Proto = proto::MsgGrantAllowance;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgGrantAllowance {
+            granter: self.granter.to_string(),
+            grantee: self.grantee.to_string(),
+            allowance: Some(self.allowance.to_any()?),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            granter: proto.granter.into(),
+            grantee: proto.grantee.into(),
+            allowance: Allowance::from_any(&proto.allowance.ok_or("missing allowance")?)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("feegrant/MsgRevokeAllowance")]
+#[msgtype_pb("/cosmos.feegrant.v1beta1.MsgRevokeAllowance")]
 pub struct MsgRevokeAllowance {
     pub granter: AccAddress,
     pub grantee: AccAddress,
 }
+
+impl MsgRevokeAllowance {
+    pub fn new(granter: impl Into<AccAddress>, grantee: impl Into<AccAddress>) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgRevokeAllowance {
+    type Proto = proto::MsgRevokeAllowance;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgRevokeAllowance {
+            granter: self.granter.to_string(),
+            grantee: self.grantee.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            granter: proto.granter.into(),
+            grantee: proto.grantee.into(),
+        })
+    }
+}
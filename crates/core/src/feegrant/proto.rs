@@ -0,0 +1,54 @@
+//! Protobuf counterparts of the `cosmos.feegrant.v1beta1` types.
+
+use prost::Message;
+
+use crate::sdk::proto::{Coin, Duration, Timestamp};
+use crate::Any;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct BasicAllowance {
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<Coin>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct PeriodicAllowance {
+    #[prost(message, optional, tag = "1")]
+    pub basic: Option<BasicAllowance>,
+    #[prost(message, optional, tag = "2")]
+    pub period: Option<Duration>,
+    #[prost(message, repeated, tag = "3")]
+    pub period_spend_limit: Vec<Coin>,
+    #[prost(message, repeated, tag = "4")]
+    pub period_can_spend: Vec<Coin>,
+    #[prost(message, optional, tag = "5")]
+    pub period_reset: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct AllowedMsgAllowance {
+    #[prost(message, optional, tag = "1")]
+    pub allowance: Option<Any>,
+    #[prost(string, repeated, tag = "2")]
+    pub allowed_messages: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgGrantAllowance {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub allowance: Option<Any>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgRevokeAllowance {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+}
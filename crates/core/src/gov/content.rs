@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use terra_sdk_internal::MsgType;
+
+use crate::distribution::CommunityPoolSpendProposal;
+use crate::gov::proto;
+use crate::params::ParameterChangeProposal;
+use crate::upgrade::{CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal};
+use crate::wasm::{
+    ClearAdminProposal, ExecuteContractProposal, InstantiateContractProposal,
+    MigrateContractProposal, PinCodesProposal, StoreCodeProposal, SudoContractProposal,
+    UnpinCodesProposal, UpdateAdminProposal,
+};
+use crate::{registry, Any, ProtoMsg};
+
+/// A proposal with no on-chain effect beyond its text.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("gov/TextProposal")]
+#[msgtype_pb("/cosmos.gov.v1beta1.TextProposal")]
+pub struct TextProposal {
+    pub title: String,
+    pub description: String,
+}
+
+impl TextProposal {
+    pub fn new(title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+        }
+    }
+}
+
+impl ProtoMsg for TextProposal {
+    type Proto = proto::TextProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::TextProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+        })
+    }
+}
+
+/// Proposal content of a type this crate does not model, kept as it was
+/// read so that listing proposals does not fail on it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum UnknownContent {
+    /// Read from protobuf.
+    Any(Any),
+    /// Read from protobuf JSON (`@type`) or Amino JSON (`type`/`value`).
+    Json(Value),
+}
+
+impl UnknownContent {
+    pub fn from_any(any: &Any) -> Self {
+        UnknownContent::Any(any.clone())
+    }
+
+    pub fn from_json(json: &Value) -> Self {
+        UnknownContent::Json(json.clone())
+    }
+
+    /// Empty for content read from Amino JSON.
+    pub fn type_url(&self) -> &str {
+        match self {
+            UnknownContent::Any(any) => &any.type_url,
+            UnknownContent::Json(json) => json_str(json, "@type"),
+        }
+    }
+
+    /// Empty for content not read from Amino JSON.
+    pub fn amino_type(&self) -> &str {
+        match self {
+            UnknownContent::Any(_) => "",
+            UnknownContent::Json(json) => json_str(json, "type"),
+        }
+    }
+
+    /// Packs the content. Content read from JSON can only be packed if its
+    /// type was added to the [registry](crate::registry).
+    pub fn to_any(&self) -> Result<Any, String> {
+        match self {
+            UnknownContent::Any(any) => Ok(any.clone()),
+            UnknownContent::Json(json) => registry::any_from_json(json)
+                .map_err(|e| format!("cannot pack proposal content: {}", e)),
+        }
+    }
+
+    pub fn to_amino(&self) -> Result<Value, String> {
+        match self {
+            UnknownContent::Any(any) => registry::any_to_amino(any),
+            UnknownContent::Json(json) if json.get("type").is_some() => Ok(json.clone()),
+            UnknownContent::Json(json) => registry::any_to_amino(&registry::any_from_data(json)?),
+        }
+    }
+
+    pub fn to_data(&self) -> Result<Value, String> {
+        match self {
+            UnknownContent::Any(any) => registry::any_to_data(any),
+            UnknownContent::Json(json) if json.get("@type").is_some() => Ok(json.clone()),
+            UnknownContent::Json(json) => registry::any_to_data(&registry::any_from_amino(json)?),
+        }
+    }
+}
+
+fn json_str<'a>(json: &'a Value, key: &str) -> &'a str {
+    json.get(key).and_then(Value::as_str).unwrap_or_default()
+}
+
+any_enum! {
+    /// The content of a governance proposal.
+    pub enum Content: "proposal content" {
+        Text(TextProposal),
+        CommunityPoolSpend(CommunityPoolSpendProposal),
+        ParameterChange(ParameterChangeProposal),
+        SoftwareUpgrade(SoftwareUpgradeProposal),
+        CancelSoftwareUpgrade(CancelSoftwareUpgradeProposal),
+        StoreCode(StoreCodeProposal),
+        InstantiateContract(InstantiateContractProposal),
+        MigrateContract(MigrateContractProposal),
+        SudoContract(SudoContractProposal),
+        ExecuteContract(ExecuteContractProposal),
+        UpdateAdmin(UpdateAdminProposal),
+        ClearAdmin(ClearAdminProposal),
+        PinCodes(PinCodesProposal),
+        UnpinCodes(UnpinCodesProposal),
+        ..Other(UnknownContent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::ParamChange;
+    use serde_json::json;
+
+    #[test]
+    fn it_packs_content() {
+        let content: Content = TextProposal::new("title", "description").into();
+        let any = content.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1beta1.TextProposal");
+        assert_eq!(Content::from_any(&any).unwrap(), content);
+
+        let content: Content = ParameterChangeProposal {
+            title: "raise tax".into(),
+            description: "raise the tax cap".into(),
+            changes: vec![ParamChange::new("treasury", "TaxPolicy", "{}")],
+        }
+        .into();
+        assert_eq!(
+            Content::from_any(&content.to_any().unwrap()).unwrap(),
+            content
+        );
+    }
+
+    #[test]
+    fn it_deserializes_both_json_forms() {
+        let expected: Content = TextProposal::new("title", "description").into();
+        let amino: Content = serde_json::from_value(json!({
+            "type": "gov/TextProposal",
+            "value": { "title": "title", "description": "description" }
+        }))
+        .unwrap();
+        let data: Content = serde_json::from_value(json!({
+            "@type": "/cosmos.gov.v1beta1.TextProposal",
+            "title": "title",
+            "description": "description"
+        }))
+        .unwrap();
+        assert_eq!(amino, expected);
+        assert_eq!(data, expected);
+        assert_eq!(
+            serde_json::to_value(&expected).unwrap()["type"],
+            "gov/TextProposal"
+        );
+    }
+
+    #[test]
+    fn it_keeps_unknown_content() {
+        let data = json!({
+            "@type": "/cosmos.gov.v1beta1.NewProposal",
+            "title": "title"
+        });
+        let content: Content = serde_json::from_value(data.clone()).unwrap();
+        assert_eq!(content, Content::Other(UnknownContent::Json(data.clone())));
+        assert_eq!(content.type_url(), "/cosmos.gov.v1beta1.NewProposal");
+        assert_eq!(content.to_data().unwrap(), data);
+        assert!(content.to_amino().is_err());
+
+        let any = Any {
+            type_url: "/cosmos.gov.v1beta1.NewProposal".into(),
+            value: vec![10, 5, 116, 105, 116, 108, 101],
+        };
+        let content = Content::from_any(&any).unwrap();
+        assert_eq!(content.to_any().unwrap(), any);
+    }
+}
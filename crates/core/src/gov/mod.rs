@@ -1,2 +1,16 @@
+pub mod content;
+pub use content::*;
+
 pub mod msgs;
 pub use msgs::*;
+
+pub mod proposal;
+pub use proposal::*;
+
+pub mod vote;
+pub use vote::*;
+
+pub mod params;
+pub use params::*;
+
+pub mod proto;
@@ -1,11 +1,283 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::gov::{proto, validate_weighted_options, Content, VoteOption, WeightedVoteOption};
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::{AccAddress, Coins, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("gov/MsgSubmitProposal")]
+#[msgtype_pb("/cosmos.gov.v1beta1.MsgSubmitProposal")]
+pub struct MsgSubmitProposal {
+    pub content: Content,
+    pub initial_deposit: Coins,
+    pub proposer: AccAddress,
+}
+
+impl MsgSubmitProposal {
+    pub fn new(
+        content: impl Into<Content>,
+        initial_deposit: impl Into<Coins>,
+        proposer: impl Into<AccAddress>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            initial_deposit: initial_deposit.into(),
+            proposer: proposer.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgSubmitProposal {
+    type Proto = proto::MsgSubmitProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgSubmitProposal {
+            content: Some(self.content.to_any()?),
+            initial_deposit: coins_to_proto(&self.initial_deposit),
+            proposer: self.proposer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            content: Content::from_any(&proto.content.ok_or("missing content")?)?,
+            initial_deposit: coins_from_proto(proto.initial_deposit)?,
+            proposer: proto.proposer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("gov/MsgDeposit")]
+#[msgtype_pb("/cosmos.gov.v1beta1.MsgDeposit")]
 pub struct MsgDeposit {
-    pub proposal_id: u128,
+    #[serde(with = "crate::internal::serde_str")]
+    pub proposal_id: u64,
     pub depositor: AccAddress,
     pub amount: Coins,
 }
 
-pub struct MsgSubmitProposal {
-    pub content: Proposal,
-    pub initial_deposit: Coins,
-    pub proposer: AccAddress,
+impl MsgDeposit {
+    pub fn new(
+        proposal_id: u64,
+        depositor: impl Into<AccAddress>,
+        amount: impl Into<Coins>,
+    ) -> Self {
+        Self {
+            proposal_id,
+            depositor: depositor.into(),
+            amount: amount.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgDeposit {
+    type Proto = proto::MsgDeposit;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgDeposit {
+            proposal_id: self.proposal_id,
+            depositor: self.depositor.to_string(),
+            amount: coins_to_proto(&self.amount),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            proposal_id: proto.proposal_id,
+            depositor: proto.depositor.into(),
+            amount: coins_from_proto(proto.amount)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("gov/MsgVote")]
+#[msgtype_pb("/cosmos.gov.v1beta1.MsgVote")]
+pub struct MsgVote {
+    #[serde(with = "crate::internal::serde_str")]
+    pub proposal_id: u64,
+    pub voter: AccAddress,
+    pub option: VoteOption,
+}
+
+impl MsgVote {
+    pub fn new(proposal_id: u64, voter: impl Into<AccAddress>, option: VoteOption) -> Self {
+        Self {
+            proposal_id,
+            voter: voter.into(),
+            option,
+        }
+    }
+}
+
+impl ProtoMsg for MsgVote {
+    type Proto = proto::MsgVote;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgVote {
+            proposal_id: self.proposal_id,
+            voter: self.voter.to_string(),
+            option: self.option as i32,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            proposal_id: proto.proposal_id,
+            voter: proto.voter.into(),
+            option: VoteOption::from_i32(proto.option)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("gov/MsgVoteWeighted")]
+#[msgtype_pb("/cosmos.gov.v1beta1.MsgVoteWeighted")]
+pub struct MsgVoteWeighted {
+    #[serde(with = "crate::internal::serde_str")]
+    pub proposal_id: u64,
+    pub voter: AccAddress,
+    pub options: Vec<WeightedVoteOption>,
+}
+
+impl MsgVoteWeighted {
+    pub fn new(
+        proposal_id: u64,
+        voter: impl Into<AccAddress>,
+        options: Vec<WeightedVoteOption>,
+    ) -> Self {
+        Self {
+            proposal_id,
+            voter: voter.into(),
+            options,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.voter.is_valid() {
+            return Err(format!("invalid voter address: {}", self.voter));
+        }
+        validate_weighted_options(&self.options)
+    }
+}
+
+impl ProtoMsg for MsgVoteWeighted {
+    type Proto = proto::MsgVoteWeighted;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgVoteWeighted {
+            proposal_id: self.proposal_id,
+            voter: self.voter.to_string(),
+            options: self
+                .options
+                .iter()
+                .map(WeightedVoteOption::to_proto)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            proposal_id: proto.proposal_id,
+            voter: proto.voter.into(),
+            options: proto
+                .options
+                .into_iter()
+                .map(WeightedVoteOption::from_proto)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::TxBuilder;
+    use crate::gov::TextProposal;
+    use crate::{dec, DecMacroInput, JsonSer, Msg};
+    use serde_json::json;
+
+    const VOTER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+
+    #[test]
+    fn it_submits_proposal() {
+        let msg = MsgSubmitProposal::new(
+            TextProposal::new("title", "description"),
+            "1000000uluna",
+            VOTER,
+        );
+        let json = msg.to_json().unwrap();
+        assert_eq!(json["value"]["content"]["type"], "gov/TextProposal");
+        assert_eq!(MsgSubmitProposal::from_amino(&json).unwrap(), msg);
+        assert_eq!(
+            MsgSubmitProposal::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+    }
+
+    #[test]
+    fn it_votes() {
+        let msg = MsgVote::new(5, VOTER, VoteOption::NoWithVeto);
+        assert_eq!(
+            msg.to_json().unwrap()["value"],
+            json!({ "proposal_id": "5", "voter": VOTER, "option": 4 })
+        );
+        let from_lcd: MsgVote = serde_json::from_value(json!({
+            "proposal_id": "5", "voter": VOTER, "option": "VOTE_OPTION_NO_WITH_VETO"
+        }))
+        .unwrap();
+        assert_eq!(from_lcd, msg);
+        assert_eq!(MsgVote::from_any(&msg.to_any().unwrap()).unwrap(), msg);
+
+        // As in `terrad tx gov vote --generate-only`.
+        let data = msg.to_data().unwrap();
+        assert_eq!(
+            data,
+            json!({
+                "@type": "/cosmos.gov.v1beta1.MsgVote",
+                "proposal_id": "5",
+                "voter": VOTER,
+                "option": "VOTE_OPTION_NO_WITH_VETO"
+            })
+        );
+        assert_eq!(MsgVote::from_data(&data).unwrap(), msg);
+    }
+
+    #[test]
+    fn it_validates_weighted_votes() {
+        let mut msg = MsgVoteWeighted::new(
+            1,
+            VOTER,
+            vec![
+                WeightedVoteOption::new(VoteOption::Yes, dec!("0.7")),
+                WeightedVoteOption::new(VoteOption::Abstain, dec!("0.3")),
+            ],
+        );
+        assert!(msg.validate().is_ok());
+        assert_eq!(
+            MsgVoteWeighted::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+
+        msg.options[1].option = VoteOption::Yes;
+        assert!(msg.validate().is_err());
+        msg.options[1] = WeightedVoteOption::new(VoteOption::No, dec!("0.2"));
+        assert!(msg.validate().is_err());
+    }
+
+    #[test]
+    fn it_fails_to_pack_unregistered_json_content() {
+        let content: Content = serde_json::from_value(json!({
+            "@type": "/cosmos.gov.v1beta1.NewProposal",
+            "title": "title"
+        }))
+        .unwrap();
+        assert!(content.to_any().is_err());
+
+        let msg = MsgSubmitProposal::new(content, "1000uluna", VOTER);
+        assert!(msg.to_any().is_err());
+        assert!(TxBuilder::new().msg(&msg).is_err());
+    }
 }
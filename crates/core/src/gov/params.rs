@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Coins, Dec};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct DepositParams {
+    /// Deposit needed for a proposal to enter its voting period.
+    pub min_deposit: Coins,
+    #[serde(with = "crate::internal::serde_duration")]
+    pub max_deposit_period: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct VotingParams {
+    #[serde(with = "crate::internal::serde_duration")]
+    pub voting_period: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct TallyParams {
+    /// Share of bonded stake that must vote for the result to be valid.
+    pub quorum: Dec,
+    /// Share of non-abstaining votes needed to pass.
+    pub threshold: Dec,
+    /// Share of votes needed to veto and burn the deposit.
+    pub veto_threshold: Dec,
+}
+
+/// Parameters of the gov module, stored under three separate keys.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct GovParams {
+    pub deposit_params: DepositParams,
+    pub voting_params: VotingParams,
+    pub tally_params: TallyParams,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_parses_lcd_gov_params() {
+        let params: GovParams = serde_json::from_value(json!({
+            "deposit_params": {
+                "min_deposit": [{ "denom": "uluna", "amount": "5000000000" }],
+                "max_deposit_period": "1209600s"
+            },
+            "voting_params": { "voting_period": "604800s" },
+            "tally_params": {
+                "quorum": "0.300000000000000000",
+                "threshold": "0.500000000000000000",
+                "veto_threshold": "0.334000000000000000"
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            params.deposit_params.max_deposit_period,
+            Duration::from_secs(1_209_600)
+        );
+        assert_eq!(
+            params.deposit_params.min_deposit,
+            Coins::from("5000000000uluna")
+        );
+        assert_eq!(
+            serde_json::to_value(&params.voting_params).unwrap(),
+            json!({ "voting_period": "604800000000000" })
+        );
+    }
+}
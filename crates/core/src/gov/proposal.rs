@@ -0,0 +1,129 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::gov::Content;
+use crate::{AccAddress, Coins, Uint128};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ProposalStatus {
+    #[serde(rename = "PROPOSAL_STATUS_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "PROPOSAL_STATUS_DEPOSIT_PERIOD")]
+    DepositPeriod,
+    #[serde(rename = "PROPOSAL_STATUS_VOTING_PERIOD")]
+    VotingPeriod,
+    #[serde(rename = "PROPOSAL_STATUS_PASSED")]
+    Passed,
+    #[serde(rename = "PROPOSAL_STATUS_REJECTED")]
+    Rejected,
+    #[serde(rename = "PROPOSAL_STATUS_FAILED")]
+    Failed,
+}
+
+impl ProposalStatus {
+    /// Name accepted by the `proposal_status` query parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProposalStatus::Unspecified => "PROPOSAL_STATUS_UNSPECIFIED",
+            ProposalStatus::DepositPeriod => "PROPOSAL_STATUS_DEPOSIT_PERIOD",
+            ProposalStatus::VotingPeriod => "PROPOSAL_STATUS_VOTING_PERIOD",
+            ProposalStatus::Passed => "PROPOSAL_STATUS_PASSED",
+            ProposalStatus::Rejected => "PROPOSAL_STATUS_REJECTED",
+            ProposalStatus::Failed => "PROPOSAL_STATUS_FAILED",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct TallyResult {
+    pub yes: Uint128,
+    pub abstain: Uint128,
+    pub no: Uint128,
+    pub no_with_veto: Uint128,
+}
+
+impl TallyResult {
+    pub fn total(&self) -> Uint128 {
+        self.yes + self.abstain + self.no + self.no_with_veto
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Proposal {
+    #[serde(with = "crate::internal::serde_str")]
+    pub proposal_id: u64,
+    pub content: Content,
+    pub status: ProposalStatus,
+    pub final_tally_result: TallyResult,
+    pub submit_time: DateTime<Utc>,
+    pub deposit_end_time: DateTime<Utc>,
+    pub total_deposit: Coins,
+    pub voting_start_time: DateTime<Utc>,
+    pub voting_end_time: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Deposit {
+    #[serde(with = "crate::internal::serde_str")]
+    pub proposal_id: u64,
+    pub depositor: AccAddress,
+    pub amount: Coins,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_deserializes_lcd_proposal() {
+        let proposal: Proposal = serde_json::from_value(json!({
+            "proposal_id": "12",
+            "content": {
+                "@type": "/cosmos.gov.v1beta1.TextProposal",
+                "title": "title",
+                "description": "description"
+            },
+            "status": "PROPOSAL_STATUS_PASSED",
+            "final_tally_result": {
+                "yes": "300", "abstain": "0", "no": "100", "no_with_veto": "0"
+            },
+            "submit_time": "2022-01-10T08:00:00.123456Z",
+            "deposit_end_time": "2022-01-24T08:00:00Z",
+            "total_deposit": [{ "denom": "uluna", "amount": "512000000" }],
+            "voting_start_time": "2022-01-10T09:00:00Z",
+            "voting_end_time": "2022-01-17T09:00:00Z"
+        }))
+        .unwrap();
+        assert_eq!(proposal.proposal_id, 12);
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        assert_eq!(proposal.final_tally_result.total(), Uint128::new(400u32));
+    }
+
+    #[test]
+    fn it_deserializes_unknown_content() {
+        let proposal: Proposal = serde_json::from_value(json!({
+            "proposal_id": "13",
+            "content": {
+                "@type": "/terra.custom.v1.FancyProposal",
+                "title": "title",
+                "fancy": { "level": 9000 }
+            },
+            "status": "PROPOSAL_STATUS_VOTING_PERIOD",
+            "final_tally_result": {
+                "yes": "0", "abstain": "0", "no": "0", "no_with_veto": "0"
+            },
+            "submit_time": "2022-01-10T08:00:00Z",
+            "deposit_end_time": "2022-01-24T08:00:00Z",
+            "total_deposit": [],
+            "voting_start_time": "2022-01-10T09:00:00Z",
+            "voting_end_time": "2022-01-17T09:00:00Z"
+        }))
+        .unwrap();
+        assert!(matches!(proposal.content, Content::Other(_)));
+        assert_eq!(
+            proposal.content.type_url(),
+            "/terra.custom.v1.FancyProposal"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Protobuf counterparts of the `cosmos.gov.v1beta1` types.
+
+use prost::Message;
+
+use crate::sdk::proto::Coin;
+use crate::Any;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct TextProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgSubmitProposal {
+    #[prost(message, optional, tag = "1")]
+    pub content: Option<Any>,
+    #[prost(message, repeated, tag = "2")]
+    pub initial_deposit: Vec<Coin>,
+    #[prost(string, tag = "3")]
+    pub proposer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgDeposit {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub depositor: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgVote {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(int32, tag = "3")]
+    pub option: i32,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct WeightedVoteOption {
+    #[prost(int32, tag = "1")]
+    pub option: i32,
+    #[prost(string, tag = "2")]
+    pub weight: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgVoteWeighted {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(message, repeated, tag = "3")]
+    pub options: Vec<WeightedVoteOption>,
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+use crate::gov::proto;
+use crate::internal::json_form::is_proto_json;
+use crate::sdk::proto::{dec_from_proto, dec_to_proto};
+use crate::{AccAddress, Dec, ProtoMsg};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub enum VoteOption {
+    #[default]
+    Unspecified = 0,
+    Yes = 1,
+    Abstain = 2,
+    No = 3,
+    NoWithVeto = 4,
+}
+
+impl VoteOption {
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(VoteOption::Unspecified),
+            1 => Ok(VoteOption::Yes),
+            2 => Ok(VoteOption::Abstain),
+            3 => Ok(VoteOption::No),
+            4 => Ok(VoteOption::NoWithVeto),
+            other => Err(format!("invalid vote option: {}", other)),
+        }
+    }
+
+    /// Name used in protobuf JSON, e.g. `VOTE_OPTION_NO_WITH_VETO`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoteOption::Unspecified => "VOTE_OPTION_UNSPECIFIED",
+            VoteOption::Yes => "VOTE_OPTION_YES",
+            VoteOption::Abstain => "VOTE_OPTION_ABSTAIN",
+            VoteOption::No => "VOTE_OPTION_NO",
+            VoteOption::NoWithVeto => "VOTE_OPTION_NO_WITH_VETO",
+        }
+    }
+}
+
+impl std::str::FromStr for VoteOption {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "VOTE_OPTION_UNSPECIFIED" | "Unspecified" => Ok(VoteOption::Unspecified),
+            "VOTE_OPTION_YES" | "Yes" => Ok(VoteOption::Yes),
+            "VOTE_OPTION_ABSTAIN" | "Abstain" => Ok(VoteOption::Abstain),
+            "VOTE_OPTION_NO" | "No" => Ok(VoteOption::No),
+            "VOTE_OPTION_NO_WITH_VETO" | "NoWithVeto" => Ok(VoteOption::NoWithVeto),
+            other => other
+                .parse::<i32>()
+                .map_err(|_| format!("invalid vote option: {}", other))
+                .and_then(VoteOption::from_i32),
+        }
+    }
+}
+
+/// Amino JSON encodes vote options as numbers, protobuf JSON by name.
+impl Serialize for VoteOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if is_proto_json() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_i32(*self as i32)
+        }
+    }
+}
+
+/// Accepts a number or a `VOTE_OPTION_*` name.
+impl<'de> Deserialize<'de> for VoteOption {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Number(n) => n
+                .as_i64()
+                .ok_or_else(|| format!("invalid vote option: {}", n))
+                .and_then(|n| VoteOption::from_i32(n as i32)),
+            serde_json::Value::String(s) => s.parse(),
+            other => Err(format!("invalid vote option: {}", other)),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct WeightedVoteOption {
+    pub option: VoteOption,
+    pub weight: Dec,
+}
+
+impl WeightedVoteOption {
+    pub fn new(option: VoteOption, weight: impl Into<Dec>) -> Self {
+        Self {
+            option,
+            weight: weight.into(),
+        }
+    }
+}
+
+impl ProtoMsg for WeightedVoteOption {
+    type Proto = proto::WeightedVoteOption;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::WeightedVoteOption {
+            option: self.option as i32,
+            weight: dec_to_proto(&self.weight),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            option: VoteOption::from_i32(proto.option)?,
+            weight: dec_from_proto(&proto.weight)?,
+        })
+    }
+}
+
+/// Checks that weighted options are distinct, positive, at most one each
+/// and sum to exactly one.
+pub fn validate_weighted_options(options: &[WeightedVoteOption]) -> Result<(), String> {
+    if options.is_empty() {
+        return Err("no vote options".into());
+    }
+    let mut seen = std::collections::BTreeSet::new();
+    let mut total = Dec::zero();
+    for option in options {
+        if option.option == VoteOption::Unspecified {
+            return Err("unspecified vote option".into());
+        }
+        if !seen.insert(option.option) {
+            return Err(format!("duplicate vote option: {}", option.option.as_str()));
+        }
+        if option.weight <= Dec::zero() || option.weight > Dec::one() {
+            return Err(format!(
+                "invalid weight {} for {}",
+                option.weight,
+                option.option.as_str()
+            ));
+        }
+        total = total + option.weight;
+    }
+    if total != Dec::one() {
+        return Err(format!("vote weights sum to {}, expected 1", total));
+    }
+    Ok(())
+}
+
+/// A vote as returned by the LCD. `option` is the deprecated single-option
+/// field; `options` is always populated on v0.43+ chains.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Vote {
+    #[serde(with = "crate::internal::serde_str")]
+    pub proposal_id: u64,
+    pub voter: AccAddress,
+    #[serde(default)]
+    pub option: VoteOption,
+    #[serde(default)]
+    pub options: Vec<WeightedVoteOption>,
+}
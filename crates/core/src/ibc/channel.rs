@@ -0,0 +1,583 @@
+//! ICS-04 channel handshake and packet messages, and query models.
+
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::ibc::{proto, Height};
+use crate::{AccAddress, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum ChannelState {
+    #[default]
+    #[serde(rename = "STATE_UNINITIALIZED_UNSPECIFIED")]
+    Uninitialized,
+    #[serde(rename = "STATE_INIT")]
+    Init,
+    #[serde(rename = "STATE_TRYOPEN")]
+    TryOpen,
+    #[serde(rename = "STATE_OPEN")]
+    Open,
+    #[serde(rename = "STATE_CLOSED")]
+    Closed,
+}
+
+impl ChannelState {
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(ChannelState::Uninitialized),
+            1 => Ok(ChannelState::Init),
+            2 => Ok(ChannelState::TryOpen),
+            3 => Ok(ChannelState::Open),
+            4 => Ok(ChannelState::Closed),
+            other => Err(format!("invalid channel state: {}", other)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum Order {
+    #[default]
+    #[serde(rename = "ORDER_NONE_UNSPECIFIED")]
+    None,
+    #[serde(rename = "ORDER_UNORDERED")]
+    Unordered,
+    #[serde(rename = "ORDER_ORDERED")]
+    Ordered,
+}
+
+impl Order {
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(Order::None),
+            1 => Ok(Order::Unordered),
+            2 => Ok(Order::Ordered),
+            other => Err(format!("invalid channel ordering: {}", other)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct ChannelCounterparty {
+    pub port_id: String,
+    #[serde(default)]
+    pub channel_id: String,
+}
+
+/// A channel end.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct Channel {
+    pub state: ChannelState,
+    pub ordering: Order,
+    pub counterparty: ChannelCounterparty,
+    pub connection_hops: Vec<String>,
+    pub version: String,
+}
+
+impl Channel {
+    fn to_proto(&self) -> proto::Channel {
+        proto::Channel {
+            state: self.state as i32,
+            ordering: self.ordering as i32,
+            counterparty: Some(proto::ChannelCounterparty {
+                port_id: self.counterparty.port_id.clone(),
+                channel_id: self.counterparty.channel_id.clone(),
+            }),
+            connection_hops: self.connection_hops.clone(),
+            version: self.version.clone(),
+        }
+    }
+
+    fn from_proto(proto: Option<proto::Channel>) -> Result<Self, String> {
+        let proto = proto.ok_or("missing channel")?;
+        let counterparty = proto.counterparty.unwrap_or_default();
+        Ok(Self {
+            state: ChannelState::from_i32(proto.state)?,
+            ordering: Order::from_i32(proto.ordering)?,
+            counterparty: ChannelCounterparty {
+                port_id: counterparty.port_id,
+                channel_id: counterparty.channel_id,
+            },
+            connection_hops: proto.connection_hops,
+            version: proto.version,
+        })
+    }
+}
+
+/// A packet sent from `source_port/source_channel` to
+/// `destination_port/destination_channel`. `data` is application specific;
+/// for ICS-20 it is the JSON `FungibleTokenPacketData`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct Packet {
+    #[serde(with = "crate::internal::serde_str")]
+    pub sequence: u64,
+    pub source_port: String,
+    pub source_channel: String,
+    pub destination_port: String,
+    pub destination_channel: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub data: Vec<u8>,
+    #[serde(default)]
+    pub timeout_height: Height,
+    #[serde(default, with = "crate::internal::serde_str")]
+    pub timeout_timestamp: u64,
+}
+
+impl Packet {
+    fn to_proto(&self) -> proto::Packet {
+        proto::Packet {
+            sequence: self.sequence,
+            source_port: self.source_port.clone(),
+            source_channel: self.source_channel.clone(),
+            destination_port: self.destination_port.clone(),
+            destination_channel: self.destination_channel.clone(),
+            data: self.data.clone(),
+            timeout_height: Some(self.timeout_height.to_proto()),
+            timeout_timestamp: self.timeout_timestamp,
+        }
+    }
+
+    fn from_proto(proto: Option<proto::Packet>) -> Result<Self, String> {
+        let proto = proto.ok_or("missing packet")?;
+        Ok(Self {
+            sequence: proto.sequence,
+            source_port: proto.source_port,
+            source_channel: proto.source_channel,
+            destination_port: proto.destination_port,
+            destination_channel: proto.destination_channel,
+            data: proto.data,
+            timeout_height: Height::from_optional_proto(proto.timeout_height),
+            timeout_timestamp: proto.timeout_timestamp,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgChannelOpenInit")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgChannelOpenInit")]
+pub struct MsgChannelOpenInit {
+    pub port_id: String,
+    pub channel: Channel,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgChannelOpenInit {
+    type Proto = proto::MsgChannelOpenInit;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgChannelOpenInit {
+            port_id: self.port_id.clone(),
+            channel: Some(self.channel.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            port_id: proto.port_id,
+            channel: Channel::from_proto(proto.channel)?,
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgChannelOpenTry")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgChannelOpenTry")]
+pub struct MsgChannelOpenTry {
+    pub port_id: String,
+    #[serde(default)]
+    pub previous_channel_id: String,
+    pub channel: Channel,
+    pub counterparty_version: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_init: Vec<u8>,
+    pub proof_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgChannelOpenTry {
+    type Proto = proto::MsgChannelOpenTry;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgChannelOpenTry {
+            port_id: self.port_id.clone(),
+            previous_channel_id: self.previous_channel_id.clone(),
+            channel: Some(self.channel.to_proto()),
+            counterparty_version: self.counterparty_version.clone(),
+            proof_init: self.proof_init.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            port_id: proto.port_id,
+            previous_channel_id: proto.previous_channel_id,
+            channel: Channel::from_proto(proto.channel)?,
+            counterparty_version: proto.counterparty_version,
+            proof_init: proto.proof_init,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgChannelOpenAck")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgChannelOpenAck")]
+pub struct MsgChannelOpenAck {
+    pub port_id: String,
+    pub channel_id: String,
+    pub counterparty_channel_id: String,
+    pub counterparty_version: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_try: Vec<u8>,
+    pub proof_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgChannelOpenAck {
+    type Proto = proto::MsgChannelOpenAck;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgChannelOpenAck {
+            port_id: self.port_id.clone(),
+            channel_id: self.channel_id.clone(),
+            counterparty_channel_id: self.counterparty_channel_id.clone(),
+            counterparty_version: self.counterparty_version.clone(),
+            proof_try: self.proof_try.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            port_id: proto.port_id,
+            channel_id: proto.channel_id,
+            counterparty_channel_id: proto.counterparty_channel_id,
+            counterparty_version: proto.counterparty_version,
+            proof_try: proto.proof_try,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgChannelOpenConfirm")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgChannelOpenConfirm")]
+pub struct MsgChannelOpenConfirm {
+    pub port_id: String,
+    pub channel_id: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_ack: Vec<u8>,
+    pub proof_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgChannelOpenConfirm {
+    type Proto = proto::MsgChannelOpenConfirm;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgChannelOpenConfirm {
+            port_id: self.port_id.clone(),
+            channel_id: self.channel_id.clone(),
+            proof_ack: self.proof_ack.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            port_id: proto.port_id,
+            channel_id: proto.channel_id,
+            proof_ack: proto.proof_ack,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgChannelCloseInit")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgChannelCloseInit")]
+pub struct MsgChannelCloseInit {
+    pub port_id: String,
+    pub channel_id: String,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgChannelCloseInit {
+    type Proto = proto::MsgChannelCloseInit;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgChannelCloseInit {
+            port_id: self.port_id.clone(),
+            channel_id: self.channel_id.clone(),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            port_id: proto.port_id,
+            channel_id: proto.channel_id,
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgChannelCloseConfirm")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgChannelCloseConfirm")]
+pub struct MsgChannelCloseConfirm {
+    pub port_id: String,
+    pub channel_id: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_init: Vec<u8>,
+    pub proof_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgChannelCloseConfirm {
+    type Proto = proto::MsgChannelCloseConfirm;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgChannelCloseConfirm {
+            port_id: self.port_id.clone(),
+            channel_id: self.channel_id.clone(),
+            proof_init: self.proof_init.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            port_id: proto.port_id,
+            channel_id: proto.channel_id,
+            proof_init: proto.proof_init,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+/// Delivers `packet` to its destination, proven by the commitment stored
+/// on the source chain at `proof_height`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgRecvPacket")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgRecvPacket")]
+pub struct MsgRecvPacket {
+    pub packet: Packet,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_commitment: Vec<u8>,
+    pub proof_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgRecvPacket {
+    type Proto = proto::MsgRecvPacket;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgRecvPacket {
+            packet: Some(self.packet.to_proto()),
+            proof_commitment: self.proof_commitment.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            packet: Packet::from_proto(proto.packet)?,
+            proof_commitment: proto.proof_commitment,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+/// Times out `packet` on its source chain, proving it was never received.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgTimeout")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgTimeout")]
+pub struct MsgTimeout {
+    pub packet: Packet,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_unreceived: Vec<u8>,
+    pub proof_height: Height,
+    #[serde(with = "crate::internal::serde_str")]
+    pub next_sequence_recv: u64,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgTimeout {
+    type Proto = proto::MsgTimeout;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgTimeout {
+            packet: Some(self.packet.to_proto()),
+            proof_unreceived: self.proof_unreceived.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            next_sequence_recv: self.next_sequence_recv,
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            packet: Packet::from_proto(proto.packet)?,
+            proof_unreceived: proto.proof_unreceived,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            next_sequence_recv: proto.next_sequence_recv,
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+/// Relays the destination chain's acknowledgement of `packet` back to its
+/// source.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgAcknowledgement")]
+#[msgtype_pb("/ibc.core.channel.v1.MsgAcknowledgement")]
+pub struct MsgAcknowledgement {
+    pub packet: Packet,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub acknowledgement: Vec<u8>,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_acked: Vec<u8>,
+    pub proof_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgAcknowledgement {
+    type Proto = proto::MsgAcknowledgement;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgAcknowledgement {
+            packet: Some(self.packet.to_proto()),
+            acknowledgement: self.acknowledgement.clone(),
+            proof_acked: self.proof_acked.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            packet: Packet::from_proto(proto.packet)?,
+            acknowledgement: proto.acknowledgement,
+            proof_acked: proto.proof_acked,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+/// A channel end as returned by the LCD.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct IdentifiedChannel {
+    pub state: ChannelState,
+    pub ordering: Order,
+    pub counterparty: ChannelCounterparty,
+    pub connection_hops: Vec<String>,
+    pub version: String,
+    pub port_id: String,
+    pub channel_id: String,
+}
+
+/// A packet commitment, receipt or acknowledgement stored under
+/// `port_id/channel_id/sequence`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct PacketState {
+    pub port_id: String,
+    pub channel_id: String,
+    #[serde(with = "crate::internal::serde_str")]
+    pub sequence: u64,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub data: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Msg;
+    use serde_json::json;
+
+    #[test]
+    fn it_decodes_packet_msgs() {
+        let packet = Packet {
+            sequence: 42,
+            source_port: "transfer".into(),
+            source_channel: "channel-1".into(),
+            destination_port: "transfer".into(),
+            destination_channel: "channel-72".into(),
+            data: br#"{"amount":"1","denom":"uluna"}"#.to_vec(),
+            timeout_height: Height::new(1, 7_001_000),
+            timeout_timestamp: 0,
+        };
+        let msg = MsgAcknowledgement {
+            packet: packet.clone(),
+            acknowledgement: br#"{"result":"AQ=="}"#.to_vec(),
+            proof_acked: vec![1, 2, 3],
+            proof_height: Height::new(1, 7_000_500),
+            signer: "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4".into(),
+        };
+        assert_eq!(
+            MsgAcknowledgement::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+        assert_eq!(
+            MsgAcknowledgement::from_data(&msg.to_data().unwrap()).unwrap(),
+            msg
+        );
+
+        let msg = MsgTimeout {
+            packet,
+            proof_unreceived: vec![],
+            proof_height: Height::new(1, 7_002_000),
+            next_sequence_recv: 42,
+            signer: "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4".into(),
+        };
+        assert_eq!(MsgTimeout::from_any(&msg.to_any().unwrap()).unwrap(), msg);
+    }
+
+    #[test]
+    fn it_parses_channels() {
+        let channel: IdentifiedChannel = serde_json::from_value(json!({
+            "state": "STATE_OPEN",
+            "ordering": "ORDER_UNORDERED",
+            "counterparty": { "port_id": "transfer", "channel_id": "channel-72" },
+            "connection_hops": ["connection-1"],
+            "version": "ics20-1",
+            "port_id": "transfer",
+            "channel_id": "channel-1"
+        }))
+        .unwrap();
+        assert_eq!(channel.state, ChannelState::Open);
+        assert_eq!(channel.ordering, Order::Unordered);
+
+        let msg = MsgChannelOpenInit {
+            port_id: "wasm.terra1abc".into(),
+            channel: Channel {
+                state: ChannelState::Init,
+                ordering: Order::Ordered,
+                counterparty: ChannelCounterparty {
+                    port_id: "icahost".into(),
+                    channel_id: String::new(),
+                },
+                connection_hops: vec!["connection-1".into()],
+                version: "ics27-1".into(),
+            },
+            signer: "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4".into(),
+        };
+        assert_eq!(
+            MsgChannelOpenInit::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! ICS-02 light client messages and query models. Client states, consensus
+//! states and headers are light-client specific and kept as packed `Any`s.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use terra_sdk_internal::MsgType;
+
+use crate::ibc::{proto, Height};
+use crate::{AccAddress, Any, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgCreateClient")]
+#[msgtype_pb("/ibc.core.client.v1.MsgCreateClient")]
+pub struct MsgCreateClient {
+    #[serde(with = "crate::internal::serde_any")]
+    pub client_state: Any,
+    #[serde(with = "crate::internal::serde_any")]
+    pub consensus_state: Any,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgCreateClient {
+    type Proto = proto::MsgCreateClient;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgCreateClient {
+            client_state: Some(self.client_state.clone()),
+            consensus_state: Some(self.consensus_state.clone()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            client_state: proto.client_state.ok_or("missing client state")?,
+            consensus_state: proto.consensus_state.ok_or("missing consensus state")?,
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+/// Advances a light client with a new header from its counterparty chain.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgUpdateClient")]
+#[msgtype_pb("/ibc.core.client.v1.MsgUpdateClient")]
+pub struct MsgUpdateClient {
+    pub client_id: String,
+    #[serde(with = "crate::internal::serde_any")]
+    pub header: Any,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgUpdateClient {
+    type Proto = proto::MsgUpdateClient;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgUpdateClient {
+            client_id: self.client_id.clone(),
+            header: Some(self.header.clone()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            client_id: proto.client_id,
+            header: proto.header.ok_or("missing header")?,
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgSubmitMisbehaviour")]
+#[msgtype_pb("/ibc.core.client.v1.MsgSubmitMisbehaviour")]
+pub struct MsgSubmitMisbehaviour {
+    pub client_id: String,
+    #[serde(with = "crate::internal::serde_any")]
+    pub misbehaviour: Any,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgSubmitMisbehaviour {
+    type Proto = proto::MsgSubmitMisbehaviour;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgSubmitMisbehaviour {
+            client_id: self.client_id.clone(),
+            misbehaviour: Some(self.misbehaviour.clone()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            client_id: proto.client_id,
+            misbehaviour: proto.misbehaviour.ok_or("missing misbehaviour")?,
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+/// A client state as returned by the LCD, in protobuf JSON.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct IdentifiedClientState {
+    pub client_id: String,
+    pub client_state: Value,
+}
+
+impl IdentifiedClientState {
+    /// Latest height the client has been updated to, for light clients that
+    /// report one (e.g. Tendermint).
+    pub fn latest_height(&self) -> Option<Height> {
+        latest_height(&self.client_state)
+    }
+
+    pub fn chain_id(&self) -> Option<&str> {
+        self.client_state.get("chain_id").and_then(Value::as_str)
+    }
+}
+
+/// `latest_height` of a client state in protobuf JSON.
+pub fn latest_height(client_state: &Value) -> Option<Height> {
+    serde_json::from_value(client_state.get("latest_height")?.clone()).ok()
+}
+
+/// A consensus state stored by a client at `height`, in protobuf JSON.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ConsensusStateWithHeight {
+    pub height: Height,
+    pub consensus_state: Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Msg;
+    use serde_json::json;
+
+    #[test]
+    fn it_decodes_update_client() {
+        let msg = MsgUpdateClient {
+            client_id: "07-tendermint-1".into(),
+            header: Any {
+                type_url: "/ibc.lightclients.tendermint.v1.Header".into(),
+                value: vec![1, 2, 3],
+            },
+            signer: "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4".into(),
+        };
+        assert_eq!(
+            MsgUpdateClient::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+        assert_eq!(
+            msg.to_data().unwrap()["header"],
+            json!({ "type_url": "/ibc.lightclients.tendermint.v1.Header", "value": "AQID" })
+        );
+
+        let state: IdentifiedClientState = serde_json::from_value(json!({
+            "client_id": "07-tendermint-1",
+            "client_state": {
+                "@type": "/ibc.lightclients.tendermint.v1.ClientState",
+                "chain_id": "osmosis-1",
+                "latest_height": { "revision_number": "1", "revision_height": "7000000" }
+            }
+        }))
+        .unwrap();
+        assert_eq!(state.latest_height(), Some(Height::new(1, 7_000_000)));
+        assert_eq!(state.chain_id(), Some("osmosis-1"));
+    }
+}
@@ -0,0 +1,351 @@
+//! ICS-03 connection handshake messages and query models.
+
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::ibc::{proto, Height};
+use crate::{AccAddress, Any, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum ConnectionState {
+    #[default]
+    #[serde(rename = "STATE_UNINITIALIZED_UNSPECIFIED")]
+    Uninitialized,
+    #[serde(rename = "STATE_INIT")]
+    Init,
+    #[serde(rename = "STATE_TRYOPEN")]
+    TryOpen,
+    #[serde(rename = "STATE_OPEN")]
+    Open,
+}
+
+impl ConnectionState {
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(ConnectionState::Uninitialized),
+            1 => Ok(ConnectionState::Init),
+            2 => Ok(ConnectionState::TryOpen),
+            3 => Ok(ConnectionState::Open),
+            other => Err(format!("invalid connection state: {}", other)),
+        }
+    }
+}
+
+/// A connection version and the channel orderings it supports.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct Version {
+    pub identifier: String,
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+impl Version {
+    fn to_proto(&self) -> proto::Version {
+        proto::Version {
+            identifier: self.identifier.clone(),
+            features: self.features.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::Version) -> Self {
+        Self {
+            identifier: proto.identifier,
+            features: proto.features,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct MerklePrefix {
+    #[serde(with = "crate::internal::serde_base64")]
+    pub key_prefix: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct ConnectionCounterparty {
+    pub client_id: String,
+    #[serde(default)]
+    pub connection_id: String,
+    pub prefix: MerklePrefix,
+}
+
+impl ConnectionCounterparty {
+    fn to_proto(&self) -> proto::ConnectionCounterparty {
+        proto::ConnectionCounterparty {
+            client_id: self.client_id.clone(),
+            connection_id: self.connection_id.clone(),
+            prefix: Some(proto::MerklePrefix {
+                key_prefix: self.prefix.key_prefix.clone(),
+            }),
+        }
+    }
+
+    fn from_proto(proto: Option<proto::ConnectionCounterparty>) -> Result<Self, String> {
+        let proto = proto.ok_or("missing counterparty")?;
+        Ok(Self {
+            client_id: proto.client_id,
+            connection_id: proto.connection_id,
+            prefix: MerklePrefix {
+                key_prefix: proto.prefix.unwrap_or_default().key_prefix,
+            },
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgConnectionOpenInit")]
+#[msgtype_pb("/ibc.core.connection.v1.MsgConnectionOpenInit")]
+pub struct MsgConnectionOpenInit {
+    pub client_id: String,
+    pub counterparty: ConnectionCounterparty,
+    #[serde(default)]
+    pub version: Option<Version>,
+    #[serde(with = "crate::internal::serde_str")]
+    pub delay_period: u64,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgConnectionOpenInit {
+    type Proto = proto::MsgConnectionOpenInit;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgConnectionOpenInit {
+            client_id: self.client_id.clone(),
+            counterparty: Some(self.counterparty.to_proto()),
+            version: self.version.as_ref().map(Version::to_proto),
+            delay_period: self.delay_period,
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            client_id: proto.client_id,
+            counterparty: ConnectionCounterparty::from_proto(proto.counterparty)?,
+            version: proto.version.map(Version::from_proto),
+            delay_period: proto.delay_period,
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgConnectionOpenTry")]
+#[msgtype_pb("/ibc.core.connection.v1.MsgConnectionOpenTry")]
+pub struct MsgConnectionOpenTry {
+    pub client_id: String,
+    #[serde(default)]
+    pub previous_connection_id: String,
+    #[serde(with = "crate::internal::serde_any")]
+    pub client_state: Any,
+    pub counterparty: ConnectionCounterparty,
+    #[serde(with = "crate::internal::serde_str")]
+    pub delay_period: u64,
+    pub counterparty_versions: Vec<Version>,
+    pub proof_height: Height,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_init: Vec<u8>,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_client: Vec<u8>,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_consensus: Vec<u8>,
+    pub consensus_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgConnectionOpenTry {
+    type Proto = proto::MsgConnectionOpenTry;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgConnectionOpenTry {
+            client_id: self.client_id.clone(),
+            previous_connection_id: self.previous_connection_id.clone(),
+            client_state: Some(self.client_state.clone()),
+            counterparty: Some(self.counterparty.to_proto()),
+            delay_period: self.delay_period,
+            counterparty_versions: self
+                .counterparty_versions
+                .iter()
+                .map(Version::to_proto)
+                .collect(),
+            proof_height: Some(self.proof_height.to_proto()),
+            proof_init: self.proof_init.clone(),
+            proof_client: self.proof_client.clone(),
+            proof_consensus: self.proof_consensus.clone(),
+            consensus_height: Some(self.consensus_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            client_id: proto.client_id,
+            previous_connection_id: proto.previous_connection_id,
+            client_state: proto.client_state.ok_or("missing client state")?,
+            counterparty: ConnectionCounterparty::from_proto(proto.counterparty)?,
+            delay_period: proto.delay_period,
+            counterparty_versions: proto
+                .counterparty_versions
+                .into_iter()
+                .map(Version::from_proto)
+                .collect(),
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            proof_init: proto.proof_init,
+            proof_client: proto.proof_client,
+            proof_consensus: proto.proof_consensus,
+            consensus_height: Height::from_optional_proto(proto.consensus_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgConnectionOpenAck")]
+#[msgtype_pb("/ibc.core.connection.v1.MsgConnectionOpenAck")]
+pub struct MsgConnectionOpenAck {
+    pub connection_id: String,
+    pub counterparty_connection_id: String,
+    pub version: Version,
+    #[serde(with = "crate::internal::serde_any")]
+    pub client_state: Any,
+    pub proof_height: Height,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_try: Vec<u8>,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_client: Vec<u8>,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_consensus: Vec<u8>,
+    pub consensus_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgConnectionOpenAck {
+    type Proto = proto::MsgConnectionOpenAck;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgConnectionOpenAck {
+            connection_id: self.connection_id.clone(),
+            counterparty_connection_id: self.counterparty_connection_id.clone(),
+            version: Some(self.version.to_proto()),
+            client_state: Some(self.client_state.clone()),
+            proof_height: Some(self.proof_height.to_proto()),
+            proof_try: self.proof_try.clone(),
+            proof_client: self.proof_client.clone(),
+            proof_consensus: self.proof_consensus.clone(),
+            consensus_height: Some(self.consensus_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            connection_id: proto.connection_id,
+            counterparty_connection_id: proto.counterparty_connection_id,
+            version: Version::from_proto(proto.version.ok_or("missing version")?),
+            client_state: proto.client_state.ok_or("missing client state")?,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            proof_try: proto.proof_try,
+            proof_client: proto.proof_client,
+            proof_consensus: proto.proof_consensus,
+            consensus_height: Height::from_optional_proto(proto.consensus_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgConnectionOpenConfirm")]
+#[msgtype_pb("/ibc.core.connection.v1.MsgConnectionOpenConfirm")]
+pub struct MsgConnectionOpenConfirm {
+    pub connection_id: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub proof_ack: Vec<u8>,
+    pub proof_height: Height,
+    pub signer: AccAddress,
+}
+
+impl ProtoMsg for MsgConnectionOpenConfirm {
+    type Proto = proto::MsgConnectionOpenConfirm;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgConnectionOpenConfirm {
+            connection_id: self.connection_id.clone(),
+            proof_ack: self.proof_ack.clone(),
+            proof_height: Some(self.proof_height.to_proto()),
+            signer: self.signer.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            connection_id: proto.connection_id,
+            proof_ack: proto.proof_ack,
+            proof_height: Height::from_optional_proto(proto.proof_height),
+            signer: proto.signer.into(),
+        })
+    }
+}
+
+/// A connection end as returned by the LCD.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct IdentifiedConnection {
+    #[serde(default)]
+    pub id: String,
+    pub client_id: String,
+    pub versions: Vec<Version>,
+    pub state: ConnectionState,
+    pub counterparty: ConnectionCounterparty,
+    #[serde(default, with = "crate::internal::serde_str")]
+    pub delay_period: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Msg;
+    use serde_json::json;
+
+    #[test]
+    fn it_decodes_connection_handshakes() {
+        let msg = MsgConnectionOpenInit {
+            client_id: "07-tendermint-0".into(),
+            counterparty: ConnectionCounterparty {
+                client_id: "07-tendermint-1".into(),
+                connection_id: String::new(),
+                prefix: MerklePrefix {
+                    key_prefix: b"ibc".to_vec(),
+                },
+            },
+            version: Some(Version {
+                identifier: "1".into(),
+                features: vec!["ORDER_ORDERED".into(), "ORDER_UNORDERED".into()],
+            }),
+            delay_period: 0,
+            signer: "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4".into(),
+        };
+        assert_eq!(
+            MsgConnectionOpenInit::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+        assert_eq!(
+            MsgConnectionOpenInit::from_data(&msg.to_data().unwrap()).unwrap(),
+            msg
+        );
+
+        let connection: IdentifiedConnection = serde_json::from_value(json!({
+            "id": "connection-1",
+            "client_id": "07-tendermint-1",
+            "versions": [{ "identifier": "1", "features": ["ORDER_UNORDERED"] }],
+            "state": "STATE_OPEN",
+            "counterparty": {
+                "client_id": "07-tendermint-1793",
+                "connection_id": "connection-1389",
+                "prefix": { "key_prefix": "aWJj" }
+            },
+            "delay_period": "0"
+        }))
+        .unwrap();
+        assert_eq!(connection.state, ConnectionState::Open);
+        assert_eq!(connection.counterparty.prefix.key_prefix, b"ibc");
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ibc::proto;
+
+/// An IBC height: the block height within a revision (hard fork) of a
+/// chain. A zero height disables height-based timeouts.
+#[derive(
+    Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Hash,
+)]
+pub struct Height {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::internal::json_form::is_amino_zero",
+        with = "crate::internal::serde_str"
+    )]
+    pub revision_number: u64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::internal::json_form::is_amino_zero",
+        with = "crate::internal::serde_str"
+    )]
+    pub revision_height: u64,
+}
+
+impl Height {
+    pub fn new(revision_number: u64, revision_height: u64) -> Self {
+        Self {
+            revision_number,
+            revision_height,
+        }
+    }
+
+    /// `height` on the chain `chain_id`, whose revision number is the
+    /// trailing number of an id like `osmosis-1`.
+    pub fn for_chain(chain_id: &str, height: u64) -> Self {
+        Self::new(revision_number(chain_id), height)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.revision_number == 0 && self.revision_height == 0
+    }
+
+    /// The height `blocks` later in the same revision.
+    pub fn add(&self, blocks: u64) -> Self {
+        Self::new(self.revision_number, self.revision_height + blocks)
+    }
+
+    pub fn to_proto(&self) -> proto::Height {
+        proto::Height {
+            revision_number: self.revision_number,
+            revision_height: self.revision_height,
+        }
+    }
+
+    pub fn from_proto(proto: proto::Height) -> Self {
+        Self::new(proto.revision_number, proto.revision_height)
+    }
+
+    /// Heights are optional on the wire; a missing one reads as zero.
+    pub(crate) fn from_optional_proto(proto: Option<proto::Height>) -> Self {
+        proto.map(Self::from_proto).unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for Height {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}-{}", self.revision_number, self.revision_height)
+    }
+}
+
+/// Revision number encoded in a chain id of the form `{name}-{revision}`,
+/// or 0 if the id does not follow that format.
+pub fn revision_number(chain_id: &str) -> u64 {
+    chain_id
+        .rsplit_once('-')
+        .and_then(|(name, revision)| {
+            let valid = !name.is_empty()
+                && !revision.is_empty()
+                && !revision.starts_with('0')
+                && revision.bytes().all(|b| b.is_ascii_digit());
+            valid.then(|| revision.parse().ok()).flatten()
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_revision_numbers() {
+        assert_eq!(Height::for_chain("osmosis-1", 100), Height::new(1, 100));
+        assert_eq!(revision_number("columbus-5"), 5);
+        assert_eq!(revision_number("localterra"), 0);
+        assert_eq!(revision_number("chain-01"), 0);
+        assert_eq!(Height::new(1, 100).add(1000).to_string(), "1-1100");
+    }
+}
@@ -1,2 +1,10 @@
-pub mod msgs;
-pub use msgs::*;
+pub mod channel;
+pub mod client;
+pub mod connection;
+pub mod height;
+pub use channel::*;
+pub use client::*;
+pub use connection::*;
+pub use height::*;
+
+pub mod proto;
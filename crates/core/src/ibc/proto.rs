@@ -0,0 +1,319 @@
+//! Protobuf counterparts of the `ibc.core` types.
+
+use prost::Message;
+
+use crate::Any;
+
+/// `ibc.core.client.v1.Height`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Height {
+    #[prost(uint64, tag = "1")]
+    pub revision_number: u64,
+    #[prost(uint64, tag = "2")]
+    pub revision_height: u64,
+}
+
+/// `ibc.core.commitment.v1.MerklePrefix`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MerklePrefix {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key_prefix: Vec<u8>,
+}
+
+// ibc.core.client.v1
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgCreateClient {
+    #[prost(message, optional, tag = "1")]
+    pub client_state: Option<Any>,
+    #[prost(message, optional, tag = "2")]
+    pub consensus_state: Option<Any>,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgUpdateClient {
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub header: Option<Any>,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgSubmitMisbehaviour {
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub misbehaviour: Option<Any>,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+// ibc.core.connection.v1
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Version {
+    #[prost(string, tag = "1")]
+    pub identifier: String,
+    #[prost(string, repeated, tag = "2")]
+    pub features: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ConnectionCounterparty {
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub prefix: Option<MerklePrefix>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgConnectionOpenInit {
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub counterparty: Option<ConnectionCounterparty>,
+    #[prost(message, optional, tag = "3")]
+    pub version: Option<Version>,
+    #[prost(uint64, tag = "4")]
+    pub delay_period: u64,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgConnectionOpenTry {
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+    #[prost(string, tag = "2")]
+    pub previous_connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub client_state: Option<Any>,
+    #[prost(message, optional, tag = "4")]
+    pub counterparty: Option<ConnectionCounterparty>,
+    #[prost(uint64, tag = "5")]
+    pub delay_period: u64,
+    #[prost(message, repeated, tag = "6")]
+    pub counterparty_versions: Vec<Version>,
+    #[prost(message, optional, tag = "7")]
+    pub proof_height: Option<Height>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub proof_init: Vec<u8>,
+    #[prost(bytes = "vec", tag = "9")]
+    pub proof_client: Vec<u8>,
+    #[prost(bytes = "vec", tag = "10")]
+    pub proof_consensus: Vec<u8>,
+    #[prost(message, optional, tag = "11")]
+    pub consensus_height: Option<Height>,
+    #[prost(string, tag = "12")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgConnectionOpenAck {
+    #[prost(string, tag = "1")]
+    pub connection_id: String,
+    #[prost(string, tag = "2")]
+    pub counterparty_connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub version: Option<Version>,
+    #[prost(message, optional, tag = "4")]
+    pub client_state: Option<Any>,
+    #[prost(message, optional, tag = "5")]
+    pub proof_height: Option<Height>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub proof_try: Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub proof_client: Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub proof_consensus: Vec<u8>,
+    #[prost(message, optional, tag = "9")]
+    pub consensus_height: Option<Height>,
+    #[prost(string, tag = "10")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgConnectionOpenConfirm {
+    #[prost(string, tag = "1")]
+    pub connection_id: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_ack: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+// ibc.core.channel.v1
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ChannelCounterparty {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Channel {
+    #[prost(int32, tag = "1")]
+    pub state: i32,
+    #[prost(int32, tag = "2")]
+    pub ordering: i32,
+    #[prost(message, optional, tag = "3")]
+    pub counterparty: Option<ChannelCounterparty>,
+    #[prost(string, repeated, tag = "4")]
+    pub connection_hops: Vec<String>,
+    #[prost(string, tag = "5")]
+    pub version: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Packet {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    #[prost(string, tag = "2")]
+    pub source_port: String,
+    #[prost(string, tag = "3")]
+    pub source_channel: String,
+    #[prost(string, tag = "4")]
+    pub destination_port: String,
+    #[prost(string, tag = "5")]
+    pub destination_channel: String,
+    #[prost(bytes = "vec", tag = "6")]
+    pub data: Vec<u8>,
+    #[prost(message, optional, tag = "7")]
+    pub timeout_height: Option<Height>,
+    #[prost(uint64, tag = "8")]
+    pub timeout_timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgChannelOpenInit {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub channel: Option<Channel>,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgChannelOpenTry {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub previous_channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub channel: Option<Channel>,
+    #[prost(string, tag = "4")]
+    pub counterparty_version: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub proof_init: Vec<u8>,
+    #[prost(message, optional, tag = "6")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "7")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgChannelOpenAck {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(string, tag = "3")]
+    pub counterparty_channel_id: String,
+    #[prost(string, tag = "4")]
+    pub counterparty_version: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub proof_try: Vec<u8>,
+    #[prost(message, optional, tag = "6")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "7")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgChannelOpenConfirm {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof_ack: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgChannelCloseInit {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgChannelCloseConfirm {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof_init: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgRecvPacket {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_commitment: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgTimeout {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_unreceived: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<Height>,
+    #[prost(uint64, tag = "4")]
+    pub next_sequence_recv: u64,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgAcknowledgement {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub acknowledgement: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof_acked: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
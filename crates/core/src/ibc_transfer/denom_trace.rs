@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::ibc_transfer::proto;
+use crate::ProtoMsg;
+
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// The origin of a voucher denom: the `port/channel` hops it travelled
+/// through (most recent first) and its denom on the source chain.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct DenomTrace {
+    pub path: String,
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    pub fn new(path: impl Into<String>, base_denom: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            base_denom: base_denom.into(),
+        }
+    }
+
+    /// Parses a full path like `transfer/channel-0/uatom`. Base denoms may
+    /// themselves contain `/`; the path ends at the first segment pair that
+    /// is not `port/channel-N`.
+    pub fn parse(full_path: &str) -> Self {
+        let segments: Vec<&str> = full_path.split('/').collect();
+        let mut hops = 0;
+        while hops * 2 + 2 < segments.len() && is_channel_id(segments[hops * 2 + 1]) {
+            hops += 1;
+        }
+        Self {
+            path: segments[..hops * 2].join("/"),
+            base_denom: segments[hops * 2..].join("/"),
+        }
+    }
+
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Uppercase hex SHA-256 of the full path, as used in `ibc/{hash}`.
+    pub fn hash(&self) -> String {
+        hex::encode_upper(Sha256::digest(self.full_path().as_bytes()))
+    }
+
+    /// The denom of this voucher on the receiving chain.
+    pub fn ibc_denom(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}{}", IBC_DENOM_PREFIX, self.hash())
+        }
+    }
+
+    /// Whether the token is native to the chain holding it.
+    pub fn is_native(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// The `(port, channel)` hops in `path`. A trailing port without a
+    /// channel, as in a malformed path, is left out.
+    pub fn hops(&self) -> Vec<(&str, &str)> {
+        let segments: Vec<&str> = self.path.split('/').filter(|s| !s.is_empty()).collect();
+        segments
+            .chunks_exact(2)
+            .map(|hop| (hop[0], hop[1]))
+            .collect()
+    }
+}
+
+fn is_channel_id(segment: &str) -> bool {
+    segment
+        .strip_prefix("channel-")
+        .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// The hash part of an `ibc/{hash}` denom.
+pub fn ibc_denom_hash(denom: &str) -> Option<&str> {
+    denom.strip_prefix(IBC_DENOM_PREFIX)
+}
+
+impl ProtoMsg for DenomTrace {
+    type Proto = proto::DenomTrace;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::DenomTrace {
+            path: self.path.clone(),
+            base_denom: self.base_denom.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            path: proto.path,
+            base_denom: proto.base_denom,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_computes_ibc_denoms() {
+        let trace = DenomTrace::parse("transfer/channel-0/uatom");
+        assert_eq!(trace, DenomTrace::new("transfer/channel-0", "uatom"));
+        assert_eq!(
+            trace.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(trace.hops(), vec![("transfer", "channel-0")]);
+        assert_eq!(
+            ibc_denom_hash(&trace.ibc_denom()),
+            Some(trace.hash().as_str())
+        );
+
+        let trace = DenomTrace::parse("transfer/channel-1/transfer/channel-7/gamm/pool/1");
+        assert_eq!(trace.path, "transfer/channel-1/transfer/channel-7");
+        assert_eq!(trace.base_denom, "gamm/pool/1");
+        assert_eq!(trace.hops().len(), 2);
+        assert_eq!(
+            DenomTrace::new("transfer/channel-0/transfer", "uatom").hops(),
+            vec![("transfer", "channel-0")]
+        );
+
+        let native = DenomTrace::parse("uluna");
+        assert!(native.is_native());
+        assert_eq!(native.ibc_denom(), "uluna");
+    }
+}
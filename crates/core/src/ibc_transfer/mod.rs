@@ -0,0 +1,7 @@
+pub mod denom_trace;
+pub use denom_trace::*;
+
+pub mod msgs;
+pub use msgs::*;
+
+pub mod proto;
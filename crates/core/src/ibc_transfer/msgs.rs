@@ -0,0 +1,262 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::ibc::Height;
+use crate::ibc_transfer::proto;
+use crate::sdk::proto::coin_from_proto;
+use crate::{AccAddress, Coin, ProtoMsg};
+
+pub const TRANSFER_PORT: &str = "transfer";
+
+/// Blocks on the destination chain after which a transfer times out by
+/// default, as in the `ibc-transfer` CLI.
+pub const DEFAULT_TIMEOUT_BLOCKS: u64 = 1000;
+
+/// Time after which a transfer times out by default, as in the
+/// `ibc-transfer` CLI.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Sends `token` over an ICS-20 channel. The packet times out when either
+/// the destination chain reaches `timeout_height` or its block time passes
+/// `timeout_timestamp` (nanoseconds since the Unix epoch); zero disables
+/// either check, but at least one must be set.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgTransfer")]
+#[msgtype_pb("/ibc.applications.transfer.v1.MsgTransfer")]
+pub struct MsgTransfer {
+    pub source_port: String,
+    pub source_channel: String,
+    pub token: Coin,
+    pub sender: AccAddress,
+    /// Address on the destination chain, which may use another prefix.
+    pub receiver: String,
+    #[serde(default)]
+    pub timeout_height: Height,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::internal::json_form::is_amino_zero",
+        with = "crate::internal::serde_str"
+    )]
+    pub timeout_timestamp: u64,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub memo: String,
+}
+
+impl MsgTransfer {
+    /// A transfer over `transfer/{source_channel}` with no timeouts set.
+    pub fn new(
+        source_channel: impl Into<String>,
+        token: Coin,
+        sender: impl Into<AccAddress>,
+        receiver: impl Into<String>,
+    ) -> Self {
+        Self {
+            source_port: TRANSFER_PORT.into(),
+            source_channel: source_channel.into(),
+            token,
+            sender: sender.into(),
+            receiver: receiver.into(),
+            timeout_height: Height::default(),
+            timeout_timestamp: 0,
+            memo: String::new(),
+        }
+    }
+
+    pub fn timeout_height(mut self, height: Height) -> Self {
+        self.timeout_height = height;
+        self
+    }
+
+    /// Times out at `time`, saturating to the range of unix nanoseconds
+    /// the chain accepts.
+    pub fn timeout_timestamp(mut self, time: DateTime<Utc>) -> Self {
+        let nanos = i128::from(time.timestamp()) * 1_000_000_000
+            + i128::from(time.timestamp_subsec_nanos());
+        self.timeout_timestamp = u64::try_from(nanos.max(0)).unwrap_or(u64::MAX);
+        self
+    }
+
+    /// Times out `blocks` after `destination_height` or `timeout` after
+    /// `now`, whichever comes first. `destination_height` is the latest
+    /// height of the receiving chain, not of Terra.
+    pub fn timeout_after(
+        self,
+        destination_height: Height,
+        blocks: u64,
+        now: DateTime<Utc>,
+        timeout: Duration,
+    ) -> Self {
+        let deadline = chrono::Duration::from_std(timeout)
+            .ok()
+            .and_then(|timeout| now.checked_add_signed(timeout))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.timeout_height(destination_height.add(blocks))
+            .timeout_timestamp(deadline)
+    }
+
+    /// `timeout_after` with `DEFAULT_TIMEOUT_BLOCKS` and `DEFAULT_TIMEOUT`.
+    pub fn default_timeouts(self, destination_height: Height, now: DateTime<Utc>) -> Self {
+        self.timeout_after(
+            destination_height,
+            DEFAULT_TIMEOUT_BLOCKS,
+            now,
+            DEFAULT_TIMEOUT,
+        )
+    }
+
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.source_port.is_empty() || self.source_channel.is_empty() {
+            return Err("source port and channel are required".into());
+        }
+        if self.token.amount.u128() == 0 {
+            return Err("transfer amount must be positive".into());
+        }
+        if self.receiver.is_empty() {
+            return Err("receiver is required".into());
+        }
+        if self.timeout_height.is_zero() && self.timeout_timestamp == 0 {
+            return Err("timeout height or timestamp must be set".into());
+        }
+        Ok(())
+    }
+}
+
+impl ProtoMsg for MsgTransfer {
+    type Proto = proto::MsgTransfer;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgTransfer {
+            source_port: self.source_port.clone(),
+            source_channel: self.source_channel.clone(),
+            token: Some((&self.token).into()),
+            sender: self.sender.to_string(),
+            receiver: self.receiver.clone(),
+            timeout_height: Some(self.timeout_height.to_proto()),
+            timeout_timestamp: self.timeout_timestamp,
+            memo: self.memo.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            source_port: proto.source_port,
+            source_channel: proto.source_channel,
+            token: coin_from_proto(proto.token)?,
+            sender: proto.sender.into(),
+            receiver: proto.receiver,
+            timeout_height: Height::from_optional_proto(proto.timeout_height),
+            timeout_timestamp: proto.timeout_timestamp,
+            memo: proto.memo,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{AuthInfo, Fee, StdSignDoc, Tx, TxBody};
+    use crate::{coin, CoinMacroAmount, JsonSer, Msg};
+    use serde_json::json;
+
+    #[test]
+    fn it_builds_transfers_with_timeouts() {
+        let now: DateTime<Utc> = "2023-01-01T00:00:00Z".parse().unwrap();
+        let msg = MsgTransfer::new(
+            "channel-1",
+            coin!("uluna", 1000000),
+            "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+            "osmo1v9ku44wycfnsucez6fp085f5fsksp47uxfdpwn",
+        );
+        assert!(msg.validate().is_err());
+
+        let msg = msg.default_timeouts(Height::for_chain("osmosis-1", 7_000_000), now);
+        msg.validate().unwrap();
+        assert_eq!(
+            msg.to_json().unwrap()["value"],
+            json!({
+                "source_port": "transfer",
+                "source_channel": "channel-1",
+                "token": { "denom": "uluna", "amount": "1000000" },
+                "sender": "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+                "receiver": "osmo1v9ku44wycfnsucez6fp085f5fsksp47uxfdpwn",
+                "timeout_height": { "revision_number": "1", "revision_height": "7001000" },
+                "timeout_timestamp": "1672531800000000000"
+            })
+        );
+        assert_eq!(MsgTransfer::from_any(&msg.to_any().unwrap()).unwrap(), msg);
+
+        let far = msg.timeout_timestamp("3000-01-01T00:00:00Z".parse().unwrap());
+        assert_eq!(far.timeout_timestamp, u64::MAX);
+    }
+
+    #[test]
+    fn it_omits_zero_timeouts_from_amino_sign_bytes() {
+        let msg = MsgTransfer::new(
+            "channel-1",
+            coin!("uluna", 1000000),
+            "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+            "osmo1v9ku44wycfnsucez6fp085f5fsksp47uxfdpwn",
+        )
+        .timeout_timestamp("2023-01-01T00:00:00Z".parse().unwrap());
+        msg.validate().unwrap();
+
+        let tx = Tx {
+            body: TxBody {
+                messages: vec![msg.to_any().unwrap()],
+                memo: String::new(),
+                timeout_height: 0,
+                ..Default::default()
+            },
+            auth_info: AuthInfo {
+                signer_infos: vec![],
+                fee: Fee::new(100_000, "2uluna"),
+            },
+            signatures: vec![],
+        };
+        let doc = StdSignDoc::new(&tx, "columbus-5", 7, 3).unwrap();
+        assert_eq!(
+            String::from_utf8(doc.sign_bytes()).unwrap(),
+            concat!(
+                r#"{"account_number":"7","chain_id":"columbus-5","#,
+                r#""fee":{"amount":[{"amount":"2","denom":"uluna"}],"gas":"100000"},"#,
+                r#""memo":"","msgs":[{"type":"cosmos-sdk/MsgTransfer","value":{"#,
+                r#""receiver":"osmo1v9ku44wycfnsucez6fp085f5fsksp47uxfdpwn","#,
+                r#""sender":"terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4","#,
+                r#""source_channel":"channel-1","source_port":"transfer","#,
+                r#""timeout_height":{},"timeout_timestamp":"1672531200000000000","#,
+                r#""token":{"amount":"1000000","denom":"uluna"}}}],"sequence":"3"}"#
+            )
+        );
+
+        // Revision 0, as on `localterra`, and no timestamp.
+        let msg = msg.timeout_height(Height::new(0, 100));
+        let msg = MsgTransfer {
+            timeout_timestamp: 0,
+            ..msg
+        };
+        assert_eq!(
+            msg.to_json().unwrap()["value"]["timeout_height"],
+            json!({ "revision_height": "100" })
+        );
+        assert!(msg.to_json().unwrap()["value"]
+            .get("timeout_timestamp")
+            .is_none());
+
+        // Protobuf JSON keeps zeros.
+        let data = msg.to_data().unwrap();
+        assert_eq!(
+            data["timeout_height"],
+            json!({ "revision_number": "0", "revision_height": "100" })
+        );
+        assert_eq!(data["timeout_timestamp"], "0");
+        assert_eq!(MsgTransfer::from_data(&data).unwrap(), msg);
+    }
+}
@@ -0,0 +1,146 @@
+/// Defines an enum over the concrete types that can fill a protobuf
+/// interface field (proposal content, authorizations, fee allowances...).
+///
+/// The enum packs to and unpacks from `Any`, serializes to protobuf JSON
+/// (`@type`) within `Msg::to_data` and to Amino JSON (`type`/`value`)
+/// otherwise, and deserializes from either. Every variant type must
+/// implement `Msg`.
+///
+/// A trailing `..Other(Fallback)` adds a variant for values of unknown
+/// types, so that reading them does not fail. `Fallback` provides
+/// `from_any`, `from_json`, `to_any`, `to_amino`, `to_data`, `type_url`
+/// and `amino_type` with the same signatures as the enum itself.
+macro_rules! any_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $what:literal {
+            $($variant:ident($ty:ty)),* $(,)?
+            $(..$other:ident($other_ty:ty))?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Clone)]
+        pub enum $name {
+            $($variant($ty),)*
+            $($other($other_ty),)?
+        }
+
+        impl $name {
+            pub fn type_url(&self) -> &str {
+                match self {
+                    $($name::$variant(_) => <$ty as $crate::MsgType>::TYPE_URL,)*
+                    $($name::$other(v) => v.type_url(),)?
+                }
+            }
+
+            pub fn amino_type(&self) -> &str {
+                match self {
+                    $($name::$variant(_) => <$ty as $crate::MsgType>::AMINO_TYPE,)*
+                    $($name::$other(v) => v.amino_type(),)?
+                }
+            }
+
+            pub fn to_any(&self) -> Result<$crate::Any, String> {
+                match self {
+                    $($name::$variant(v) => $crate::Msg::to_any(v),)*
+                    $($name::$other(v) => v.to_any(),)?
+                }
+            }
+
+            pub fn from_any(any: &$crate::Any) -> Result<Self, String> {
+                $(
+                    if any.type_url == <$ty as $crate::MsgType>::TYPE_URL {
+                        return <$ty as $crate::Msg>::from_any(any).map($name::$variant);
+                    }
+                )*
+                any_enum!(@unknown $name $what any.type_url $(, $other(<$other_ty>::from_any(any)))?)
+            }
+
+            pub fn to_amino(&self) -> Result<serde_json::Value, String> {
+                match self {
+                    $($name::$variant(v) => $crate::JsonSer::to_json(v),)*
+                    $($name::$other(v) => v.to_amino(),)?
+                }
+            }
+
+            pub fn to_data(&self) -> Result<serde_json::Value, String> {
+                match self {
+                    $($name::$variant(v) => $crate::Msg::to_data(v),)*
+                    $($name::$other(v) => v.to_data(),)?
+                }
+            }
+
+            pub fn from_amino(json: &serde_json::Value) -> Result<Self, String> {
+                let amino_type = json
+                    .get("type")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default();
+                $(
+                    if amino_type == <$ty as $crate::MsgType>::AMINO_TYPE {
+                        return <$ty as $crate::Msg>::from_amino(json).map($name::$variant);
+                    }
+                )*
+                any_enum!(@unknown $name $what amino_type $(, $other(<$other_ty>::from_json(json)))?)
+            }
+
+            pub fn from_data(data: &serde_json::Value) -> Result<Self, String> {
+                let type_url = data
+                    .get("@type")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default();
+                $(
+                    if type_url == <$ty as $crate::MsgType>::TYPE_URL {
+                        return <$ty as $crate::Msg>::from_data(data).map($name::$variant);
+                    }
+                )*
+                any_enum!(@unknown $name $what type_url $(, $other(<$other_ty>::from_json(data)))?)
+            }
+        }
+
+        $(
+            impl From<$ty> for $name {
+                fn from(value: $ty) -> Self {
+                    $name::$variant(value)
+                }
+            }
+        )*
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let json = if $crate::internal::json_form::is_proto_json() {
+                    self.to_data()
+                } else {
+                    self.to_amino()
+                }
+                .map_err(serde::ser::Error::custom)?;
+                serde::Serialize::serialize(&json, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+                if value.get("@type").is_some() {
+                    $name::from_data(&value)
+                } else {
+                    $name::from_amino(&value)
+                }
+                .map_err(serde::de::Error::custom)
+            }
+        }
+    };
+
+    // A value of none of the variant types.
+    (@unknown $name:ident $what:literal $type_name:expr) => {
+        Err(format!(concat!("unknown ", $what, ": {}"), $type_name))
+    };
+    (@unknown $name:ident $what:literal $type_name:expr, $other:ident($value:expr)) => {
+        Ok($name::$other($value))
+    };
+}
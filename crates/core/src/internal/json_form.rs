@@ -0,0 +1,38 @@
+//! Which JSON form a message is being written in.
+//!
+//! Nested packed messages, interface values and durations look different
+//! in Amino JSON and in protobuf JSON, but share one `Serialize` impl with
+//! the message holding them. `Msg::to_data` writes them as protobuf JSON;
+//! everything else, including `JsonSer::to_json`, as Amino JSON.
+
+use std::cell::Cell;
+
+thread_local! {
+    static PROTO_JSON: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether values are currently written as protobuf JSON.
+pub fn is_proto_json() -> bool {
+    PROTO_JSON.with(Cell::get)
+}
+
+/// Whether a number is left out: Amino JSON omits zeros of fields Go tags
+/// `omitempty`, protobuf JSON keeps them.
+pub fn is_amino_zero(value: &u64) -> bool {
+    *value == 0 && !is_proto_json()
+}
+
+/// Runs `f` writing values as protobuf JSON if `proto_json`, otherwise as
+/// Amino JSON, and restores the previous form afterwards.
+pub fn with_form<T>(proto_json: bool, f: impl FnOnce() -> T) -> T {
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PROTO_JSON.with(|form| form.set(self.0));
+        }
+    }
+
+    let _restore = Restore(PROTO_JSON.with(|form| form.replace(proto_json)));
+    f()
+}
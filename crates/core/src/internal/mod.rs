@@ -1,3 +1,9 @@
 #[macro_use]
+mod any_enum;
+#[macro_use]
 pub mod bech32;
-pub mod msg;
+pub mod json_form;
+pub mod serde_any;
+pub mod serde_base64;
+pub mod serde_duration;
+pub mod serde_str;
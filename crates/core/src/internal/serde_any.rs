@@ -0,0 +1,36 @@
+//! `#[serde(with = "...")]` helpers for `Any` fields holding types this
+//! crate does not model (e.g. light client states and headers), written as
+//! `{"type_url": ..., "value": <base64>}`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Any;
+
+#[derive(Serialize, Deserialize)]
+struct RawAny {
+    type_url: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    value: Vec<u8>,
+}
+
+pub fn serialize<S>(any: &Any, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    RawAny {
+        type_url: any.type_url.clone(),
+        value: any.value.clone(),
+    }
+    .serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Any, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = RawAny::deserialize(deserializer)?;
+    Ok(Any {
+        type_url: raw.type_url,
+        value: raw.value,
+    })
+}
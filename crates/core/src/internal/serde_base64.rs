@@ -0,0 +1,18 @@
+//! `#[serde(with = "...")]` helpers for byte fields encoded as base64.
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&base64::encode(bytes))
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    base64::decode(s).map_err(serde::de::Error::custom)
+}
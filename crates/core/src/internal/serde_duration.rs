@@ -0,0 +1,68 @@
+//! `#[serde(with = "...")]` helpers for `std::time::Duration`, written as
+//! protobuf JSON seconds (`"3600s"`, `"0.500s"`) within `Msg::to_data` and
+//! as Amino JSON nanoseconds (`"3600000000000"`) otherwise, and read from
+//! either.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::internal::json_form::is_proto_json;
+
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if is_proto_json() {
+        serializer.collect_str(&format_seconds(duration))
+    } else {
+        serializer.collect_str(&duration.as_nanos())
+    }
+}
+
+/// Seconds with 0, 3, 6 or 9 fractional digits, like protobuf JSON.
+fn format_seconds(duration: &Duration) -> String {
+    let (secs, nanos) = (duration.as_secs(), duration.subsec_nanos());
+    if nanos == 0 {
+        format!("{}s", secs)
+    } else if nanos % 1_000_000 == 0 {
+        format!("{}.{:03}s", secs, nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        format!("{}.{:06}s", secs, nanos / 1_000)
+    } else {
+        format!("{}.{:09}s", secs, nanos)
+    }
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse(&s).map_err(serde::de::Error::custom)
+}
+
+pub(crate) fn parse(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration: {}", s);
+    match s.strip_suffix('s') {
+        Some(seconds) => {
+            let (whole, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+            if fraction.len() > 9 {
+                return Err(invalid());
+            }
+            let whole = whole.parse::<u64>().map_err(|_| invalid())?;
+            let nanos = if fraction.is_empty() {
+                0
+            } else {
+                format!("{:0<9}", fraction)
+                    .parse::<u32>()
+                    .map_err(|_| invalid())?
+            };
+            Ok(Duration::new(whole, nanos))
+        }
+        None => s
+            .parse::<u64>()
+            .map(Duration::from_nanos)
+            .map_err(|_| invalid()),
+    }
+}
@@ -1,23 +1,27 @@
-// pub mod auth;
-// pub mod authz;
-pub mod bank;
-// pub mod distribution;
-// pub mod feegrant;
-// pub mod gov;
-// pub mod ibc;
-// pub mod ibc_transfer;
-// pub mod market;
-// pub mod oracle;
-// pub mod params;
-pub mod sdk;
-// pub mod slashing;
-// pub mod staking;
-// pub mod treasury;
-// pub mod wasm;
-
 #[macro_use]
 mod internal;
 
+pub mod auth;
+pub mod authz;
+pub mod bank;
+pub mod cw20;
+pub mod distribution;
+pub mod feegrant;
+pub mod gov;
+pub mod ibc;
+pub mod ibc_transfer;
+pub mod market;
+pub mod mint;
+pub mod oracle;
+pub mod params;
+pub mod registry;
+pub mod sdk;
+pub mod slashing;
+pub mod staking;
+pub mod treasury;
+pub mod upgrade;
+pub mod wasm;
+
 pub use sdk::*;
 
 pub mod traits;
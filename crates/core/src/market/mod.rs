@@ -1,2 +1,10 @@
 pub mod msgs;
 pub use msgs::*;
+
+pub mod params;
+pub use params::*;
+
+pub mod simulate;
+pub use simulate::*;
+
+pub mod proto;
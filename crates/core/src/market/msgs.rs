@@ -1,5 +1,131 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::market::proto;
+use crate::sdk::proto::coin_from_proto;
+use crate::{AccAddress, Coin, ProtoMsg};
+
+/// Swaps `offer_coin` for `ask_denom` at the market rate.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("market/MsgSwap")]
+#[msgtype_pb("/terra.market.v1beta1.MsgSwap")]
 pub struct MsgSwap {
     pub trader: AccAddress,
     pub offer_coin: Coin,
-    pub denom: Denom,
+    pub ask_denom: String,
+}
+
+impl MsgSwap {
+    pub fn new(
+        trader: impl Into<AccAddress>,
+        offer_coin: Coin,
+        ask_denom: impl Into<String>,
+    ) -> Self {
+        Self {
+            trader: trader.into(),
+            offer_coin,
+            ask_denom: ask_denom.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgSwap {
+    type Proto = proto::MsgSwap;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgSwap {
+            trader: self.trader.to_string(),
+            offer_coin: Some((&self.offer_coin).into()),
+            ask_denom: self.ask_denom.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            trader: proto.trader.into(),
+            offer_coin: coin_from_proto(proto.offer_coin)?,
+            ask_denom: proto.ask_denom,
+        })
+    }
+}
+
+/// Like `MsgSwap`, but sends the swapped coins to `to_address`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("market/MsgSwapSend")]
+#[msgtype_pb("/terra.market.v1beta1.MsgSwapSend")]
+pub struct MsgSwapSend {
+    pub from_address: AccAddress,
+    pub to_address: AccAddress,
+    pub offer_coin: Coin,
+    pub ask_denom: String,
+}
+
+impl MsgSwapSend {
+    pub fn new(
+        from_address: impl Into<AccAddress>,
+        to_address: impl Into<AccAddress>,
+        offer_coin: Coin,
+        ask_denom: impl Into<String>,
+    ) -> Self {
+        Self {
+            from_address: from_address.into(),
+            to_address: to_address.into(),
+            offer_coin,
+            ask_denom: ask_denom.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgSwapSend {
+    type Proto = proto::MsgSwapSend;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgSwapSend {
+            from_address: self.from_address.to_string(),
+            to_address: self.to_address.to_string(),
+            offer_coin: Some((&self.offer_coin).into()),
+            ask_denom: self.ask_denom.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            from_address: proto.from_address.into(),
+            to_address: proto.to_address.into(),
+            offer_coin: coin_from_proto(proto.offer_coin)?,
+            ask_denom: proto.ask_denom,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coin, CoinMacroAmount, JsonSer, Msg};
+    use serde_json::json;
+
+    const TRADER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+    const RECIPIENT: &str = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+
+    #[test]
+    fn it_encodes_swaps() {
+        let swap = MsgSwap::new(TRADER, coin!("uluna", 1000000), "uusd");
+        assert_eq!(
+            swap.to_json().unwrap(),
+            json!({
+                "type": "market/MsgSwap",
+                "value": {
+                    "trader": TRADER,
+                    "offer_coin": { "denom": "uluna", "amount": "1000000" },
+                    "ask_denom": "uusd"
+                }
+            })
+        );
+        assert_eq!(MsgSwap::from_any(&swap.to_any().unwrap()).unwrap(), swap);
+
+        let send = MsgSwapSend::new(TRADER, RECIPIENT, coin!("uusd", 500), "uluna");
+        let any = send.to_any().unwrap();
+        assert_eq!(any.type_url, "/terra.market.v1beta1.MsgSwapSend");
+        assert_eq!(MsgSwapSend::from_any(&any).unwrap(), send);
+    }
 }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Dec;
+
+/// Parameters of the market module's constant-product swap pool.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct MarketParams {
+    /// Size of each side of the pool at equilibrium, in `usdr`.
+    pub base_pool: Dec,
+    /// Number of blocks over which the pool delta recovers to zero.
+    #[serde(with = "crate::internal::serde_str")]
+    pub pool_recovery_period: u64,
+    /// Minimum spread charged on Terra <> Luna swaps.
+    pub min_stability_spread: Dec,
+}
+
+/// The chain's default parameters.
+impl Default for MarketParams {
+    fn default() -> Self {
+        Self {
+            base_pool: Dec::from(1_000_000usize) * Dec::from(1_000_000usize),
+            pool_recovery_period: 14_400,
+            min_stability_spread: Dec::new(2 * 10i128.pow(16)),
+        }
+    }
+}
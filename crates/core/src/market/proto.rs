@@ -0,0 +1,27 @@
+//! Protobuf counterparts of the `terra.market.v1beta1` messages.
+
+use prost::Message;
+
+use crate::sdk::proto::Coin;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgSwap {
+    #[prost(string, tag = "1")]
+    pub trader: String,
+    #[prost(message, optional, tag = "2")]
+    pub offer_coin: Option<Coin>,
+    #[prost(string, tag = "3")]
+    pub ask_denom: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgSwapSend {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(message, optional, tag = "3")]
+    pub offer_coin: Option<Coin>,
+    #[prost(string, tag = "4")]
+    pub ask_denom: String,
+}
@@ -0,0 +1,254 @@
+use num::bigint::BigInt;
+use num::ToPrimitive;
+
+use crate::market::MarketParams;
+use crate::sdk::numeric::dec::{div_raw, mul_raw};
+use crate::{Coin, Dec, DecCoin, DecCoins};
+
+pub const MICRO_LUNA_DENOM: &str = "uluna";
+pub const MICRO_SDR_DENOM: &str = "usdr";
+
+fn overflow(offer: &impl std::fmt::Display) -> String {
+    format!("swap of {} overflows", offer)
+}
+
+/// Outcome of a simulated `MsgSwap`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapResult {
+    /// Coins received by the trader.
+    pub return_coin: Coin,
+    /// Spread fee burned by the swap, including the truncated fraction.
+    pub fee: Coin,
+    pub spread: Dec,
+    /// Terra pool delta after the swap.
+    pub terra_pool_delta: Dec,
+}
+
+/// Reproduces the market module's swap computation offline.
+///
+/// `exchange_rates` are the oracle's Luna exchange rates (e.g. `uusd` per
+/// `uluna`), `tobin_taxes` the per-denom taxes on Terra <> Terra swaps.
+#[derive(Debug, Clone)]
+pub struct SwapSimulator {
+    pub params: MarketParams,
+    pub exchange_rates: DecCoins,
+    pub tobin_taxes: DecCoins,
+    pub terra_pool_delta: Dec,
+}
+
+impl SwapSimulator {
+    pub fn new(
+        params: MarketParams,
+        exchange_rates: impl Into<DecCoins>,
+        tobin_taxes: impl Into<DecCoins>,
+        terra_pool_delta: Dec,
+    ) -> Self {
+        Self {
+            params,
+            exchange_rates: exchange_rates.into(),
+            tobin_taxes: tobin_taxes.into(),
+            terra_pool_delta,
+        }
+    }
+
+    fn luna_exchange_rate(&self, denom: &str) -> Result<Dec, String> {
+        if denom == MICRO_LUNA_DENOM {
+            return Ok(Dec::one());
+        }
+        self.exchange_rates
+            .get(denom)
+            .map(|rate| rate.amount)
+            .filter(|rate| *rate > Dec::zero())
+            .ok_or_else(|| format!("no exchange rate for {}", denom))
+    }
+
+    fn tobin_tax(&self, denom: &str) -> Result<Dec, String> {
+        self.tobin_taxes
+            .get(denom)
+            .map(|tax| tax.amount)
+            .ok_or_else(|| format!("no tobin tax for {}", denom))
+    }
+
+    /// Converts at oracle rates, without spread.
+    pub fn compute_internal_swap(
+        &self,
+        offer: &DecCoin,
+        ask_denom: &str,
+    ) -> Result<DecCoin, String> {
+        if offer.denom == ask_denom {
+            return Ok(offer.clone());
+        }
+        let offer_rate = self.luna_exchange_rate(&offer.denom)?;
+        let ask_rate = self.luna_exchange_rate(ask_denom)?;
+        let amount = offer
+            .amount
+            .checked_mul(ask_rate)
+            .and_then(|amount| amount.checked_div(offer_rate))
+            .ok_or_else(|| overflow(offer))?;
+        if amount <= Dec::zero() {
+            return Err(format!(
+                "{}{} is too small to swap",
+                offer.amount, offer.denom
+            ));
+        }
+        Ok(DecCoin::new(ask_denom, amount))
+    }
+
+    /// The ask amount before fees and the spread to charge on it, like the
+    /// chain's `ComputeSwap`.
+    pub fn compute_swap(&self, offer: &DecCoin, ask_denom: &str) -> Result<(DecCoin, Dec), String> {
+        if offer.denom == ask_denom {
+            return Err(format!("cannot swap {} to itself", ask_denom));
+        }
+        let base_offer = self.compute_internal_swap(offer, MICRO_SDR_DENOM)?;
+        let ask = self.compute_internal_swap(&base_offer, ask_denom)?;
+
+        if offer.denom != MICRO_LUNA_DENOM && ask_denom != MICRO_LUNA_DENOM {
+            let spread = self
+                .tobin_tax(&offer.denom)?
+                .max(self.tobin_tax(ask_denom)?);
+            return Ok((ask, spread));
+        }
+
+        // The constant product is far outside the range of `Dec`, so the pool
+        // math runs on the raw values with the same rounding.
+        let base_pool = BigInt::from(self.params.base_pool.raw());
+        let cp = mul_raw(&base_pool, &base_pool);
+        let terra_pool = &base_pool + BigInt::from(self.terra_pool_delta.raw());
+        if terra_pool <= BigInt::from(0) {
+            return Err("terra pool is depleted".into());
+        }
+        let luna_pool = div_raw(&cp, &terra_pool);
+        let (offer_pool, ask_pool) = if offer.denom != MICRO_LUNA_DENOM {
+            (terra_pool, luna_pool)
+        } else {
+            (luna_pool, terra_pool)
+        };
+
+        let base_offer_amount = BigInt::from(base_offer.amount.raw());
+        let ask_base_amount = ask_pool - div_raw(&cp, &(offer_pool + &base_offer_amount));
+        let spread = div_raw(&(&base_offer_amount - ask_base_amount), &base_offer_amount)
+            .to_i128()
+            .map(Dec::new)
+            .ok_or("spread out of range")?;
+        Ok((ask, spread.max(self.params.min_stability_spread)))
+    }
+
+    /// Simulates `MsgSwap` without changing the pool.
+    pub fn simulate(&self, offer: &Coin, ask_denom: &str) -> Result<SwapResult, String> {
+        let (mut ask, spread) = self.compute_swap(&DecCoin::try_from(offer.clone())?, ask_denom)?;
+        let fee_amount = if spread > Dec::zero() {
+            spread
+                .checked_mul(ask.amount)
+                .ok_or_else(|| overflow(offer))?
+        } else {
+            Dec::zero()
+        };
+        ask.amount = ask
+            .amount
+            .checked_sub(fee_amount)
+            .ok_or_else(|| overflow(offer))?;
+
+        let terra_pool_delta = self.apply_swap_to_pool(offer, &ask)?;
+        let (return_coin, change) = ask.truncate();
+        let fee_amount = fee_amount
+            .checked_add(change.amount)
+            .ok_or_else(|| overflow(offer))?;
+        let (fee, _) = DecCoin::new(ask_denom, fee_amount).truncate();
+        Ok(SwapResult {
+            return_coin,
+            fee,
+            spread,
+            terra_pool_delta,
+        })
+    }
+
+    /// Simulates `MsgSwap` and updates the pool delta.
+    pub fn swap(&mut self, offer: &Coin, ask_denom: &str) -> Result<SwapResult, String> {
+        let result = self.simulate(offer, ask_denom)?;
+        self.terra_pool_delta = result.terra_pool_delta;
+        Ok(result)
+    }
+
+    /// Moves the pool delta towards zero, as the chain does every block.
+    pub fn replenish_pool(&mut self) {
+        let period = self.params.pool_recovery_period.max(1) as i128;
+        let regression = Dec::new(self.terra_pool_delta.raw() / period);
+        self.terra_pool_delta = self.terra_pool_delta - regression;
+    }
+
+    fn apply_swap_to_pool(&self, offer: &Coin, ask: &DecCoin) -> Result<Dec, String> {
+        let delta = self.terra_pool_delta;
+        if offer.denom != MICRO_LUNA_DENOM && ask.denom == MICRO_LUNA_DENOM {
+            let base =
+                self.compute_internal_swap(&DecCoin::try_from(offer.clone())?, MICRO_SDR_DENOM)?;
+            return delta
+                .checked_add(base.amount)
+                .ok_or_else(|| overflow(offer));
+        }
+        if offer.denom == MICRO_LUNA_DENOM && ask.denom != MICRO_LUNA_DENOM {
+            let base = self.compute_internal_swap(ask, MICRO_SDR_DENOM)?;
+            return delta
+                .checked_sub(base.amount)
+                .ok_or_else(|| overflow(offer));
+        }
+        Ok(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coin, dec, CoinMacroAmount, DecMacroInput, Uint128};
+
+    fn simulator() -> SwapSimulator {
+        SwapSimulator::new(
+            MarketParams::default(),
+            DecCoins::parse("80usdr,100uusd,120000ukrw").unwrap(),
+            DecCoins::parse("0.0035uusd,0.0035usdr,0.02ukrw").unwrap(),
+            Dec::zero(),
+        )
+    }
+
+    #[test]
+    fn it_applies_tobin_tax_between_terra_denoms() {
+        let result = simulator()
+            .simulate(&coin!("uusd", 1000000), "ukrw")
+            .unwrap();
+        assert_eq!(result.spread, dec!("0.02"));
+        // 1 UST = 1200 KRW, minus 2%.
+        assert_eq!(result.return_coin, coin!("ukrw", 1176000000));
+        assert_eq!(result.fee, coin!("ukrw", 24000000));
+        assert_eq!(result.terra_pool_delta, Dec::zero());
+    }
+
+    #[test]
+    fn it_swaps_against_the_constant_product_pool() {
+        let mut sim = simulator();
+        let small = sim.simulate(&coin!("uluna", 1000000), "uusd").unwrap();
+        assert_eq!(small.spread, sim.params.min_stability_spread);
+        assert_eq!(small.return_coin, coin!("uusd", 98000000));
+
+        // A swap worth 10% of the base pool pays a larger constant-product
+        // spread: 1 - 1 / 1.1.
+        let large = sim.swap(&coin!("uluna", 1250000000u64), "uusd").unwrap();
+        assert_eq!(large.spread, dec!("0.090909090909090909"));
+        assert!(sim.terra_pool_delta < Dec::zero());
+
+        let delta = sim.terra_pool_delta;
+        sim.replenish_pool();
+        assert!(sim.terra_pool_delta > delta);
+
+        assert!(sim.simulate(&coin!("uluna", 1), "uluna").is_err());
+        assert!(sim.simulate(&coin!("uluna", 1), "ueur").is_err());
+    }
+
+    #[test]
+    fn it_fails_on_overflowing_offers() {
+        let sim = simulator();
+        let offer = Coin::new("uusd", Uint128::new(10u128.pow(20)));
+        assert!(sim.simulate(&offer, "ukrw").is_err());
+        let offer = Coin::new("uusd", Uint128::new(u128::MAX));
+        assert!(sim.simulate(&offer, "uluna").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Dec;
+
+/// Parameters of the mint module.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct MintParams {
+    pub mint_denom: String,
+    /// Maximum annual change of the inflation rate.
+    pub inflation_rate_change: Dec,
+    pub inflation_max: Dec,
+    pub inflation_min: Dec,
+    /// Bonded ratio the inflation rate steers towards.
+    pub goal_bonded: Dec,
+    #[serde(with = "crate::internal::serde_str")]
+    pub blocks_per_year: u64,
+}
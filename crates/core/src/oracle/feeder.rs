@@ -0,0 +1,146 @@
+use crate::oracle::{generate_salt, MsgAggregateExchangeRatePrevote, MsgAggregateExchangeRateVote};
+use crate::{AccAddress, DecCoins, ValAddress};
+
+/// Oracle vote period on Columbus-5, in blocks.
+pub const DEFAULT_VOTE_PERIOD: u64 = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeederState {
+    /// Nothing committed; the next round only prevotes.
+    Idle,
+    /// A prevote was submitted in `period`; `vote` reveals it in `period + 1`.
+    Prevoted {
+        period: u64,
+        vote: MsgAggregateExchangeRateVote,
+    },
+}
+
+/// Messages to broadcast for one vote period, in this order: the vote
+/// revealing last period's prevote (if any), then the new prevote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeederRound {
+    pub period: u64,
+    pub vote: Option<MsgAggregateExchangeRateVote>,
+    pub prevote: MsgAggregateExchangeRatePrevote,
+}
+
+/// Drives the oracle commit-reveal cycle: every vote period the feeder
+/// reveals the rates committed to in the previous period and commits to new
+/// ones. A prevote older than one period can no longer be revealed and is
+/// dropped.
+#[derive(Debug, Clone)]
+pub struct Feeder {
+    feeder: AccAddress,
+    validator: ValAddress,
+    vote_period: u64,
+    state: FeederState,
+}
+
+impl Feeder {
+    pub fn new(
+        feeder: impl Into<AccAddress>,
+        validator: impl Into<ValAddress>,
+        vote_period: u64,
+    ) -> Self {
+        Self {
+            feeder: feeder.into(),
+            validator: validator.into(),
+            vote_period: vote_period.max(1),
+            state: FeederState::Idle,
+        }
+    }
+
+    pub fn state(&self) -> &FeederState {
+        &self.state
+    }
+
+    pub fn period(&self, height: u64) -> u64 {
+        height / self.vote_period
+    }
+
+    /// Messages for the block at `height`, or `None` if this period was
+    /// already handled.
+    pub fn round(
+        &mut self,
+        height: u64,
+        exchange_rates: impl Into<DecCoins>,
+    ) -> Option<FeederRound> {
+        self.round_with_salt(height, exchange_rates, generate_salt())
+    }
+
+    pub fn round_with_salt(
+        &mut self,
+        height: u64,
+        exchange_rates: impl Into<DecCoins>,
+        salt: impl Into<String>,
+    ) -> Option<FeederRound> {
+        let period = self.period(height);
+        let vote = match &self.state {
+            FeederState::Prevoted { period: p, .. } if *p >= period => return None,
+            FeederState::Prevoted { period: p, vote } if *p + 1 == period => Some(vote.clone()),
+            _ => None,
+        };
+        let next = MsgAggregateExchangeRateVote::new(
+            salt,
+            exchange_rates,
+            self.feeder.clone(),
+            self.validator.clone(),
+        );
+        let prevote = next.prevote();
+        self.state = FeederState::Prevoted { period, vote: next };
+        Some(FeederRound {
+            period,
+            vote,
+            prevote,
+        })
+    }
+
+    /// Forgets the pending prevote, e.g. after its broadcast failed.
+    pub fn reset(&mut self) {
+        self.state = FeederState::Idle;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEEDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+    const VALIDATOR: &str = "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk";
+
+    fn rates(s: &str) -> DecCoins {
+        DecCoins::parse(s).unwrap()
+    }
+
+    #[test]
+    fn it_reveals_previous_prevote() {
+        let mut feeder = Feeder::new(FEEDER, VALIDATOR, DEFAULT_VOTE_PERIOD);
+
+        let first = feeder
+            .round_with_salt(10, rates("8.8uusd"), "aaaa")
+            .unwrap();
+        assert_eq!(first.period, 2);
+        assert!(first.vote.is_none());
+        assert!(feeder
+            .round_with_salt(14, rates("8.9uusd"), "bbbb")
+            .is_none());
+
+        let second = feeder
+            .round_with_salt(15, rates("9.0uusd"), "cccc")
+            .unwrap();
+        let vote = second.vote.unwrap();
+        assert_eq!(vote.salt, "aaaa");
+        assert_eq!(vote.prevote(), first.prevote);
+        assert_ne!(second.prevote, first.prevote);
+
+        // Period 4 was skipped, so the prevote from period 3 is stale.
+        let third = feeder
+            .round_with_salt(25, rates("9.1uusd"), "dddd")
+            .unwrap();
+        assert!(third.vote.is_none());
+
+        feeder.reset();
+        assert_eq!(feeder.state(), &FeederState::Idle);
+        assert!(feeder.round(30, rates("9.2uusd")).unwrap().vote.is_none());
+    }
+}
@@ -0,0 +1,66 @@
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::{DecCoins, ValAddress};
+
+/// Length of an aggregate vote hash in bytes, as truncated by the chain.
+pub const AGGREGATE_VOTE_HASH_LEN: usize = 20;
+
+/// Maximum salt length accepted by the chain.
+pub const MAX_SALT_LEN: usize = 4;
+
+/// Hex-encoded `SHA256("{salt}:{exchange_rates}:{validator}")`, truncated to
+/// 20 bytes. `exchange_rates` is hashed in its `Display` form, which is also
+/// how it is sent in `MsgAggregateExchangeRateVote`.
+pub fn aggregate_vote_hash(
+    salt: &str,
+    exchange_rates: &DecCoins,
+    validator: &ValAddress,
+) -> String {
+    let payload = format!("{}:{}:{}", salt, exchange_rates, validator);
+    let digest = Sha256::digest(payload.as_bytes());
+    hex::encode(&digest[..AGGREGATE_VOTE_HASH_LEN])
+}
+
+/// A random hex salt of the maximum allowed length.
+pub fn generate_salt() -> String {
+    let bytes: [u8; MAX_SALT_LEN / 2] = rand::thread_rng().gen();
+    hex::encode(bytes)
+}
+
+pub fn validate_salt(salt: &str) -> Result<(), String> {
+    if salt.is_empty() || salt.len() > MAX_SALT_LEN {
+        return Err(format!(
+            "salt length must be between 1 and {}: {}",
+            MAX_SALT_LEN, salt
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_hashes_aggregate_vote() {
+        let validator = ValAddress::from("terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk");
+        let rates = DecCoins::parse("8.8uusd,1000ukrw").unwrap();
+        assert_eq!(
+            rates.to_string(),
+            "1000.000000000000000000ukrw,8.800000000000000000uusd"
+        );
+        let hash = aggregate_vote_hash("1234", &rates, &validator);
+        assert_eq!(hash.len(), AGGREGATE_VOTE_HASH_LEN * 2);
+        assert_eq!(hash, "9dd304f82970232e1db98569699c0b39cdab996d");
+    }
+
+    #[test]
+    fn it_generates_salt() {
+        let salt = generate_salt();
+        assert_eq!(salt.len(), MAX_SALT_LEN);
+        assert!(validate_salt(&salt).is_ok());
+        assert!(validate_salt("").is_err());
+        assert!(validate_salt("12345").is_err());
+    }
+}
@@ -1,2 +1,13 @@
+pub mod feeder;
+pub use feeder::*;
+
+pub mod hash;
+pub use hash::*;
+
 pub mod msgs;
 pub use msgs::*;
+
+pub mod params;
+pub use params::*;
+
+pub mod proto;
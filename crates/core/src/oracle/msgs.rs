@@ -1,17 +1,228 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::oracle::{aggregate_vote_hash, generate_salt, proto, validate_salt};
+use crate::{AccAddress, Dec, DecCoins, ProtoMsg, ValAddress};
+
+/// Commits to the exchange rates revealed in the next vote period.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("oracle/MsgAggregateExchangeRatePrevote")]
+#[msgtype_pb("/terra.oracle.v1beta1.MsgAggregateExchangeRatePrevote")]
 pub struct MsgAggregateExchangeRatePrevote {
     pub hash: String,
     pub feeder: AccAddress,
     pub validator: ValAddress,
 }
 
+impl MsgAggregateExchangeRatePrevote {
+    pub fn new(
+        salt: &str,
+        exchange_rates: &DecCoins,
+        feeder: impl Into<AccAddress>,
+        validator: impl Into<ValAddress>,
+    ) -> Self {
+        let validator = validator.into();
+        Self {
+            hash: aggregate_vote_hash(salt, exchange_rates, &validator),
+            feeder: feeder.into(),
+            validator,
+        }
+    }
+}
+
+impl ProtoMsg for MsgAggregateExchangeRatePrevote {
+    type Proto = proto::MsgAggregateExchangeRatePrevote;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgAggregateExchangeRatePrevote {
+            hash: self.hash.clone(),
+            feeder: self.feeder.to_string(),
+            validator: self.validator.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            hash: proto.hash,
+            feeder: proto.feeder.into(),
+            validator: proto.validator.into(),
+        })
+    }
+}
+
+/// Reveals the exchange rates committed to by the previous period's prevote.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("oracle/MsgAggregateExchangeRateVote")]
+#[msgtype_pb("/terra.oracle.v1beta1.MsgAggregateExchangeRateVote")]
 pub struct MsgAggregateExchangeRateVote {
-    pub exchange_rates: Coins,
     pub salt: String,
+    #[serde(with = "crate::internal::serde_str")]
+    pub exchange_rates: DecCoins,
     pub feeder: AccAddress,
     pub validator: ValAddress,
 }
 
+impl MsgAggregateExchangeRateVote {
+    pub fn new(
+        salt: impl Into<String>,
+        exchange_rates: impl Into<DecCoins>,
+        feeder: impl Into<AccAddress>,
+        validator: impl Into<ValAddress>,
+    ) -> Self {
+        Self {
+            salt: salt.into(),
+            exchange_rates: exchange_rates.into(),
+            feeder: feeder.into(),
+            validator: validator.into(),
+        }
+    }
+
+    /// A vote with a freshly generated salt.
+    pub fn with_random_salt(
+        exchange_rates: impl Into<DecCoins>,
+        feeder: impl Into<AccAddress>,
+        validator: impl Into<ValAddress>,
+    ) -> Self {
+        Self::new(generate_salt(), exchange_rates, feeder, validator)
+    }
+
+    pub fn hash(&self) -> String {
+        aggregate_vote_hash(&self.salt, &self.exchange_rates, &self.validator)
+    }
+
+    /// The prevote committing to this vote.
+    pub fn prevote(&self) -> MsgAggregateExchangeRatePrevote {
+        MsgAggregateExchangeRatePrevote {
+            hash: self.hash(),
+            feeder: self.feeder.clone(),
+            validator: self.validator.clone(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        validate_salt(&self.salt)?;
+        if self.exchange_rates.is_empty() {
+            return Err("no exchange rates".into());
+        }
+        if let Some(rate) = self.exchange_rates.iter().find(|r| r.amount < Dec::zero()) {
+            return Err(format!("negative exchange rate for {}", rate.denom));
+        }
+        Ok(())
+    }
+}
+
+impl ProtoMsg for MsgAggregateExchangeRateVote {
+    type Proto = proto::MsgAggregateExchangeRateVote;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgAggregateExchangeRateVote {
+            salt: self.salt.clone(),
+            exchange_rates: self.exchange_rates.to_string(),
+            feeder: self.feeder.to_string(),
+            validator: self.validator.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            salt: proto.salt,
+            exchange_rates: DecCoins::parse(&proto.exchange_rates)?,
+            feeder: proto.feeder.into(),
+            validator: proto.validator.into(),
+        })
+    }
+}
+
+/// Delegates oracle voting rights of `operator` to the `delegate` account.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("oracle/MsgDelegateFeedConsent")]
+#[msgtype_pb("/terra.oracle.v1beta1.MsgDelegateFeedConsent")]
 pub struct MsgDelegateFeedConsent {
     pub operator: ValAddress,
     pub delegate: AccAddress,
 }
+
+impl MsgDelegateFeedConsent {
+    pub fn new(operator: impl Into<ValAddress>, delegate: impl Into<AccAddress>) -> Self {
+        Self {
+            operator: operator.into(),
+            delegate: delegate.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgDelegateFeedConsent {
+    type Proto = proto::MsgDelegateFeedConsent;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgDelegateFeedConsent {
+            operator: self.operator.to_string(),
+            delegate: self.delegate.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            operator: proto.operator.into(),
+            delegate: proto.delegate.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+    use serde_json::json;
+
+    const FEEDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+    const VALIDATOR: &str = "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk";
+
+    #[test]
+    fn it_matches_prevote_and_vote() {
+        let vote = MsgAggregateExchangeRateVote::new(
+            "1234",
+            DecCoins::parse("8.8uusd,1000ukrw").unwrap(),
+            FEEDER,
+            VALIDATOR,
+        );
+        assert!(vote.validate().is_ok());
+        let prevote = MsgAggregateExchangeRatePrevote::new(
+            "1234",
+            &DecCoins::parse("1000ukrw,8.8uusd").unwrap(),
+            FEEDER,
+            VALIDATOR,
+        );
+        assert_eq!(vote.prevote(), prevote);
+        assert_eq!(prevote.hash, "9dd304f82970232e1db98569699c0b39cdab996d");
+
+        let json = vote.to_json().unwrap();
+        assert_eq!(
+            json["value"]["exchange_rates"],
+            "1000.000000000000000000ukrw,8.800000000000000000uusd"
+        );
+        assert_eq!(
+            MsgAggregateExchangeRateVote::from_amino(&json).unwrap(),
+            vote
+        );
+        assert_eq!(
+            MsgAggregateExchangeRateVote::from_any(&vote.to_any().unwrap()).unwrap(),
+            vote
+        );
+    }
+
+    #[test]
+    fn it_encodes_feed_consent() {
+        let msg = MsgDelegateFeedConsent::new(VALIDATOR, FEEDER);
+        assert_eq!(
+            msg.to_json().unwrap(),
+            json!({
+                "type": "oracle/MsgDelegateFeedConsent",
+                "value": { "operator": VALIDATOR, "delegate": FEEDER }
+            })
+        );
+        let any = msg.to_any().unwrap();
+        assert_eq!(any.type_url, "/terra.oracle.v1beta1.MsgDelegateFeedConsent");
+        assert_eq!(MsgDelegateFeedConsent::from_any(&any).unwrap(), msg);
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Dec;
+
+/// A denom the oracle collects exchange rates for.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct OracleDenom {
+    pub name: String,
+    pub tobin_tax: Dec,
+}
+
+/// Parameters of the oracle module.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct OracleParams {
+    /// Number of blocks per voting round.
+    #[serde(with = "crate::internal::serde_str")]
+    pub vote_period: u64,
+    /// Share of voting power needed for an exchange rate to be accepted.
+    pub vote_threshold: Dec,
+    /// Tolerated distance from the weighted median for a vote to be rewarded.
+    pub reward_band: Dec,
+    #[serde(with = "crate::internal::serde_str")]
+    pub reward_distribution_window: u64,
+    pub whitelist: Vec<OracleDenom>,
+    pub slash_fraction: Dec,
+    #[serde(with = "crate::internal::serde_str")]
+    pub slash_window: u64,
+    pub min_valid_per_window: Dec,
+}
+
+impl OracleParams {
+    /// Whether `denom` is voted on.
+    pub fn is_whitelisted(&self, denom: &str) -> bool {
+        self.whitelist.iter().any(|d| d.name == denom)
+    }
+}
@@ -0,0 +1,33 @@
+//! Protobuf counterparts of the `terra.oracle.v1beta1` messages.
+
+use prost::Message;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgAggregateExchangeRatePrevote {
+    #[prost(string, tag = "1")]
+    pub hash: String,
+    #[prost(string, tag = "2")]
+    pub feeder: String,
+    #[prost(string, tag = "3")]
+    pub validator: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgAggregateExchangeRateVote {
+    #[prost(string, tag = "1")]
+    pub salt: String,
+    #[prost(string, tag = "2")]
+    pub exchange_rates: String,
+    #[prost(string, tag = "3")]
+    pub feeder: String,
+    #[prost(string, tag = "4")]
+    pub validator: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgDelegateFeedConsent {
+    #[prost(string, tag = "1")]
+    pub operator: String,
+    #[prost(string, tag = "2")]
+    pub delegate: String,
+}
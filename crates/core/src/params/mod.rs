@@ -1,2 +1,7 @@
-pub mod msgs;
-pub use msgs::*;
+pub mod proposals;
+pub use proposals::*;
+
+pub mod subspaces;
+pub use subspaces::*;
+
+pub mod proto;
@@ -0,0 +1,167 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::params::{proto, validate_param};
+use crate::ProtoMsg;
+
+/// A single parameter update. `value` is the new value as a JSON string,
+/// e.g. `"\"100\""` for a string-encoded integer.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ParamChange {
+    pub subspace: String,
+    pub key: String,
+    pub value: String,
+}
+
+impl ParamChange {
+    pub fn new(
+        subspace: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            subspace: subspace.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Checks the key exists in the subspace and the value decodes as its
+    /// typed parameter.
+    pub fn validate(&self) -> Result<(), String> {
+        validate_param(&self.subspace, &self.key, &self.value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("params/ParameterChangeProposal")]
+#[msgtype_pb("/cosmos.params.v1beta1.ParameterChangeProposal")]
+pub struct ParameterChangeProposal {
+    pub title: String,
+    pub description: String,
+    pub changes: Vec<ParamChange>,
+}
+
+impl ParameterChangeProposal {
+    pub fn new(title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            changes: vec![],
+        }
+    }
+
+    /// Adds a change setting `subspace/key` to `value`, failing if the value
+    /// does not match the parameter's type. Durations are given as
+    /// nanoseconds in a string and 64-bit integers as strings, as the
+    /// chain's amino JSON encodes them.
+    pub fn change(
+        mut self,
+        subspace: impl Into<String>,
+        key: impl Into<String>,
+        value: &impl Serialize,
+    ) -> Result<Self, String> {
+        let value = serde_json::to_string(value).map_err(|e| e.to_string())?;
+        let change = ParamChange::new(subspace, key, value);
+        change.validate()?;
+        self.changes.push(change);
+        Ok(self)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.changes.is_empty() {
+            return Err("proposal has no changes".into());
+        }
+        let mut keys = BTreeSet::new();
+        for change in &self.changes {
+            change.validate()?;
+            if !keys.insert((&change.subspace, &change.key)) {
+                return Err(format!(
+                    "duplicate change of {}/{}",
+                    change.subspace, change.key
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ProtoMsg for ParameterChangeProposal {
+    type Proto = proto::ParameterChangeProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::ParameterChangeProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            changes: self
+                .changes
+                .iter()
+                .map(|c| proto::ParamChange {
+                    subspace: c.subspace.clone(),
+                    key: c.key.clone(),
+                    value: c.value.clone(),
+                })
+                .collect(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            changes: proto
+                .changes
+                .into_iter()
+                .map(|c| ParamChange::new(c.subspace, c.key, c.value))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coins, Dec};
+
+    #[test]
+    fn it_validates_changes() {
+        let proposal = ParameterChangeProposal::new("Raise min deposit", "")
+            .change("staking", "MaxValidators", &130)
+            .unwrap()
+            .change("market", "PoolRecoveryPeriod", &"36")
+            .unwrap()
+            .change(
+                "gov",
+                "depositparams",
+                &serde_json::json!({
+                    "min_deposit": Coins::from("5000000000uluna"),
+                    "max_deposit_period": "1209600000000000"
+                }),
+            )
+            .unwrap();
+        proposal.validate().unwrap();
+        assert_eq!(proposal.changes[1].value, "\"36\"");
+
+        let invalid = ParameterChangeProposal::new("", "");
+        assert!(invalid
+            .clone()
+            .change("staking", "MaxValidators", &"130")
+            .is_err());
+        assert!(invalid
+            .clone()
+            .change("market", "PoolRecoveryPeriod", &36)
+            .is_err());
+        assert!(invalid.clone().change("market", "Nope", &"1").is_err());
+        assert!(invalid
+            .clone()
+            .change("treasury", "MiningIncrement", &Dec::one())
+            .is_ok());
+        assert!(invalid.validate().is_err());
+
+        let mut duplicate = proposal.clone();
+        duplicate.changes.push(proposal.changes[0].clone());
+        assert!(duplicate.validate().is_err());
+    }
+}
@@ -0,0 +1,23 @@
+//! Protobuf counterparts of the `cosmos.params.v1beta1` types.
+
+use prost::Message;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ParamChange {
+    #[prost(string, tag = "1")]
+    pub subspace: String,
+    #[prost(string, tag = "2")]
+    pub key: String,
+    #[prost(string, tag = "3")]
+    pub value: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ParameterChangeProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(message, repeated, tag = "3")]
+    pub changes: Vec<ParamChange>,
+}
@@ -0,0 +1,81 @@
+//! The parameter keys of each module's subspace and the JSON their values
+//! must decode as in a `ParamChange`.
+
+use serde::de::DeserializeOwned;
+
+use crate::gov::{DepositParams, TallyParams, VotingParams};
+use crate::oracle::OracleDenom;
+use crate::treasury::PolicyConstraints;
+use crate::Dec;
+
+fn check<T: DeserializeOwned>(value: &str) -> Result<(), String> {
+    serde_json::from_str::<T>(value)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+// Amino JSON encodes 64-bit integers and durations as strings.
+fn check_u64(value: &str) -> Result<(), String> {
+    let value: String = serde_json::from_str(value).map_err(|e| e.to_string())?;
+    value.parse::<u64>().map(|_| ()).map_err(|e| e.to_string())
+}
+
+fn check_duration(value: &str) -> Result<(), String> {
+    let mut deserializer = serde_json::Deserializer::from_str(value);
+    crate::internal::serde_duration::deserialize(&mut deserializer)
+        .and_then(|_| deserializer.end())
+        .map_err(|e| e.to_string())
+}
+
+/// Checks that `value` is valid JSON for `key` in `subspace`.
+pub fn validate_param(subspace: &str, key: &str, value: &str) -> Result<(), String> {
+    let result = match (subspace, key) {
+        ("treasury", "TaxPolicy" | "RewardPolicy") => check::<PolicyConstraints>(value),
+        (
+            "treasury",
+            "SeigniorageBurdenTarget"
+            | "MiningIncrement"
+            | "BurnTaxSplit"
+            | "MinInitialDepositRatio",
+        ) => check::<Dec>(value),
+        ("treasury", "WindowShort" | "WindowLong" | "WindowProbation") => check_u64(value),
+
+        ("oracle", "VotePeriod" | "RewardDistributionWindow" | "SlashWindow") => check_u64(value),
+        ("oracle", "VoteThreshold" | "RewardBand" | "SlashFraction" | "MinValidPerWindow") => {
+            check::<Dec>(value)
+        }
+        ("oracle", "Whitelist") => check::<Vec<OracleDenom>>(value),
+
+        ("market", "BasePool" | "MinStabilitySpread") => check::<Dec>(value),
+        ("market", "PoolRecoveryPeriod") => check_u64(value),
+
+        ("staking", "UnbondingTime") => check_duration(value),
+        ("staking", "MaxValidators" | "MaxEntries" | "HistoricalEntries") => check::<u32>(value),
+        ("staking", "BondDenom") => check::<String>(value),
+
+        ("slashing", "SignedBlocksWindow") => check_u64(value),
+        (
+            "slashing",
+            "MinSignedPerWindow" | "SlashFractionDoubleSign" | "SlashFractionDowntime",
+        ) => check::<Dec>(value),
+        ("slashing", "DowntimeJailDuration") => check_duration(value),
+
+        ("distribution", "communitytax" | "baseproposerreward" | "bonusproposerreward") => {
+            check::<Dec>(value)
+        }
+        ("distribution", "withdrawaddrenabled") => check::<bool>(value),
+
+        ("gov", "depositparams") => check::<DepositParams>(value),
+        ("gov", "votingparams") => check::<VotingParams>(value),
+        ("gov", "tallyparams") => check::<TallyParams>(value),
+
+        ("mint", "MintDenom") => check::<String>(value),
+        ("mint", "InflationRateChange" | "InflationMax" | "InflationMin" | "GoalBonded") => {
+            check::<Dec>(value)
+        }
+        ("mint", "BlocksPerYear") => check_u64(value),
+
+        _ => return Err(format!("unknown parameter {}/{}", subspace, key)),
+    };
+    result.map_err(|e| format!("invalid value for {}/{}: {}", subspace, key, e))
+}
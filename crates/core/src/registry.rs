@@ -0,0 +1,256 @@
+//! Lookup of message types by protobuf type URL and Amino type name, used to
+//! turn packed `Any` messages (e.g. inside `MsgExec` or a decoded tx) back
+//! into JSON and vice versa.
+//!
+//! Every message type in this crate is registered up front; messages from
+//! other crates can be added with [`register`].
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+use serde_json::Value;
+
+use crate::{Any, Msg};
+
+#[derive(Clone, Copy)]
+struct Entry {
+    to_data: fn(&Any) -> Result<Value, String>,
+    to_amino: fn(&Any) -> Result<Value, String>,
+    from_data: fn(&Value) -> Result<Any, String>,
+    from_amino: fn(&Value) -> Result<Any, String>,
+}
+
+impl Entry {
+    fn of<M: Msg>() -> Self {
+        Entry {
+            to_data: |any| M::from_any(any)?.to_data(),
+            to_amino: |any| M::from_any(any)?.to_json(),
+            from_data: |data| M::from_data(data)?.to_any(),
+            from_amino: |json| M::from_amino(json)?.to_any(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    by_type_url: HashMap<&'static str, Entry>,
+    by_amino_type: HashMap<&'static str, &'static str>,
+}
+
+impl Registry {
+    fn add<M: Msg>(&mut self) {
+        self.by_type_url.insert(M::TYPE_URL, Entry::of::<M>());
+        // Terra classic and wasmd share Amino names; the first one wins.
+        self.by_amino_type
+            .entry(M::AMINO_TYPE)
+            .or_insert(M::TYPE_URL);
+    }
+
+    fn builtin() -> Self {
+        use crate::{
+            authz, bank, distribution, feegrant, gov, ibc, ibc_transfer, market, oracle, slashing,
+            staking, upgrade, wasm,
+        };
+
+        let mut registry = Registry::default();
+        registry.add::<authz::MsgGrant>();
+        registry.add::<authz::MsgRevoke>();
+        registry.add::<authz::MsgExec>();
+        registry.add::<bank::MsgSend>();
+        registry.add::<bank::MsgMultiSend>();
+        registry.add::<distribution::MsgWithdrawValidatorCommission>();
+        registry.add::<distribution::MsgWithdrawDelegatorReward>();
+        registry.add::<distribution::MsgSetWithdrawAddress>();
+        registry.add::<distribution::MsgFundCommunityPool>();
+        registry.add::<feegrant::MsgGrantAllowance>();
+        registry.add::<feegrant::MsgRevokeAllowance>();
+        registry.add::<gov::MsgSubmitProposal>();
+        registry.add::<gov::MsgDeposit>();
+        registry.add::<gov::MsgVote>();
+        registry.add::<gov::MsgVoteWeighted>();
+        registry.add::<ibc::MsgCreateClient>();
+        registry.add::<ibc::MsgUpdateClient>();
+        registry.add::<ibc::MsgSubmitMisbehaviour>();
+        registry.add::<ibc::MsgConnectionOpenInit>();
+        registry.add::<ibc::MsgConnectionOpenTry>();
+        registry.add::<ibc::MsgConnectionOpenAck>();
+        registry.add::<ibc::MsgConnectionOpenConfirm>();
+        registry.add::<ibc::MsgChannelOpenInit>();
+        registry.add::<ibc::MsgChannelOpenTry>();
+        registry.add::<ibc::MsgChannelOpenAck>();
+        registry.add::<ibc::MsgChannelOpenConfirm>();
+        registry.add::<ibc::MsgChannelCloseInit>();
+        registry.add::<ibc::MsgChannelCloseConfirm>();
+        registry.add::<ibc::MsgRecvPacket>();
+        registry.add::<ibc::MsgTimeout>();
+        registry.add::<ibc::MsgAcknowledgement>();
+        registry.add::<ibc_transfer::MsgTransfer>();
+        registry.add::<market::MsgSwap>();
+        registry.add::<market::MsgSwapSend>();
+        registry.add::<oracle::MsgAggregateExchangeRatePrevote>();
+        registry.add::<oracle::MsgAggregateExchangeRateVote>();
+        registry.add::<oracle::MsgDelegateFeedConsent>();
+        registry.add::<slashing::MsgUnjail>();
+        registry.add::<staking::MsgDelegate>();
+        registry.add::<staking::MsgUndelegate>();
+        registry.add::<staking::MsgBeginRedelegate>();
+        registry.add::<staking::MsgEditValidator>();
+        registry.add::<staking::MsgCreateValidator>();
+        registry.add::<upgrade::MsgSoftwareUpgrade>();
+        registry.add::<upgrade::MsgCancelUpgrade>();
+        registry.add::<wasm::MsgStoreCode>();
+        registry.add::<wasm::MsgMigrateCode>();
+        registry.add::<wasm::MsgInstantiateContract>();
+        registry.add::<wasm::MsgExecuteContract>();
+        registry.add::<wasm::MsgMigrateContract>();
+        registry.add::<wasm::MsgUpdateContractAdmin>();
+        registry.add::<wasm::MsgClearContractAdmin>();
+        registry.add::<wasm::wasmd::MsgStoreCode>();
+        registry.add::<wasm::wasmd::MsgInstantiateContract>();
+        registry.add::<wasm::wasmd::MsgExecuteContract>();
+        registry.add::<wasm::wasmd::MsgMigrateContract>();
+        registry.add::<wasm::wasmd::MsgUpdateAdmin>();
+        registry.add::<wasm::wasmd::MsgClearAdmin>();
+        registry
+    }
+}
+
+lazy_static! {
+    static ref REGISTRY: RwLock<Registry> = RwLock::new(Registry::builtin());
+}
+
+/// Registers `M` so that packed instances of it can be converted to and from
+/// JSON. Replaces any previous registration for the same type URL.
+pub fn register<M: Msg>() {
+    REGISTRY.write().unwrap().add::<M>();
+}
+
+pub fn is_registered(type_url: &str) -> bool {
+    REGISTRY.read().unwrap().by_type_url.contains_key(type_url)
+}
+
+// Entries are copied out so that nested messages (`MsgExec`) can look up
+// the registry again without holding the lock.
+fn entry(type_url: &str) -> Result<Entry, String> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .by_type_url
+        .get(type_url)
+        .copied()
+        .ok_or_else(|| format!("unregistered message type: {}", type_url))
+}
+
+fn entry_by_amino_type(amino_type: &str) -> Result<Entry, String> {
+    let registry = REGISTRY.read().unwrap();
+    registry
+        .by_amino_type
+        .get(amino_type)
+        .and_then(|type_url| registry.by_type_url.get(type_url))
+        .copied()
+        .ok_or_else(|| format!("unregistered message type: {}", amino_type))
+}
+
+/// Protobuf JSON (`@type`) form of a packed message.
+pub fn any_to_data(any: &Any) -> Result<Value, String> {
+    (entry(&any.type_url)?.to_data)(any)
+}
+
+/// Amino JSON (`type`/`value`) form of a packed message.
+pub fn any_to_amino(any: &Any) -> Result<Value, String> {
+    (entry(&any.type_url)?.to_amino)(any)
+}
+
+pub fn any_from_data(data: &Value) -> Result<Any, String> {
+    let type_url = data
+        .get("@type")
+        .and_then(Value::as_str)
+        .ok_or("missing @type")?;
+    (entry(type_url)?.from_data)(data)
+}
+
+pub fn any_from_amino(json: &Value) -> Result<Any, String> {
+    let amino_type = json
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or("missing type")?;
+    (entry_by_amino_type(amino_type)?.from_amino)(json)
+}
+
+/// Packs a message given in either JSON form.
+pub fn any_from_json(json: &Value) -> Result<Any, String> {
+    if json.get("@type").is_some() {
+        any_from_data(json)
+    } else {
+        any_from_amino(json)
+    }
+}
+
+/// Serde helpers for `Vec<Any>` message lists: serialized as protobuf JSON
+/// within `Msg::to_data` and as Amino JSON otherwise, deserialized from
+/// either JSON form.
+pub(crate) mod serde_msgs {
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+
+    use crate::internal::json_form::is_proto_json;
+    use crate::Any;
+
+    pub fn serialize<S>(msgs: &[Any], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let to_json = if is_proto_json() {
+            super::any_to_data
+        } else {
+            super::any_to_amino
+        };
+        msgs.iter()
+            .map(to_json)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Any>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Value>::deserialize(deserializer)?
+            .iter()
+            .map(super::any_from_json)
+            .collect::<Result<_, _>>()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bank::MsgSend;
+    use crate::{Coins, JsonSer};
+
+    #[test]
+    fn it_converts_packed_messages() {
+        let msg = MsgSend::new(
+            "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+            "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+            Coins::from("1000uluna"),
+        );
+        let any = msg.to_any().unwrap();
+        assert!(is_registered(&any.type_url));
+        assert_eq!(any_to_data(&any).unwrap(), msg.to_data().unwrap());
+        assert_eq!(any_to_amino(&any).unwrap(), msg.to_json().unwrap());
+        assert_eq!(any_from_json(&msg.to_data().unwrap()).unwrap(), any);
+        assert_eq!(any_from_json(&msg.to_json().unwrap()).unwrap(), any);
+
+        let unknown = Any {
+            type_url: "/unknown.Msg".into(),
+            value: vec![],
+        };
+        assert!(any_to_data(&unknown).is_err());
+    }
+}
@@ -259,7 +259,7 @@ impl<T: Into<Uint128>> Mul<T> for Coins {
         let rhs = rhs.into();
         let mut new_coins = self.clone();
         for coin in self.iter() {
-            new_coins.set(coin.denom.clone(), coin.clone().mul(rhs.clone()));
+            new_coins.set(coin.denom.clone(), coin.clone().mul(rhs));
         }
         new_coins
     }
@@ -281,7 +281,7 @@ impl<T: Into<Uint128>> Div<T> for Coins {
         let rhs = rhs.into();
         let mut new_coins = self.clone();
         for coin in self.iter() {
-            new_coins.set(coin.denom.clone(), coin.clone().div(rhs.clone()));
+            new_coins.set(coin.denom.clone(), coin.clone().div(rhs));
         }
         new_coins
     }
@@ -303,7 +303,7 @@ impl<T: Into<Uint128>> Rem<T> for Coins {
         let rhs = rhs.into();
         let mut new_coins = self.clone();
         for coin in self.iter() {
-            new_coins.set(coin.denom.clone(), coin.clone().rem(rhs.clone()));
+            new_coins.set(coin.denom.clone(), coin.clone().rem(rhs));
         }
         new_coins
     }
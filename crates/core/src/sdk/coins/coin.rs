@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::sdk::numeric::{Dec, Uint128};
+use crate::sdk::numeric::Uint128;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -24,7 +24,7 @@ impl Coin {
 
     pub fn parse(coin_str: &str) -> Result<Self, String> {
         lazy_static! {
-            static ref RE: Regex = Regex::new(r"^([0-9]+)([a-zA-Z]+)$").unwrap();
+            static ref RE: Regex = Regex::new(r"^([0-9]+)([a-zA-Z][a-zA-Z0-9/:._-]*)$").unwrap();
         }
         let caps = RE
             .captures(coin_str)
@@ -122,6 +122,7 @@ mod tests {
     fn macro_works() {
         let a = coin!("uluna", 1);
         let b = Coin::new("uluna", 1u128);
+        assert_eq!(a, b);
     }
 
     #[test]
@@ -2,11 +2,13 @@ use crate::coin::*;
 use serde::ser::SerializeSeq;
 use serde::{Deserialize, Serialize};
 use std::cmp::{Eq, PartialEq};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
+/// A set of coins keyed by denom. Iteration and serialization are always in
+/// denom order, as required for signing.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Coins(HashMap<String, Coin>);
+pub struct Coins(BTreeMap<String, Coin>);
 
 impl Serialize for Coins {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -52,7 +54,7 @@ impl<'de> Deserialize<'de> for Coins {
 
 impl Coins {
     pub fn new() -> Self {
-        Coins(HashMap::new())
+        Coins(BTreeMap::new())
     }
 
     pub fn insert_coin(&mut self, coin: Coin) -> &mut Self {
@@ -85,11 +87,11 @@ impl Coins {
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &Coin> {
-        self.0.iter().map(|(_, v)| v).into_iter()
+        self.0.values()
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Coin> {
-        self.0.iter_mut().map(|(_, v)| v).into_iter()
+        self.0.values_mut()
     }
 
     pub fn has_denom(&self, denom: &str) -> bool {
@@ -106,6 +108,33 @@ impl Coins {
         coins
     }
 
+    /// `self + other`, or `None` if any denom would overflow.
+    pub fn checked_add(&self, other: &Coins) -> Option<Coins> {
+        let mut coins = self.clone();
+        for coin in other.iter() {
+            let have = self.get(&coin.denom).map(|c| c.amount.u128()).unwrap_or(0);
+            let sum = have.checked_add(coin.amount.u128())?;
+            coins.set(&coin.denom, Coin::new(&coin.denom, sum));
+        }
+        Some(coins)
+    }
+
+    /// `self - other`, or `None` if any denom would go negative. Denoms
+    /// reduced to zero are dropped.
+    pub fn checked_sub(&self, other: &Coins) -> Option<Coins> {
+        let mut coins = self.clone();
+        for coin in other.iter() {
+            let have = self.get(&coin.denom).map(|c| c.amount.u128()).unwrap_or(0);
+            let left = have.checked_sub(coin.amount.u128())?;
+            if left == 0 {
+                coins.remove_denom(&coin.denom);
+            } else {
+                coins.set(&coin.denom, Coin::new(&coin.denom, left));
+            }
+        }
+        Some(coins)
+    }
+
     pub fn parse(s: &str) -> Result<Self, String> {
         let mut coins = Coins::new();
         for coin in s.split(',') {
@@ -121,11 +150,7 @@ impl IntoIterator for Coins {
     type IntoIter = std::vec::IntoIter<Coin>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0
-            .into_iter()
-            .map(|(_, v)| v)
-            .collect::<Vec<_>>()
-            .into_iter()
+        self.0.into_values().collect::<Vec<_>>().into_iter()
     }
 }
 
@@ -159,7 +184,7 @@ where
     T: Into<Coin>,
 {
     fn from(coin_list: Vec<T>) -> Self {
-        Self::from_iter(coin_list.into_iter())
+        Self::from_iter(coin_list)
     }
 }
 
@@ -175,6 +200,13 @@ impl From<&str> for Coins {
     }
 }
 
+impl std::fmt::Display for Coins {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let coins = self.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", coins.join(","))
+    }
+}
+
 impl Default for Coins {
     fn default() -> Self {
         Coins::new()
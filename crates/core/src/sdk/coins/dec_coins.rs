@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::sdk::coins::{Coin, Coins};
+use crate::sdk::numeric::{Dec, Uint128, DEC_ONE};
+
+/// A coin with a decimal amount, as used for rewards, exchange rates and
+/// gas prices.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DecCoin {
+    pub denom: String,
+    pub amount: Dec,
+}
+
+impl DecCoin {
+    pub fn new(denom: impl Into<String>, amount: Dec) -> Self {
+        DecCoin {
+            denom: denom.into(),
+            amount,
+        }
+    }
+
+    pub fn parse(coin_str: &str) -> Result<Self, String> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new(r"^(-?[0-9]+(?:\.[0-9]+)?)([a-zA-Z][a-zA-Z0-9/]*)$").unwrap();
+        }
+        let caps = RE
+            .captures(coin_str.trim())
+            .ok_or_else(|| format!("Invalid dec coin string: {}", coin_str))?;
+        Ok(DecCoin {
+            denom: caps[2].to_string(),
+            amount: Dec::from_str(&caps[1])?,
+        })
+    }
+
+    /// Splits into the whole-number coin and the remaining fraction.
+    pub fn truncate(&self) -> (Coin, DecCoin) {
+        let raw = self.amount.raw().max(0);
+        let whole = raw / DEC_ONE;
+        let coin = Coin::new(self.denom.clone(), Uint128::from(whole as u128));
+        let change = DecCoin::new(self.denom.clone(), Dec::new(raw - whole * DEC_ONE));
+        (coin, change)
+    }
+}
+
+impl TryFrom<Coin> for DecCoin {
+    type Error = String;
+
+    fn try_from(coin: Coin) -> Result<Self, Self::Error> {
+        let amount = i128::try_from(coin.amount.u128())
+            .ok()
+            .and_then(|amount| amount.checked_mul(DEC_ONE))
+            .ok_or_else(|| format!("{} is too large for a dec coin", coin))?;
+        Ok(DecCoin::new(coin.denom, Dec::new(amount)))
+    }
+}
+
+impl FromStr for DecCoin {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DecCoin::parse(s)
+    }
+}
+
+impl std::fmt::Display for DecCoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+/// A set of `DecCoin`s keyed by denom, iterated and serialized in denom
+/// order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct DecCoins(BTreeMap<String, DecCoin>);
+
+impl DecCoins {
+    pub fn new() -> Self {
+        DecCoins(BTreeMap::new())
+    }
+
+    /// Adds `coin` to the set, summing with an existing amount of the same
+    /// denom.
+    pub fn add_coin(&mut self, coin: DecCoin) -> &mut Self {
+        match self.0.get_mut(&coin.denom) {
+            Some(existing) => existing.amount = existing.amount + coin.amount,
+            None => {
+                self.0.insert(coin.denom.clone(), coin);
+            }
+        }
+        self
+    }
+
+    pub fn get(&self, denom: &str) -> Option<&DecCoin> {
+        self.0.get(denom)
+    }
+
+    pub fn set(&mut self, coin: DecCoin) {
+        self.0.insert(coin.denom.clone(), coin);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &DecCoin> {
+        self.0.values()
+    }
+
+    pub fn has_denom(&self, denom: &str) -> bool {
+        self.0.contains_key(denom)
+    }
+
+    pub fn denoms(&self) -> impl Iterator<Item = &String> {
+        self.0.keys()
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut coins = DecCoins::new();
+        for coin in s.split(',').filter(|c| !c.trim().is_empty()) {
+            let coin = DecCoin::parse(coin)?;
+            if coins.has_denom(&coin.denom) {
+                return Err(format!("duplicate denom {} in {}", coin.denom, s));
+            }
+            coins.set(coin);
+        }
+        Ok(coins)
+    }
+
+    /// Splits into whole-number `Coins` (dropping zero amounts) and the
+    /// remaining fractional change.
+    pub fn truncate(&self) -> (Coins, DecCoins) {
+        let mut coins = Coins::new();
+        let mut change = DecCoins::new();
+        for coin in self.iter() {
+            let (whole, rest) = coin.truncate();
+            if whole.amount.u128() > 0 {
+                coins.insert_coin(whole);
+            }
+            if rest.amount != Dec::zero() {
+                change.set(rest);
+            }
+        }
+        (coins, change)
+    }
+}
+
+impl std::ops::Add for DecCoins {
+    type Output = Self;
+
+    fn add(mut self, rhs: DecCoins) -> Self {
+        for coin in rhs.0.into_values() {
+            self.add_coin(coin);
+        }
+        self
+    }
+}
+
+impl IntoIterator for DecCoins {
+    type Item = DecCoin;
+    type IntoIter = std::collections::btree_map::IntoValues<String, DecCoin>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_values()
+    }
+}
+
+impl<T> FromIterator<T> for DecCoins
+where
+    T: Into<DecCoin>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut coins = DecCoins::new();
+        iter.into_iter().for_each(|coin| {
+            coins.add_coin(coin.into());
+        });
+        coins
+    }
+}
+
+impl TryFrom<Coins> for DecCoins {
+    type Error = String;
+
+    fn try_from(coins: Coins) -> Result<Self, Self::Error> {
+        coins.into_iter().map(DecCoin::try_from).collect()
+    }
+}
+
+impl<T> From<Vec<T>> for DecCoins
+where
+    T: Into<DecCoin>,
+{
+    fn from(coin_list: Vec<T>) -> Self {
+        Self::from_iter(coin_list)
+    }
+}
+
+impl TryFrom<&str> for DecCoins {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl FromStr for DecCoins {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DecCoins::parse(s)
+    }
+}
+
+impl std::fmt::Display for DecCoins {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let coins = self.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", coins.join(","))
+    }
+}
+
+impl Serialize for DecCoins {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for DecCoins {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Vec::<DecCoin>::deserialize(deserializer)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_and_truncates() {
+        let coins = DecCoins::parse("1.5uusd,0.25uluna").unwrap();
+        assert_eq!(
+            coins.to_string(),
+            "0.250000000000000000uluna,1.500000000000000000uusd"
+        );
+        let (whole, change) = coins.truncate();
+        assert_eq!(whole, Coins::from("1uusd"));
+        assert_eq!(change.len(), 2);
+
+        let json = serde_json::to_value(&coins).unwrap();
+        assert_eq!(json[0]["amount"], "0.250000000000000000");
+        assert_eq!(serde_json::from_value::<DecCoins>(json).unwrap(), coins);
+
+        assert!(DecCoins::parse("1uusd,2uusd").is_err());
+        assert!(DecCoins::try_from("1.5").is_err());
+    }
+
+    #[test]
+    fn it_converts_coins() {
+        let coin = DecCoin::try_from(Coin::new("uluna", Uint128::from(5u64))).unwrap();
+        assert_eq!(coin.amount, Dec::from(5u8));
+        assert!(DecCoin::try_from(Coin::new("uluna", Uint128::new(u128::MAX))).is_err());
+        assert!(DecCoins::try_from(Coins::from("1uluna,2uusd")).is_ok());
+    }
+}
@@ -1,9 +1,12 @@
 pub mod arith;
-pub use arith::*;
 
 #[macro_use]
 pub mod coin;
 pub use coin::*;
 
+#[allow(clippy::module_inception)]
 pub mod coins;
 pub use coins::*;
+
+pub mod dec_coins;
+pub use dec_coins::*;
@@ -6,3 +6,8 @@ pub use strings::*;
 
 pub mod numeric;
 pub use numeric::*;
+
+pub mod proto;
+
+pub mod public_key;
+pub use public_key::*;
@@ -1,8 +1,11 @@
-use std::convert::{From, Into, TryFrom};
+use std::cmp::Ordering;
+use std::convert::{From, Into};
 use std::ops::{Add, Div, Mul, Rem, Sub};
 use std::str::FromStr;
 
-use num::{Integer, ToPrimitive};
+use num::bigint::BigInt;
+use num::{Integer, Signed, ToPrimitive};
+use serde::{Deserialize, Serialize};
 
 /// Type for representing decimal numbers as they are used in
 /// Cosmos SDK (18 digits of precision).
@@ -43,7 +46,7 @@ impl Dec {
     }
 
     pub fn integer(&self) -> i64 {
-        self.0.div_floor(&DEC_ONE).to_i64().unwrap()
+        Integer::div_floor(&self.0, &DEC_ONE).to_i64().unwrap()
     }
 
     pub fn fraction(&self) -> f64 {
@@ -51,7 +54,13 @@ impl Dec {
     }
 
     pub fn as_f64(&self) -> f64 {
-        self.clone().into()
+        (*self).into()
+    }
+
+    /// The underlying integer, scaled by 10^18. This is also how `Dec`
+    /// fields are encoded in protobuf messages.
+    pub fn raw(&self) -> i128 {
+        self.0
     }
 }
 
@@ -76,10 +85,10 @@ impl_from_primitive!(i8);
 impl_from_primitive!(i16);
 impl_from_primitive!(i32);
 
-impl Into<f64> for Dec {
-    fn into(self) -> f64 {
-        let integer = self.integer();
-        let fraction = self.fraction();
+impl From<Dec> for f64 {
+    fn from(value: Dec) -> f64 {
+        let integer = value.integer();
+        let fraction = value.fraction();
         let mut result;
         if integer < 0 {
             result = -fraction;
@@ -87,7 +96,7 @@ impl Into<f64> for Dec {
             result = fraction;
         }
         result += integer as f64;
-        return result;
+        result
     }
 }
 
@@ -105,18 +114,78 @@ impl<T: Into<Dec>> Sub<T> for Dec {
     }
 }
 
+/// Divides by 10^18, rounding half to even, like `chopPrecisionAndRound`
+/// in the Cosmos SDK.
+fn chop_precision_and_round(value: BigInt) -> BigInt {
+    if value.is_negative() {
+        return -chop_precision_and_round(-value);
+    }
+    let one = BigInt::from(DEC_ONE);
+    let (quo, rem) = value.div_rem(&one);
+    let half = one / 2;
+    match rem.cmp(&half) {
+        Ordering::Less => quo,
+        Ordering::Greater => quo + 1,
+        Ordering::Equal if quo.is_even() => quo,
+        Ordering::Equal => quo + 1,
+    }
+}
+
+/// Multiplies two raw 18-decimal values with `sdk.Dec` rounding. Used where
+/// intermediate values exceed the range of `Dec`.
+pub(crate) fn mul_raw(lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    chop_precision_and_round(lhs * rhs)
+}
+
+/// Divides two raw 18-decimal values with `sdk.Dec` rounding.
+pub(crate) fn div_raw(lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    let one = BigInt::from(DEC_ONE);
+    chop_precision_and_round(lhs * &one * &one / rhs)
+}
+
+impl Dec {
+    /// `self + rhs`; `None` on overflow.
+    pub fn checked_add(self, rhs: Dec) -> Option<Dec> {
+        self.0.checked_add(rhs.0).map(Dec)
+    }
+
+    /// `self - rhs`; `None` on overflow.
+    pub fn checked_sub(self, rhs: Dec) -> Option<Dec> {
+        self.0.checked_sub(rhs.0).map(Dec)
+    }
+
+    /// `self * rhs`, rounded half to even; `None` on overflow.
+    pub fn checked_mul(self, rhs: Dec) -> Option<Dec> {
+        mul_raw(&BigInt::from(self.0), &BigInt::from(rhs.0))
+            .to_i128()
+            .map(Dec)
+    }
+
+    /// `self / rhs`, rounded half to even; `None` on overflow or division by
+    /// zero.
+    pub fn checked_div(self, rhs: Dec) -> Option<Dec> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        div_raw(&BigInt::from(self.0), &BigInt::from(rhs.0))
+            .to_i128()
+            .map(Dec)
+    }
+}
+
 impl<T: Into<Dec>> Mul<T> for Dec {
     type Output = Self;
     fn mul(self, rhs: T) -> Self {
-        let rhs = rhs.into();
-        Dec(self.0 * rhs.0 / DEC_ONE)
+        self.checked_mul(rhs.into())
+            .expect("Dec multiplication overflow")
     }
 }
 
 impl<T: Into<Dec>> Div<T> for Dec {
     type Output = Self;
     fn div(self, rhs: T) -> Self {
-        Dec(self.0 / rhs.into().0)
+        self.checked_div(rhs.into())
+            .expect("Dec division overflow or division by zero")
     }
 }
 
@@ -124,43 +193,76 @@ impl FromStr for Dec {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('.').collect::<Vec<_>>();
-        if parts.len() > 2 {
-            return Err(format!("invalid decimal string: {}", s));
-        }
-        if parts.len() == 1 {
-            let integer = parts[0]
-                .parse::<i128>()
-                .map_err(|_| format!("invalid decimal string: {}", s))?;
-            return Ok(Dec(integer * DEC_ONE));
-        } else {
-            let integer = parts[0]
-                .parse::<i128>()
-                .map_err(|_| format!("invalid decimal string: {}", s))?;
-            let mut fraction = parts[1]
-                .parse::<i128>()
-                .map_err(|_| format!("invalid decimal string: {}", s))?;
-            fraction = fraction * 10i128.pow(DEC_PRECISION - parts[1].len() as u32);
-            return Ok(Dec(integer + fraction));
+        let err = || format!("invalid decimal string: {}", s);
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let parts = unsigned.split('.').collect::<Vec<_>>();
+        if parts.len() > 2 || parts.iter().any(|p| !p.chars().all(|c| c.is_ascii_digit())) {
+            return Err(err());
         }
+        let integer = parts[0].parse::<i128>().map_err(|_| err())?;
+        let fraction = match parts.get(1) {
+            Some(f) if f.is_empty() || f.len() > DEC_PRECISION as usize => return Err(err()),
+            Some(f) => {
+                f.parse::<i128>().map_err(|_| err())? * 10i128.pow(DEC_PRECISION - f.len() as u32)
+            }
+            None => 0,
+        };
+        let raw = integer
+            .checked_mul(DEC_ONE)
+            .and_then(|i| i.checked_add(fraction))
+            .ok_or_else(err)?;
+        Ok(Dec(if negative { -raw } else { raw }))
     }
 }
 
+/// Formats with all 18 decimal places, like `sdk.Dec.String()`.
 impl std::fmt::Display for Dec {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.as_f64())
+        let abs = self.0.unsigned_abs();
+        let one = DEC_ONE as u128;
+        let sign = if self.0 < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            abs / one,
+            abs % one,
+            width = DEC_PRECISION as usize
+        )
+    }
+}
+
+impl Serialize for Dec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
     }
 }
 
-trait DecMacroInput {
-    fn into_dec(&self) -> Dec;
+impl<'de> Deserialize<'de> for Dec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Dec::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+pub trait DecMacroInput {
+    fn to_dec(&self) -> Dec;
 }
 
 macro_rules! impl_dec_macro_input {
     ($($t:ty),*) => {
         $(
             impl DecMacroInput for $t {
-                fn into_dec(&self) -> Dec {
+                fn to_dec(&self) -> Dec {
                     Dec::new(*self as i128 * DEC_ONE)
                 }
             }
@@ -171,25 +273,25 @@ macro_rules! impl_dec_macro_input {
 impl_dec_macro_input!(usize, u8, u16, u32, u64, isize, i8, i16, i32, i64);
 
 impl DecMacroInput for &str {
-    fn into_dec(&self) -> Dec {
+    fn to_dec(&self) -> Dec {
         Dec::from_str(self).unwrap()
     }
 }
 
 impl DecMacroInput for String {
-    fn into_dec(&self) -> Dec {
+    fn to_dec(&self) -> Dec {
         Dec::from_str(self).unwrap()
     }
 }
 
 impl DecMacroInput for f32 {
-    fn into_dec(&self) -> Dec {
+    fn to_dec(&self) -> Dec {
         Dec::from_str(self.to_string().as_str()).unwrap()
     }
 }
 
 impl DecMacroInput for f64 {
-    fn into_dec(&self) -> Dec {
+    fn to_dec(&self) -> Dec {
         Dec::from_str(self.to_string().as_str()).unwrap()
     }
 }
@@ -197,7 +299,7 @@ impl DecMacroInput for f64 {
 #[macro_export]
 macro_rules! dec {
     ($e:expr) => {
-        DecMacroInput::into_dec(&$e)
+        DecMacroInput::to_dec(&$e)
     };
 }
 
@@ -213,4 +315,35 @@ mod tests {
         let d4 = d1 * d2 * d2 - d3 - d3 * d3 * d3;
         println!("{}", d4);
     }
+
+    #[test]
+    fn it_parses_and_formats() {
+        let d = Dec::from_str("-1.5").unwrap();
+        assert_eq!(d.raw(), -15 * 10i128.pow(17));
+        assert_eq!(d.to_string(), "-1.500000000000000000");
+        assert_eq!(dec!("0.05").to_string(), "0.050000000000000000");
+        assert!(Dec::from_str("1.2.3").is_err());
+        assert!(Dec::from_str("0.0000000000000000001").is_err());
+        assert_eq!(
+            serde_json::to_string(&dec!(2)).unwrap(),
+            "\"2.000000000000000000\""
+        );
+    }
+
+    #[test]
+    fn it_multiplies_and_divides_with_bankers_rounding() {
+        assert_eq!(dec!("1.5") * dec!("2.5"), dec!("3.75"));
+        assert_eq!(dec!(1) / dec!(3), dec!("0.333333333333333333"));
+        assert_eq!(dec!(2) / dec!(3), dec!("0.666666666666666667"));
+        assert_eq!(dec!(-2) / dec!(3), dec!("-0.666666666666666667"));
+        assert_eq!(dec!(10) / dec!(4), dec!("2.5"));
+        // 0.5 * 10^-18 rounds to even.
+        let tiny = Dec::new(1);
+        assert_eq!(tiny * dec!("0.5"), Dec::zero());
+        assert_eq!(Dec::new(3) * dec!("0.5"), Dec::new(2));
+        // Intermediate products beyond i128 are fine.
+        let big = dec!(1_000_000_000i64);
+        assert_eq!(big * big / big, big);
+        assert!(dec!(1).checked_div(Dec::zero()).is_none());
+    }
 }
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::convert::{From, Into, TryFrom};
 use std::ops::{Add, Div, Mul, Rem, Sub};
 use std::str::FromStr;
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
 pub struct Uint128(u128);
 
 impl Serialize for Uint128 {
@@ -34,12 +34,6 @@ impl Uint128 {
     }
 }
 
-impl std::default::Default for Uint128 {
-    fn default() -> Self {
-        Uint128(0)
-    }
-}
-
 impl FromStr for Uint128 {
     type Err = String;
 
@@ -141,9 +135,9 @@ where
     }
 }
 
-impl Into<u128> for Uint128 {
-    fn into(self) -> u128 {
-        self.0
+impl From<Uint128> for u128 {
+    fn from(value: Uint128) -> u128 {
+        value.0
     }
 }
 
@@ -157,7 +151,6 @@ impl std::fmt::Display for Uint128 {
 mod tests {
 
     use super::*;
-    use serde_json::*;
 
     #[test]
     fn it_serializes() {
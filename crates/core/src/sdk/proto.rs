@@ -0,0 +1,172 @@
+//! Protobuf counterparts of the `cosmos.base.v1beta1` types.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeZone, Utc};
+use prost::Message;
+
+use crate::sdk::coins::{Coin as SdkCoin, Coins, DecCoin as SdkDecCoin, DecCoins};
+use crate::sdk::numeric::{Dec, Uint128};
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Coin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+impl From<&SdkCoin> for Coin {
+    fn from(coin: &SdkCoin) -> Self {
+        Coin {
+            denom: coin.denom.clone(),
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Coin> for SdkCoin {
+    type Error = String;
+
+    fn try_from(coin: Coin) -> Result<Self, Self::Error> {
+        Ok(SdkCoin::new(coin.denom, Uint128::from_str(&coin.amount)?))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct DecCoin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+/// `sdk.Dec` fields are encoded as the integer string of the raw value.
+pub fn dec_to_proto(dec: &Dec) -> String {
+    dec.raw().to_string()
+}
+
+pub fn dec_from_proto(s: &str) -> Result<Dec, String> {
+    s.parse::<i128>()
+        .map(Dec::new)
+        .map_err(|_| format!("invalid protobuf decimal: {}", s))
+}
+
+pub fn coin_from_proto(coin: Option<Coin>) -> Result<SdkCoin, String> {
+    coin.ok_or_else(|| "missing coin".to_string())
+        .and_then(SdkCoin::try_from)
+}
+
+pub fn coins_to_proto(coins: &Coins) -> Vec<Coin> {
+    coins.iter().map(Coin::from).collect()
+}
+
+pub fn coins_from_proto(coins: Vec<Coin>) -> Result<Coins, String> {
+    coins
+        .into_iter()
+        .map(SdkCoin::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map(Coins::from)
+}
+
+/// `google.protobuf.Timestamp`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Timestamp {
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
+
+pub fn timestamp_to_proto(time: &DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    }
+}
+
+pub fn timestamp_from_proto(timestamp: Timestamp) -> Result<DateTime<Utc>, String> {
+    u32::try_from(timestamp.nanos)
+        .ok()
+        .and_then(|nanos| Utc.timestamp_opt(timestamp.seconds, nanos).single())
+        .ok_or_else(|| {
+            format!(
+                "invalid timestamp: {}s {}ns",
+                timestamp.seconds, timestamp.nanos
+            )
+        })
+}
+
+/// `google.protobuf.Duration`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Duration {
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
+
+pub fn duration_to_proto(duration: &std::time::Duration) -> Duration {
+    Duration {
+        seconds: duration.as_secs() as i64,
+        nanos: duration.subsec_nanos() as i32,
+    }
+}
+
+pub fn duration_from_proto(duration: Duration) -> Result<std::time::Duration, String> {
+    match (
+        u64::try_from(duration.seconds),
+        u32::try_from(duration.nanos),
+    ) {
+        (Ok(seconds), Ok(nanos)) => Ok(std::time::Duration::new(seconds, nanos)),
+        _ => Err(format!(
+            "invalid duration: {}s {}ns",
+            duration.seconds, duration.nanos
+        )),
+    }
+}
+
+/// `cosmos.crypto.secp256k1.PubKey` and `cosmos.crypto.ed25519.PubKey`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct PubKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+}
+
+/// `cosmos.crypto.multisig.LegacyAminoPubKey`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct LegacyAminoPubKey {
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+    #[prost(message, repeated, tag = "2")]
+    pub public_keys: Vec<crate::Any>,
+}
+
+impl From<&SdkDecCoin> for DecCoin {
+    fn from(coin: &SdkDecCoin) -> Self {
+        DecCoin {
+            denom: coin.denom.clone(),
+            amount: dec_to_proto(&coin.amount),
+        }
+    }
+}
+
+impl TryFrom<DecCoin> for SdkDecCoin {
+    type Error = String;
+
+    fn try_from(coin: DecCoin) -> Result<Self, Self::Error> {
+        Ok(SdkDecCoin::new(coin.denom, dec_from_proto(&coin.amount)?))
+    }
+}
+
+pub fn dec_coins_to_proto(coins: &DecCoins) -> Vec<DecCoin> {
+    coins.iter().map(DecCoin::from).collect()
+}
+
+pub fn dec_coins_from_proto(coins: Vec<DecCoin>) -> Result<DecCoins, String> {
+    coins
+        .into_iter()
+        .map(SdkDecCoin::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map(DecCoins::from)
+}
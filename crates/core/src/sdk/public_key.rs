@@ -0,0 +1,355 @@
+use bech32::{FromBase32, ToBase32, Variant};
+use k256::ecdsa::signature::Verifier;
+use k256::ecdsa::{Signature, VerifyingKey};
+use prost::encoding::encode_varint;
+use prost::Message;
+use ripemd::Ripemd160;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::internal::json_form::is_proto_json;
+use crate::sdk::proto;
+use crate::sdk::strings::{AccAddress, ValConsPubKey, ACC_ADDRESS_HRP};
+use crate::traits::Any;
+
+pub const SECP256K1_AMINO_TYPE: &str = "tendermint/PubKeySecp256k1";
+pub const ED25519_AMINO_TYPE: &str = "tendermint/PubKeyEd25519";
+pub const MULTISIG_AMINO_TYPE: &str = "tendermint/PubKeyMultisigThreshold";
+pub const SECP256K1_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+pub const ED25519_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+pub const MULTISIG_TYPE_URL: &str = "/cosmos.crypto.multisig.LegacyAminoPubKey";
+
+/// Amino prefix of an ed25519 key inside a `terravalconspub` bech32 string.
+const ED25519_AMINO_PREFIX: [u8; 5] = [0x16, 0x24, 0xde, 0x64, 0x20];
+const SECP256K1_AMINO_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+const MULTISIG_AMINO_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+
+/// A public key: secp256k1 for accounts, ed25519 for validator consensus
+/// keys, or a threshold multisig of other keys.
+///
+/// Serializes to Amino JSON (`{"type": .., "value": <base64>}`), or to
+/// protobuf JSON (`{"@type": .., "key": <base64>}`) within `Msg::to_data`,
+/// and deserializes from either, as returned by the LCD.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PublicKey {
+    Secp256k1(Vec<u8>),
+    Ed25519(Vec<u8>),
+    /// `threshold` signatures of `public_keys` are needed.
+    Multisig {
+        threshold: u32,
+        public_keys: Vec<PublicKey>,
+    },
+}
+
+impl PublicKey {
+    /// The raw key; `None` for a multisig.
+    pub fn key(&self) -> Option<&[u8]> {
+        match self {
+            PublicKey::Secp256k1(key) | PublicKey::Ed25519(key) => Some(key),
+            PublicKey::Multisig { .. } => None,
+        }
+    }
+
+    pub fn amino_type(&self) -> &'static str {
+        match self {
+            PublicKey::Secp256k1(_) => SECP256K1_AMINO_TYPE,
+            PublicKey::Ed25519(_) => ED25519_AMINO_TYPE,
+            PublicKey::Multisig { .. } => MULTISIG_AMINO_TYPE,
+        }
+    }
+
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            PublicKey::Secp256k1(_) => SECP256K1_TYPE_URL,
+            PublicKey::Ed25519(_) => ED25519_TYPE_URL,
+            PublicKey::Multisig { .. } => MULTISIG_TYPE_URL,
+        }
+    }
+
+    /// The 20-byte address of the key: RIPEMD-160 of SHA-256 for
+    /// secp256k1, truncated SHA-256 for ed25519, and truncated SHA-256 of
+    /// the Amino encoding for a multisig.
+    pub fn address_bytes(&self) -> Vec<u8> {
+        match self {
+            PublicKey::Secp256k1(key) => Ripemd160::digest(Sha256::digest(key)).to_vec(),
+            PublicKey::Ed25519(key) => Sha256::digest(key)[..20].to_vec(),
+            PublicKey::Multisig { .. } => Sha256::digest(self.amino_bytes())[..20].to_vec(),
+        }
+    }
+
+    /// The Amino binary encoding of the key.
+    fn amino_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        match self {
+            PublicKey::Secp256k1(key) => {
+                bytes.extend(SECP256K1_AMINO_PREFIX);
+                encode_varint(key.len() as u64, &mut bytes);
+                bytes.extend(key);
+            }
+            PublicKey::Ed25519(key) => {
+                bytes.extend(&ED25519_AMINO_PREFIX[..4]);
+                encode_varint(key.len() as u64, &mut bytes);
+                bytes.extend(key);
+            }
+            PublicKey::Multisig {
+                threshold,
+                public_keys,
+            } => {
+                bytes.extend(MULTISIG_AMINO_PREFIX);
+                bytes.push(0x08);
+                encode_varint(u64::from(*threshold), &mut bytes);
+                for public_key in public_keys {
+                    let key = public_key.amino_bytes();
+                    bytes.push(0x12);
+                    encode_varint(key.len() as u64, &mut bytes);
+                    bytes.extend(key);
+                }
+            }
+        }
+        bytes
+    }
+
+    pub fn acc_address(&self) -> AccAddress {
+        let address = bech32::encode(
+            ACC_ADDRESS_HRP,
+            self.address_bytes().to_base32(),
+            Variant::Bech32,
+        )
+        .expect("valid bech32 prefix");
+        AccAddress::unchecked(address)
+    }
+
+    /// Checks a 64-byte `r || s` secp256k1 signature over the SHA-256 of
+    /// `message`. High-S signatures are rejected, as on chain.
+    ///
+    /// Multisig signatures are checked per key by
+    /// `TxRaw::verify_signatures`.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), String> {
+        let key = match self {
+            PublicKey::Secp256k1(key) => VerifyingKey::from_sec1_bytes(key)
+                .map_err(|e| format!("invalid public key: {}", e))?,
+            PublicKey::Ed25519(_) => return Err("cannot verify ed25519 signatures".into()),
+            PublicKey::Multisig { .. } => {
+                return Err("cannot verify a multisig signature with one key".into())
+            }
+        };
+        let signature =
+            Signature::from_slice(signature).map_err(|e| format!("invalid signature: {}", e))?;
+        if signature.normalize_s().is_some() {
+            return Err("signature has a high S value".into());
+        }
+        key.verify(message, &signature)
+            .map_err(|_| format!("signature does not match {}", self.acc_address()))
+    }
+
+    pub fn to_amino(&self) -> Value {
+        match self {
+            PublicKey::Secp256k1(key) | PublicKey::Ed25519(key) => json!({
+                "type": self.amino_type(),
+                "value": base64::encode(key),
+            }),
+            PublicKey::Multisig {
+                threshold,
+                public_keys,
+            } => json!({
+                "type": self.amino_type(),
+                "value": {
+                    "threshold": threshold.to_string(),
+                    "pubkeys": public_keys.iter().map(Self::to_amino).collect::<Vec<_>>(),
+                },
+            }),
+        }
+    }
+
+    pub fn to_data(&self) -> Value {
+        match self {
+            PublicKey::Secp256k1(key) | PublicKey::Ed25519(key) => json!({
+                "@type": self.type_url(),
+                "key": base64::encode(key),
+            }),
+            PublicKey::Multisig {
+                threshold,
+                public_keys,
+            } => json!({
+                "@type": self.type_url(),
+                "threshold": threshold,
+                "public_keys": public_keys.iter().map(Self::to_data).collect::<Vec<_>>(),
+            }),
+        }
+    }
+
+    pub fn to_any(&self) -> Any {
+        let value = match self {
+            PublicKey::Secp256k1(key) | PublicKey::Ed25519(key) => {
+                proto::PubKey { key: key.clone() }.encode_to_vec()
+            }
+            PublicKey::Multisig {
+                threshold,
+                public_keys,
+            } => proto::LegacyAminoPubKey {
+                threshold: *threshold,
+                public_keys: public_keys.iter().map(Self::to_any).collect(),
+            }
+            .encode_to_vec(),
+        };
+        Any {
+            type_url: self.type_url().into(),
+            value,
+        }
+    }
+
+    pub fn from_any(any: &Any) -> Result<Self, String> {
+        if any.type_url == MULTISIG_TYPE_URL {
+            let multisig = proto::LegacyAminoPubKey::decode(any.value.as_slice())
+                .map_err(|e| format!("invalid public key: {}", e))?;
+            return Ok(PublicKey::Multisig {
+                threshold: multisig.threshold,
+                public_keys: multisig
+                    .public_keys
+                    .iter()
+                    .map(Self::from_any)
+                    .collect::<Result<_, _>>()?,
+            });
+        }
+        let key = proto::PubKey::decode(any.value.as_slice())
+            .map_err(|e| format!("invalid public key: {}", e))?
+            .key;
+        Self::from_type(&any.type_url, key)
+    }
+
+    /// Parses either JSON form.
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let (type_name, key) = match (value.get("@type"), value.get("type")) {
+            (Some(t), _) => (t, value.get("key")),
+            (None, Some(t)) => (t, value.get("value")),
+            (None, None) => return Err("public key has no type".into()),
+        };
+        let type_name = type_name
+            .as_str()
+            .ok_or("public key type is not a string")?;
+        if type_name == MULTISIG_AMINO_TYPE || type_name == MULTISIG_TYPE_URL {
+            // Amino nests the fields in `value`, and writes the threshold
+            // as a string.
+            let fields = value.get("value").unwrap_or(value);
+            let threshold = match fields.get("threshold") {
+                Some(Value::String(s)) => s.parse().ok(),
+                Some(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+                None => None,
+            }
+            .ok_or("multisig has no valid threshold")?;
+            let public_keys = fields
+                .get("public_keys")
+                .or_else(|| fields.get("pubkeys"))
+                .and_then(Value::as_array)
+                .ok_or("multisig has no public keys")?
+                .iter()
+                .map(Self::from_json)
+                .collect::<Result<_, _>>()?;
+            return Ok(PublicKey::Multisig {
+                threshold,
+                public_keys,
+            });
+        }
+        let key = key.and_then(Value::as_str).ok_or("public key has no key")?;
+        let key = base64::decode(key).map_err(|e| e.to_string())?;
+        PublicKey::from_type(type_name, key)
+    }
+
+    fn from_type(type_name: &str, key: Vec<u8>) -> Result<Self, String> {
+        match type_name {
+            SECP256K1_AMINO_TYPE | SECP256K1_TYPE_URL => Ok(PublicKey::Secp256k1(key)),
+            ED25519_AMINO_TYPE | ED25519_TYPE_URL => Ok(PublicKey::Ed25519(key)),
+            other => Err(format!("unsupported public key type: {}", other)),
+        }
+    }
+}
+
+impl TryFrom<&ValConsPubKey> for PublicKey {
+    type Error = String;
+
+    fn try_from(pubkey: &ValConsPubKey) -> Result<Self, Self::Error> {
+        let (_, data, _) = bech32::decode(pubkey.as_str()).map_err(|e| e.to_string())?;
+        let bytes = Vec::<u8>::from_base32(&data).map_err(|e| e.to_string())?;
+        match bytes.strip_prefix(&ED25519_AMINO_PREFIX[..]) {
+            Some(key) => Ok(PublicKey::Ed25519(key.to_vec())),
+            None => Err(format!("not an ed25519 consensus key: {}", pubkey)),
+        }
+    }
+}
+
+impl Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if is_proto_json() {
+            self.to_data().serialize(serializer)
+        } else {
+            self.to_amino().serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = Value::deserialize(deserializer)?;
+        PublicKey::from_json(&value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_val_cons_pubkey() {
+        let bech = ValConsPubKey::unchecked(
+            "terravalconspub1zcjduepqwgwyky5375uk0llhwf0ya5lmwy4up838jevfh3pyzf5s3hd96xjslnexul",
+        );
+        let pubkey = PublicKey::try_from(&bech).unwrap();
+        assert_eq!(pubkey.key().unwrap().len(), 32);
+
+        let amino = serde_json::to_value(&pubkey).unwrap();
+        assert_eq!(amino["type"], ED25519_AMINO_TYPE);
+        let from_data: PublicKey = serde_json::from_value(pubkey.to_data()).unwrap();
+        assert_eq!(from_data, pubkey);
+        assert_eq!(PublicKey::from_any(&pubkey.to_any()).unwrap(), pubkey);
+    }
+
+    #[test]
+    fn it_converts_multisig() {
+        let member = |n: u8| PublicKey::Secp256k1([&[2u8][..], &[n; 32]].concat());
+        let multisig = PublicKey::Multisig {
+            threshold: 2,
+            public_keys: vec![member(1), member(2), member(3)],
+        };
+        assert_eq!(multisig.key(), None);
+        assert_eq!(PublicKey::from_any(&multisig.to_any()).unwrap(), multisig);
+
+        let amino = multisig.to_amino();
+        assert_eq!(amino["value"]["threshold"], "2");
+        assert_eq!(amino["value"]["pubkeys"][1], member(2).to_amino());
+        assert_eq!(
+            serde_json::from_value::<PublicKey>(amino).unwrap(),
+            multisig
+        );
+        let data = multisig.to_data();
+        assert_eq!(data["public_keys"][0]["@type"], SECP256K1_TYPE_URL);
+        assert_eq!(serde_json::from_value::<PublicKey>(data).unwrap(), multisig);
+
+        let amino_bytes = multisig.amino_bytes();
+        assert_eq!(amino_bytes[..6], [0x22, 0xc1, 0xf7, 0xe2, 0x08, 0x02]);
+        assert_eq!(
+            amino_bytes[6..13],
+            [0x12, 0x26, 0xeb, 0x5a, 0xe9, 0x87, 0x21]
+        );
+        assert_eq!(amino_bytes.len(), 6 + 3 * 40);
+        assert_ne!(multisig.acc_address(), member(1).acc_address());
+    }
+}
@@ -12,7 +12,7 @@ pub const ACC_PUBKEY_HRP: &str = "terrapub";
 pub const VAL_PUBKEY_HRP: &str = "terravaloperpub";
 pub const VALCONS_PUBKEY_HRP: &str = "terravalconspub";
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 #[serde(transparent)]
 pub struct AccAddress(pub String);
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
@@ -172,28 +172,28 @@ mod tests {
     #[test]
     fn it_validates_acc_address() {
         let a = AccAddress::validate("terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk");
-        assert_eq!(a, false);
+        assert!(!a);
 
         let b = AccAddress::validate("terra1pdx498r0h7c2fj36sjhs8vu8rz9hd2cw0tmam9");
-        assert_eq!(b, false);
+        assert!(!b);
 
         let c = AccAddress::validate("cosmos176m2p8l3fps3dal7h8gf9jvrv98tu3rqfdht86");
-        assert_eq!(c, false);
+        assert!(!c);
 
         let d = AccAddress::validate("terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0tmam9");
-        assert_eq!(d, true);
+        assert!(d);
 
         let e = AccAddress::new("terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0tmam9").is_ok();
-        assert_eq!(e, true);
+        assert!(e);
     }
 
     #[test]
     fn it_validates_val_address() {
         let a = ValAddress::validate("terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk");
-        assert_eq!(a, true);
+        assert!(a);
 
         let b = ValAddress::new("terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk").is_ok();
-        assert_eq!(b, true);
+        assert!(b);
     }
 
     #[test]
@@ -219,7 +219,7 @@ mod tests {
     #[test]
     fn it_validates_val_cons_address() {
         let a = ValConsAddress::validate("terravalcons1relcztayk87c3r529rqf3fwdmn8hr6rhcgyrxd");
-        assert_eq!(a, true);
+        assert!(a);
     }
 
     #[test]
@@ -227,24 +227,24 @@ mod tests {
         let a = AccPubKey::validate(
             "terravaloperpub1addwnpepqt8ha594svjn3nvfk4ggfn5n8xd3sm3cz6ztxyugwcuqzsuuhhfq5y7accr",
         );
-        assert_eq!(a, false);
+        assert!(!a);
 
         let b = AccPubKey::validate("terrapub1x46rqay4d3cssq8gxxvqz8xt6nwlz4tdh39t77");
-        assert_eq!(b, true);
+        assert!(b);
 
         let c = AccPubKey::new("terrapub1x46rqay4d3cssq8gxxvqz8xt6nwlz4tdh39t77").is_ok();
-        assert_eq!(c, true);
+        assert!(c);
     }
 
     #[test]
     fn it_validates_val_pubkey() {
         let a = ValPubKey::validate("terravaloper12g4nkvsjjnl0t7fvq3hdcw7y8dc9fq69nyeu9q");
-        assert_eq!(a, false);
+        assert!(!a);
 
         let b = ValPubKey::validate("terravaloperpub12g4nkvsjjnl0t7fvq3hdcw7y8dc9fq69gvd5ag");
-        assert_eq!(b, true);
+        assert!(b);
 
         let c = ValPubKey::new("terravaloperpub12g4nkvsjjnl0t7fvq3hdcw7y8dc9fq69gvd5ag").is_ok();
-        assert_eq!(c, true);
+        assert!(c);
     }
 }
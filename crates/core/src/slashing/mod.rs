@@ -1,2 +1,10 @@
 pub mod msgs;
 pub use msgs::*;
+
+pub mod params;
+pub use params::*;
+
+pub mod signing_info;
+pub use signing_info::*;
+
+pub mod proto;
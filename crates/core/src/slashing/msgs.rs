@@ -1,3 +1,57 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::slashing::proto;
+use crate::{ProtoMsg, ValAddress};
+
+/// Releases a jailed validator once its jail time has passed.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("slashing/MsgUnjail")]
+#[msgtype_pb("/cosmos.slashing.v1beta1.MsgUnjail")]
 pub struct MsgUnjail {
     pub address: ValAddress,
 }
+
+impl MsgUnjail {
+    pub fn new(address: impl Into<ValAddress>) -> Self {
+        Self {
+            address: address.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgUnjail {
+    type Proto = proto::MsgUnjail;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgUnjail {
+            validator_addr: self.address.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            address: proto.validator_addr.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_unjail() {
+        let msg = MsgUnjail::new("terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk");
+        assert_eq!(
+            msg.to_json().unwrap(),
+            json!({
+                "type": "slashing/MsgUnjail",
+                "value": { "address": "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk" }
+            })
+        );
+        assert_eq!(MsgUnjail::from_any(&msg.to_any().unwrap()).unwrap(), msg);
+    }
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Dec;
+
+/// Parameters of the slashing module.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct SlashingParams {
+    /// Number of recent blocks over which liveness is tracked.
+    #[serde(with = "crate::internal::serde_str")]
+    pub signed_blocks_window: u64,
+    /// Fraction of the window a validator must sign to avoid jailing.
+    pub min_signed_per_window: Dec,
+    #[serde(with = "crate::internal::serde_duration")]
+    pub downtime_jail_duration: Duration,
+    pub slash_fraction_double_sign: Dec,
+    pub slash_fraction_downtime: Dec,
+}
+
+impl SlashingParams {
+    /// Blocks that must be signed per window, rounded as the chain does
+    /// (half to even).
+    pub fn min_signed_blocks(&self) -> u64 {
+        let one = Dec::one().raw();
+        let product = self.min_signed_per_window.raw() * self.signed_blocks_window as i128;
+        let (quotient, remainder) = (product / one, product % one);
+        let rounded = match (remainder * 2).cmp(&one) {
+            std::cmp::Ordering::Greater => quotient + 1,
+            std::cmp::Ordering::Equal if quotient % 2 == 1 => quotient + 1,
+            _ => quotient,
+        };
+        rounded.clamp(0, self.signed_blocks_window as i128) as u64
+    }
+
+    /// Blocks a validator may miss per window; missing one more jails it.
+    pub fn max_missed_blocks(&self) -> u64 {
+        self.signed_blocks_window - self.min_signed_blocks()
+    }
+}
+
+/// Terra classic mainnet's parameters.
+impl Default for SlashingParams {
+    fn default() -> Self {
+        Self {
+            signed_blocks_window: 10_000,
+            min_signed_per_window: Dec::new(5 * 10i128.pow(16)),
+            downtime_jail_duration: Duration::from_secs(600),
+            slash_fraction_double_sign: Dec::new(5 * 10i128.pow(16)),
+            slash_fraction_downtime: Dec::new(10i128.pow(14)),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Protobuf counterparts of the `cosmos.slashing.v1beta1` messages.
+
+use prost::Message;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgUnjail {
+    #[prost(string, tag = "1")]
+    pub validator_addr: String,
+}
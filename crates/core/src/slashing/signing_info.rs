@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::slashing::SlashingParams;
+use crate::ValConsAddress;
+
+/// Liveness record of a validator, keyed by its consensus address.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ValidatorSigningInfo {
+    pub address: ValConsAddress,
+    /// Height at which the validator started signing (or was unjailed).
+    #[serde(default, with = "crate::internal::serde_str")]
+    pub start_height: u64,
+    /// Position of the current block in the signed blocks window.
+    #[serde(default, with = "crate::internal::serde_str")]
+    pub index_offset: u64,
+    pub jailed_until: DateTime<Utc>,
+    #[serde(default)]
+    pub tombstoned: bool,
+    /// Blocks missed within the current window.
+    #[serde(default, with = "crate::internal::serde_str")]
+    pub missed_blocks_counter: u64,
+}
+
+impl ValidatorSigningInfo {
+    /// How many more blocks the validator can miss in the current window
+    /// before the next miss gets it jailed.
+    pub fn missable_blocks(&self, params: &SlashingParams) -> u64 {
+        params
+            .max_missed_blocks()
+            .saturating_sub(self.missed_blocks_counter)
+    }
+
+    pub fn is_jailed(&self, now: DateTime<Utc>) -> bool {
+        self.jailed_until > now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dec;
+    use serde_json::json;
+
+    #[test]
+    fn it_computes_missable_blocks() {
+        let info: ValidatorSigningInfo = serde_json::from_value(json!({
+            "address": "terravalcons1ezj3lps8nqwytt42at2sgt7seq9hk708g7gyrf",
+            "start_height": "0",
+            "index_offset": "4851",
+            "jailed_until": "1970-01-01T00:00:00Z",
+            "tombstoned": false,
+            "missed_blocks_counter": "120"
+        }))
+        .unwrap();
+        let params: SlashingParams = serde_json::from_value(json!({
+            "signed_blocks_window": "10000",
+            "min_signed_per_window": "0.050000000000000000",
+            "downtime_jail_duration": "600s",
+            "slash_fraction_double_sign": "0.050000000000000000",
+            "slash_fraction_downtime": "0.000100000000000000"
+        }))
+        .unwrap();
+        assert_eq!(params, SlashingParams::default());
+        assert_eq!(params.max_missed_blocks(), 9_500);
+        assert_eq!(info.missable_blocks(&params), 9_380);
+        assert!(!info.is_jailed("2023-01-01T00:00:00Z".parse().unwrap()));
+
+        let params = SlashingParams {
+            signed_blocks_window: 5,
+            min_signed_per_window: Dec::new(5 * 10i128.pow(17)),
+            ..params
+        };
+        // 2.5 rounds to even.
+        assert_eq!(params.min_signed_blocks(), 2);
+    }
+}
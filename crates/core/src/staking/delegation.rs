@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{AccAddress, Coin, Dec, Uint128, ValAddress};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Delegation {
+    pub delegator_address: AccAddress,
+    pub validator_address: ValAddress,
+    pub shares: Dec,
+}
+
+/// A delegation together with its current token balance.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct DelegationResponse {
+    pub delegation: Delegation,
+    pub balance: Coin,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct UnbondingDelegationEntry {
+    #[serde(with = "crate::internal::serde_str")]
+    pub creation_height: u64,
+    pub completion_time: DateTime<Utc>,
+    pub initial_balance: Uint128,
+    pub balance: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct UnbondingDelegation {
+    pub delegator_address: AccAddress,
+    pub validator_address: ValAddress,
+    pub entries: Vec<UnbondingDelegationEntry>,
+}
+
+impl UnbondingDelegation {
+    /// Total amount still unbonding across all entries.
+    pub fn balance(&self) -> Uint128 {
+        self.entries
+            .iter()
+            .fold(Uint128::default(), |acc, e| acc + e.balance)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct RedelegationEntry {
+    #[serde(with = "crate::internal::serde_str")]
+    pub creation_height: u64,
+    pub completion_time: DateTime<Utc>,
+    pub initial_balance: Uint128,
+    pub shares_dst: Dec,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Redelegation {
+    pub delegator_address: AccAddress,
+    pub validator_src_address: ValAddress,
+    pub validator_dst_address: ValAddress,
+    pub entries: Vec<RedelegationEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct RedelegationEntryResponse {
+    pub redelegation_entry: RedelegationEntry,
+    pub balance: Uint128,
+}
+
+/// A redelegation together with the current balance of each entry.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct RedelegationResponse {
+    pub redelegation: Redelegation,
+    pub entries: Vec<RedelegationEntryResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_deserializes_unbonding_delegation() {
+        let unbonding: UnbondingDelegation = serde_json::from_value(json!({
+            "delegator_address": "terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0tmam9",
+            "validator_address": "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk",
+            "entries": [
+                {
+                    "creation_height": "4711",
+                    "completion_time": "2022-03-01T00:00:00Z",
+                    "initial_balance": "100",
+                    "balance": "90"
+                },
+                {
+                    "creation_height": "4712",
+                    "completion_time": "2022-03-02T00:00:00Z",
+                    "initial_balance": "10",
+                    "balance": "10"
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(unbonding.entries[0].creation_height, 4711);
+        assert_eq!(unbonding.balance(), Uint128::from(100u64));
+    }
+}
@@ -1,2 +1,13 @@
 pub mod msgs;
 pub use msgs::*;
+
+pub mod validator;
+pub use validator::*;
+
+pub mod delegation;
+pub use delegation::*;
+
+pub mod params;
+pub use params::*;
+
+pub mod proto;
@@ -1,15 +1,99 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::sdk::proto::{coin_from_proto, dec_from_proto, dec_to_proto};
+use crate::staking::{proto, CommissionRates, Description};
+use crate::{AccAddress, Coin, Dec, ProtoMsg, PublicKey, Uint128, ValAddress};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("staking/MsgDelegate")]
+#[msgtype_pb("/cosmos.staking.v1beta1.MsgDelegate")]
 pub struct MsgDelegate {
     pub delegator_address: AccAddress,
     pub validator_address: ValAddress,
     pub amount: Coin,
 }
 
+impl MsgDelegate {
+    pub fn new(
+        delegator_address: impl Into<AccAddress>,
+        validator_address: impl Into<ValAddress>,
+        amount: Coin,
+    ) -> Self {
+        Self {
+            delegator_address: delegator_address.into(),
+            validator_address: validator_address.into(),
+            amount,
+        }
+    }
+}
+
+impl ProtoMsg for MsgDelegate {
+    type Proto = proto::MsgDelegate;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgDelegate {
+            delegator_address: self.delegator_address.to_string(),
+            validator_address: self.validator_address.to_string(),
+            amount: Some((&self.amount).into()),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            delegator_address: proto.delegator_address.into(),
+            validator_address: proto.validator_address.into(),
+            amount: coin_from_proto(proto.amount)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("staking/MsgUndelegate")]
+#[msgtype_pb("/cosmos.staking.v1beta1.MsgUndelegate")]
 pub struct MsgUndelegate {
     pub delegator_address: AccAddress,
     pub validator_address: ValAddress,
     pub amount: Coin,
 }
 
+impl MsgUndelegate {
+    pub fn new(
+        delegator_address: impl Into<AccAddress>,
+        validator_address: impl Into<ValAddress>,
+        amount: Coin,
+    ) -> Self {
+        Self {
+            delegator_address: delegator_address.into(),
+            validator_address: validator_address.into(),
+            amount,
+        }
+    }
+}
+
+impl ProtoMsg for MsgUndelegate {
+    type Proto = proto::MsgUndelegate;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgUndelegate {
+            delegator_address: self.delegator_address.to_string(),
+            validator_address: self.validator_address.to_string(),
+            amount: Some((&self.amount).into()),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            delegator_address: proto.delegator_address.into(),
+            validator_address: proto.validator_address.into(),
+            amount: coin_from_proto(proto.amount)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("staking/MsgBeginRedelegate")]
+#[msgtype_pb("/cosmos.staking.v1beta1.MsgBeginRedelegate")]
 pub struct MsgBeginRedelegate {
     pub delegator_address: AccAddress,
     pub validator_src_address: ValAddress,
@@ -17,19 +101,226 @@ pub struct MsgBeginRedelegate {
     pub amount: Coin,
 }
 
+impl MsgBeginRedelegate {
+    pub fn new(
+        delegator_address: impl Into<AccAddress>,
+        validator_src_address: impl Into<ValAddress>,
+        validator_dst_address: impl Into<ValAddress>,
+        amount: Coin,
+    ) -> Self {
+        Self {
+            delegator_address: delegator_address.into(),
+            validator_src_address: validator_src_address.into(),
+            validator_dst_address: validator_dst_address.into(),
+            amount,
+        }
+    }
+}
+
+impl ProtoMsg for MsgBeginRedelegate {
+    type Proto = proto::MsgBeginRedelegate;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgBeginRedelegate {
+            delegator_address: self.delegator_address.to_string(),
+            validator_src_address: self.validator_src_address.to_string(),
+            validator_dst_address: self.validator_dst_address.to_string(),
+            amount: Some((&self.amount).into()),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            delegator_address: proto.delegator_address.into(),
+            validator_src_address: proto.validator_src_address.into(),
+            validator_dst_address: proto.validator_dst_address.into(),
+            amount: coin_from_proto(proto.amount)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("staking/MsgEditValidator")]
+#[msgtype_pb("/cosmos.staking.v1beta1.MsgEditValidator")]
 pub struct MsgEditValidator {
-    pub description: ValidatorDescription,
+    pub description: Description,
     pub validator_address: ValAddress,
-    pub commisssion_rate: Option<Dec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commission_rate: Option<Dec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub min_self_delegation: Option<Uint128>,
 }
 
+impl MsgEditValidator {
+    pub fn new(description: Description, validator_address: impl Into<ValAddress>) -> Self {
+        Self {
+            description,
+            validator_address: validator_address.into(),
+            commission_rate: None,
+            min_self_delegation: None,
+        }
+    }
+}
+
+impl ProtoMsg for MsgEditValidator {
+    type Proto = proto::MsgEditValidator;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgEditValidator {
+            description: Some(self.description.to_proto()?),
+            validator_address: self.validator_address.to_string(),
+            commission_rate: self
+                .commission_rate
+                .as_ref()
+                .map(dec_to_proto)
+                .unwrap_or_default(),
+            min_self_delegation: self
+                .min_self_delegation
+                .map(|m| m.to_string())
+                .unwrap_or_default(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        let commission_rate = match proto.commission_rate.as_str() {
+            "" => None,
+            rate => Some(dec_from_proto(rate)?),
+        };
+        let min_self_delegation = match proto.min_self_delegation.as_str() {
+            "" => None,
+            min => Some(min.parse()?),
+        };
+        Ok(Self {
+            description: Description::from_proto(proto.description.unwrap_or_default())?,
+            validator_address: proto.validator_address.into(),
+            commission_rate,
+            min_self_delegation,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("staking/MsgCreateValidator")]
+#[msgtype_pb("/cosmos.staking.v1beta1.MsgCreateValidator")]
 pub struct MsgCreateValidator {
-    pub description: ValidatorDescription,
-    pub commission: ValidatorCommissionRates,
+    pub description: Description,
+    pub commission: CommissionRates,
     pub min_self_delegation: Uint128,
     pub delegator_address: AccAddress,
     pub validator_address: ValAddress,
-    pub pubkey: ValConsPublicKey,
+    pub pubkey: PublicKey,
     pub value: Coin,
 }
+
+impl MsgCreateValidator {
+    /// Checks the commission rates and that the operator is the
+    /// self-delegator, as the chain requires.
+    pub fn validate(&self) -> Result<(), String> {
+        self.commission.validate()?;
+        if !self.delegator_address.is_valid() {
+            return Err(format!(
+                "invalid delegator address: {}",
+                self.delegator_address
+            ));
+        }
+        if !self.validator_address.is_valid() {
+            return Err(format!(
+                "invalid validator address: {}",
+                self.validator_address
+            ));
+        }
+        if self.delegator_address.clone().to_val_address() != self.validator_address {
+            return Err("validator address must belong to the delegator".into());
+        }
+        if !matches!(self.pubkey, PublicKey::Ed25519(_)) {
+            return Err("validator consensus key must be ed25519".into());
+        }
+        if self.value.amount < self.min_self_delegation {
+            return Err("self delegation is below the minimum".into());
+        }
+        Ok(())
+    }
+}
+
+impl ProtoMsg for MsgCreateValidator {
+    type Proto = proto::MsgCreateValidator;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgCreateValidator {
+            description: Some(self.description.to_proto()?),
+            commission: Some(self.commission.to_proto()?),
+            min_self_delegation: self.min_self_delegation.to_string(),
+            delegator_address: self.delegator_address.to_string(),
+            validator_address: self.validator_address.to_string(),
+            pubkey: Some(self.pubkey.to_any()),
+            value: Some((&self.value).into()),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            description: Description::from_proto(proto.description.unwrap_or_default())?,
+            commission: CommissionRates::from_proto(proto.commission.ok_or("missing commission")?)?,
+            min_self_delegation: proto.min_self_delegation.parse()?,
+            delegator_address: proto.delegator_address.into(),
+            validator_address: proto.validator_address.into(),
+            pubkey: PublicKey::from_any(&proto.pubkey.ok_or("missing pubkey")?)?,
+            value: coin_from_proto(proto.value)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coin, dec, CoinMacroAmount, DecMacroInput, JsonSer, Msg};
+
+    #[test]
+    fn it_encodes_create_validator() {
+        let msg = MsgCreateValidator {
+            description: Description::new("val"),
+            commission: CommissionRates::new(dec!("0.1"), dec!("0.2"), dec!("0.01")),
+            min_self_delegation: Uint128::from(1u64),
+            delegator_address: "terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0tmam9".into(),
+            validator_address: "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk".into(),
+            pubkey: PublicKey::Ed25519(vec![7; 32]),
+            value: coin!("uluna", 1000000),
+        };
+        assert!(msg.validate().is_ok());
+        assert!(MsgCreateValidator {
+            delegator_address: "terra1invalid".into(),
+            ..msg.clone()
+        }
+        .validate()
+        .is_err());
+
+        let amino = msg.to_json().unwrap();
+        assert_eq!(amino["value"]["commission"]["rate"], "0.100000000000000000");
+        assert_eq!(amino["value"]["pubkey"]["type"], "tendermint/PubKeyEd25519");
+        assert_eq!(MsgCreateValidator::from_amino(&amino).unwrap(), msg);
+        assert_eq!(
+            MsgCreateValidator::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+        assert_eq!(
+            msg.to_proto().unwrap().commission.unwrap().rate,
+            "100000000000000000"
+        );
+    }
+
+    #[test]
+    fn it_omits_unset_edit_fields() {
+        let mut msg = MsgEditValidator::new(
+            Description::unchanged(),
+            "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk",
+        );
+        let amino = msg.to_json().unwrap();
+        assert!(amino["value"].get("commission_rate").is_none());
+
+        msg.commission_rate = Some(dec!("0.05"));
+        assert_eq!(
+            MsgEditValidator::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+    }
+}
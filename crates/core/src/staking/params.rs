@@ -0,0 +1,17 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Parameters of the staking module.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct StakingParams {
+    #[serde(with = "crate::internal::serde_duration")]
+    pub unbonding_time: Duration,
+    /// Size of the active validator set.
+    pub max_validators: u32,
+    /// Unbonding or redelegation entries allowed per delegator and validator
+    /// pair.
+    pub max_entries: u32,
+    pub historical_entries: u32,
+    pub bond_denom: String,
+}
@@ -0,0 +1,92 @@
+//! Protobuf counterparts of the `cosmos.staking.v1beta1` messages.
+
+use prost::Message;
+
+use crate::sdk::proto::Coin;
+use crate::traits::Any;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Description {
+    #[prost(string, tag = "1")]
+    pub moniker: String,
+    #[prost(string, tag = "2")]
+    pub identity: String,
+    #[prost(string, tag = "3")]
+    pub website: String,
+    #[prost(string, tag = "4")]
+    pub security_contact: String,
+    #[prost(string, tag = "5")]
+    pub details: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CommissionRates {
+    #[prost(string, tag = "1")]
+    pub rate: String,
+    #[prost(string, tag = "2")]
+    pub max_rate: String,
+    #[prost(string, tag = "3")]
+    pub max_change_rate: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgCreateValidator {
+    #[prost(message, optional, tag = "1")]
+    pub description: Option<Description>,
+    #[prost(message, optional, tag = "2")]
+    pub commission: Option<CommissionRates>,
+    #[prost(string, tag = "3")]
+    pub min_self_delegation: String,
+    #[prost(string, tag = "4")]
+    pub delegator_address: String,
+    #[prost(string, tag = "5")]
+    pub validator_address: String,
+    #[prost(message, optional, tag = "6")]
+    pub pubkey: Option<Any>,
+    #[prost(message, optional, tag = "7")]
+    pub value: Option<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgEditValidator {
+    #[prost(message, optional, tag = "1")]
+    pub description: Option<Description>,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+    #[prost(string, tag = "3")]
+    pub commission_rate: String,
+    #[prost(string, tag = "4")]
+    pub min_self_delegation: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgDelegate {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+    #[prost(message, optional, tag = "3")]
+    pub amount: Option<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgBeginRedelegate {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_src_address: String,
+    #[prost(string, tag = "3")]
+    pub validator_dst_address: String,
+    #[prost(message, optional, tag = "4")]
+    pub amount: Option<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgUndelegate {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+    #[prost(message, optional, tag = "3")]
+    pub amount: Option<Coin>,
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::sdk::proto::{dec_from_proto, dec_to_proto};
+use crate::staking::proto;
+use crate::{Dec, ProtoMsg, PublicKey, Uint128, ValAddress};
+
+/// Placeholder the chain interprets as "keep the current value" for
+/// description fields in `MsgEditValidator`.
+pub const DO_NOT_MODIFY: &str = "[do-not-modify]";
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct Description {
+    #[serde(default)]
+    pub moniker: String,
+    #[serde(default)]
+    pub identity: String,
+    #[serde(default)]
+    pub website: String,
+    #[serde(default)]
+    pub security_contact: String,
+    #[serde(default)]
+    pub details: String,
+}
+
+impl Description {
+    pub fn new(moniker: impl Into<String>) -> Self {
+        Self {
+            moniker: moniker.into(),
+            ..Default::default()
+        }
+    }
+
+    /// A description that leaves every field untouched when used in
+    /// `MsgEditValidator`; override the fields to change.
+    pub fn unchanged() -> Self {
+        Self {
+            moniker: DO_NOT_MODIFY.into(),
+            identity: DO_NOT_MODIFY.into(),
+            website: DO_NOT_MODIFY.into(),
+            security_contact: DO_NOT_MODIFY.into(),
+            details: DO_NOT_MODIFY.into(),
+        }
+    }
+}
+
+impl ProtoMsg for Description {
+    type Proto = proto::Description;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::Description {
+            moniker: self.moniker.clone(),
+            identity: self.identity.clone(),
+            website: self.website.clone(),
+            security_contact: self.security_contact.clone(),
+            details: self.details.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            moniker: proto.moniker,
+            identity: proto.identity,
+            website: proto.website,
+            security_contact: proto.security_contact,
+            details: proto.details,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CommissionRates {
+    pub rate: Dec,
+    pub max_rate: Dec,
+    pub max_change_rate: Dec,
+}
+
+impl CommissionRates {
+    pub fn new(rate: Dec, max_rate: Dec, max_change_rate: Dec) -> Self {
+        Self {
+            rate,
+            max_rate,
+            max_change_rate,
+        }
+    }
+
+    /// Same checks as the chain's `CommissionRates.Validate`.
+    pub fn validate(&self) -> Result<(), String> {
+        let zero = Dec::zero();
+        let one = Dec::one();
+        if self.max_rate < zero || self.max_rate > one {
+            return Err(format!("max rate {} must be within [0, 1]", self.max_rate));
+        }
+        if self.rate < zero || self.rate > self.max_rate {
+            return Err(format!(
+                "rate {} must be within [0, max rate {}]",
+                self.rate, self.max_rate
+            ));
+        }
+        if self.max_change_rate < zero || self.max_change_rate > self.max_rate {
+            return Err(format!(
+                "max change rate {} must be within [0, max rate {}]",
+                self.max_change_rate, self.max_rate
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl ProtoMsg for CommissionRates {
+    type Proto = proto::CommissionRates;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::CommissionRates {
+            rate: dec_to_proto(&self.rate),
+            max_rate: dec_to_proto(&self.max_rate),
+            max_change_rate: dec_to_proto(&self.max_change_rate),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            rate: dec_from_proto(&proto.rate)?,
+            max_rate: dec_from_proto(&proto.max_rate)?,
+            max_change_rate: dec_from_proto(&proto.max_change_rate)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Commission {
+    pub commission_rates: CommissionRates,
+    pub update_time: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BondStatus {
+    #[serde(rename = "BOND_STATUS_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "BOND_STATUS_UNBONDED")]
+    Unbonded,
+    #[serde(rename = "BOND_STATUS_UNBONDING")]
+    Unbonding,
+    #[serde(rename = "BOND_STATUS_BONDED")]
+    Bonded,
+}
+
+/// A validator as returned by `/cosmos/staking/v1beta1/validators`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Validator {
+    pub operator_address: ValAddress,
+    pub consensus_pubkey: PublicKey,
+    pub jailed: bool,
+    pub status: BondStatus,
+    pub tokens: Uint128,
+    pub delegator_shares: Dec,
+    pub description: Description,
+    #[serde(with = "crate::internal::serde_str")]
+    pub unbonding_height: u64,
+    pub unbonding_time: DateTime<Utc>,
+    pub commission: Commission,
+    pub min_self_delegation: Uint128,
+}
+
+impl Validator {
+    /// Whether the validator is in the active set.
+    pub fn is_active(&self) -> bool {
+        self.status == BondStatus::Bonded && !self.jailed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_deserializes_validator() {
+        let validator: Validator = serde_json::from_value(json!({
+            "operator_address": "terravaloper1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk",
+            "consensus_pubkey": {
+                "@type": "/cosmos.crypto.ed25519.PubKey",
+                "key": "chxLEpH1OWf/93JeTtP7cSvAnieWWJvEJBJpCN2l0aU="
+            },
+            "jailed": false,
+            "status": "BOND_STATUS_BONDED",
+            "tokens": "8019911298",
+            "delegator_shares": "8019911298.000000000000000000",
+            "description": {
+                "moniker": "val",
+                "identity": "",
+                "website": "",
+                "security_contact": "",
+                "details": ""
+            },
+            "unbonding_height": "0",
+            "unbonding_time": "1970-01-01T00:00:00Z",
+            "commission": {
+                "commission_rates": {
+                    "rate": "0.100000000000000000",
+                    "max_rate": "0.200000000000000000",
+                    "max_change_rate": "0.010000000000000000"
+                },
+                "update_time": "2021-09-30T12:00:00.123456789Z"
+            },
+            "min_self_delegation": "1"
+        }))
+        .unwrap();
+        assert!(validator.is_active());
+        assert!(validator.commission.commission_rates.validate().is_ok());
+        assert_eq!(validator.tokens, Uint128::from(8019911298u64));
+    }
+}
@@ -1,2 +1,8 @@
 pub mod msg_type;
 pub use msg_type::*;
+
+pub mod proto_msg;
+pub use proto_msg::*;
+
+pub mod msg;
+pub use msg::*;
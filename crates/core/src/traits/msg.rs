@@ -0,0 +1,85 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::internal::json_form::with_form;
+use crate::traits::{Any, JsonSer, MsgType, ProtoMsg};
+
+/// A transaction message, encodable as Amino JSON (`JsonSer::to_json`),
+/// protobuf JSON (`to_data`) and a packed protobuf `Any` (`to_any`).
+///
+/// Implemented for every type that derives `MsgType` and implements
+/// `ProtoMsg`.
+pub trait Msg: MsgType + JsonSer + ProtoMsg + DeserializeOwned {
+    /// Protobuf JSON form, as found in `terrad tx --generate-only` output.
+    fn to_data(&self) -> Result<Value, String> {
+        let value = with_form(true, || serde_json::to_value(self)).map_err(|e| e.to_string())?;
+        let mut data = Map::new();
+        data.insert("@type".into(), Value::String(Self::TYPE_URL.into()));
+        match value {
+            Value::Object(fields) => data.extend(fields),
+            _ => {
+                return Err(format!(
+                    "{} does not serialize to an object",
+                    Self::TYPE_URL
+                ))
+            }
+        }
+        Ok(Value::Object(data))
+    }
+
+    fn from_data(data: &Value) -> Result<Self, String> {
+        let mut fields = data
+            .as_object()
+            .cloned()
+            .ok_or_else(|| format!("expected object for {}", Self::TYPE_URL))?;
+        match fields.remove("@type") {
+            Some(Value::String(t)) if t == Self::TYPE_URL => {}
+            other => {
+                return Err(format!(
+                    "expected @type {}, got {:?}",
+                    Self::TYPE_URL,
+                    other
+                ))
+            }
+        }
+        serde_json::from_value(Value::Object(fields)).map_err(|e| e.to_string())
+    }
+
+    fn from_amino(json: &Value) -> Result<Self, String> {
+        match json.get("type") {
+            Some(Value::String(t)) if t == Self::AMINO_TYPE => {}
+            other => {
+                return Err(format!(
+                    "expected type {}, got {:?}",
+                    Self::AMINO_TYPE,
+                    other
+                ))
+            }
+        }
+        let value = json
+            .get("value")
+            .cloned()
+            .ok_or_else(|| format!("missing value for {}", Self::AMINO_TYPE))?;
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+
+    fn to_any(&self) -> Result<Any, String> {
+        Ok(Any {
+            type_url: Self::TYPE_URL.into(),
+            value: self.encode_proto()?,
+        })
+    }
+
+    fn from_any(any: &Any) -> Result<Self, String> {
+        if any.type_url != Self::TYPE_URL {
+            return Err(format!(
+                "expected type url {}, got {}",
+                Self::TYPE_URL,
+                any.type_url
+            ));
+        }
+        Self::decode_proto(&any.value)
+    }
+}
+
+impl<T> Msg for T where T: MsgType + JsonSer + ProtoMsg + DeserializeOwned {}
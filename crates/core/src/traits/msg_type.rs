@@ -6,3 +6,12 @@ pub trait JsonSer: Serialize {
 pub trait JsonDes<'de>: Deserialize<'de> {
     fn from_json(value: &Value) -> Result<Self, String>;
 }
+
+/// Names a message for both of its encodings. Usually implemented through
+/// `#[derive(MsgType)]`, which also provides the Amino `JsonSer` impl.
+pub trait MsgType {
+    /// Amino type name, e.g. `bank/MsgSend`.
+    const AMINO_TYPE: &'static str;
+    /// Protobuf type URL, e.g. `/cosmos.bank.v1beta1.MsgSend`.
+    const TYPE_URL: &'static str;
+}
@@ -0,0 +1,31 @@
+use prost::Message;
+
+/// Protobuf `google.protobuf.Any`, used to pack messages, public keys and
+/// other interface types.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Any {
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+/// Conversion between a type and its generated protobuf counterpart.
+pub trait ProtoMsg: Sized {
+    type Proto: Message + Default;
+
+    /// Fails for values that have no protobuf form, such as proposal
+    /// content of an unregistered type read from JSON.
+    fn to_proto(&self) -> Result<Self::Proto, String>;
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String>;
+
+    fn encode_proto(&self) -> Result<Vec<u8>, String> {
+        Ok(self.to_proto()?.encode_to_vec())
+    }
+
+    fn decode_proto(bytes: &[u8]) -> Result<Self, String> {
+        let proto = Self::Proto::decode(bytes).map_err(|e| format!("invalid protobuf: {}", e))?;
+        Self::from_proto(proto)
+    }
+}
@@ -1,2 +1,5 @@
-pub mod msgs;
-pub use msgs::*;
+pub mod params;
+pub use params::*;
+
+pub mod tax;
+pub use tax::*;
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Coin, Dec, Uint128};
+
+/// Bounds within which the treasury adjusts a policy rate each epoch.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct PolicyConstraints {
+    pub rate_min: Dec,
+    pub rate_max: Dec,
+    pub cap: Coin,
+    pub change_rate_max: Dec,
+}
+
+impl PolicyConstraints {
+    /// `rate` limited to `[rate_min, rate_max]` and to a change of at most
+    /// `change_rate_max` from `previous`, as the chain applies it.
+    pub fn clamp(&self, rate: Dec, previous: Dec) -> Dec {
+        let rate = rate.max(self.rate_min).min(self.rate_max);
+        let lower = previous - self.change_rate_max;
+        let upper = previous + self.change_rate_max;
+        rate.max(lower).min(upper)
+    }
+}
+
+/// Parameters of the treasury module.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct TreasuryParams {
+    pub tax_policy: PolicyConstraints,
+    pub reward_policy: PolicyConstraints,
+    pub seigniorage_burden_target: Dec,
+    pub mining_increment: Dec,
+    /// Epochs over which short-term rewards are averaged.
+    #[serde(with = "crate::internal::serde_str")]
+    pub window_short: u64,
+    #[serde(with = "crate::internal::serde_str")]
+    pub window_long: u64,
+    /// Epochs after genesis during which policy rates are not updated.
+    #[serde(with = "crate::internal::serde_str")]
+    pub window_probation: u64,
+    /// Share of the burn tax sent to the distribution module instead of
+    /// being burned. Only present on Terra classic.
+    #[serde(default = "Dec::zero")]
+    pub burn_tax_split: Dec,
+    #[serde(default = "Dec::zero")]
+    pub min_initial_deposit_ratio: Dec,
+}
+
+/// The chain's default parameters.
+impl Default for TreasuryParams {
+    fn default() -> Self {
+        Self {
+            tax_policy: PolicyConstraints {
+                rate_min: Dec::new(5 * 10i128.pow(14)),
+                rate_max: Dec::new(10i128.pow(16)),
+                cap: Coin::new("usdr", 1_000_000u128),
+                change_rate_max: Dec::new(25 * 10i128.pow(13)),
+            },
+            reward_policy: PolicyConstraints {
+                rate_min: Dec::new(5 * 10i128.pow(16)),
+                rate_max: Dec::new(9 * 10i128.pow(17)),
+                cap: Coin::new("unused", 0u128),
+                change_rate_max: Dec::new(25 * 10i128.pow(15)),
+            },
+            seigniorage_burden_target: Dec::new(67 * 10i128.pow(16)),
+            mining_increment: Dec::new(107 * 10i128.pow(16)),
+            window_short: 4,
+            window_long: 52,
+            window_probation: 18,
+            burn_tax_split: Dec::new(10i128.pow(17)),
+            min_initial_deposit_ratio: Dec::zero(),
+        }
+    }
+}
+
+/// The most stability tax charged per transfer of `denom`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct TaxCap {
+    pub denom: String,
+    pub tax_cap: Uint128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_parses_treasury_params() {
+        let params: TreasuryParams = serde_json::from_value(json!({
+            "tax_policy": {
+                "rate_min": "0.000500000000000000",
+                "rate_max": "0.010000000000000000",
+                "cap": { "denom": "usdr", "amount": "1000000" },
+                "change_rate_max": "0.000250000000000000"
+            },
+            "reward_policy": {
+                "rate_min": "0.050000000000000000",
+                "rate_max": "0.900000000000000000",
+                "cap": { "denom": "unused", "amount": "0" },
+                "change_rate_max": "0.025000000000000000"
+            },
+            "seigniorage_burden_target": "0.670000000000000000",
+            "mining_increment": "1.070000000000000000",
+            "window_short": "4",
+            "window_long": "52",
+            "window_probation": "18",
+            "burn_tax_split": "0.100000000000000000"
+        }))
+        .unwrap();
+        assert_eq!(params, TreasuryParams::default());
+
+        let policy = &params.tax_policy;
+        let previous = Dec::new(5 * 10i128.pow(15));
+        assert_eq!(
+            policy.clamp(Dec::new(2 * 10i128.pow(16)), previous),
+            Dec::new(525 * 10i128.pow(13))
+        );
+        assert_eq!(policy.clamp(Dec::zero(), policy.rate_min), policy.rate_min);
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::treasury::TaxCap;
+use crate::{authz, bank, market, wasm, Any, Coin, Coins, Dec, Msg, MsgType, Uint128};
+
+/// Which tax the chain charges on transfers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum TaxMode {
+    /// The original stability tax on Terra stablecoins; `uluna` is exempt.
+    #[default]
+    Stability,
+    /// Terra classic's burn tax, charged on every denom including `uluna`.
+    Burn,
+}
+
+/// Computes the tax the ante handler requires on top of gas fees for a
+/// transaction's messages. Each taxed coin pays `amount * rate`, truncated,
+/// and at most its denom's cap.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TaxCalculator {
+    pub rate: Dec,
+    pub caps: BTreeMap<String, Uint128>,
+    /// Cap of denoms missing from `caps`; uncapped if `None`.
+    pub default_cap: Option<Uint128>,
+    pub mode: TaxMode,
+}
+
+impl TaxCalculator {
+    /// A stability tax calculator for `rate` and the chain's `tax_caps`.
+    pub fn new(rate: Dec, caps: impl IntoIterator<Item = TaxCap>) -> Self {
+        Self {
+            rate,
+            caps: caps
+                .into_iter()
+                .map(|cap| (cap.denom, cap.tax_cap))
+                .collect(),
+            default_cap: None,
+            mode: TaxMode::Stability,
+        }
+    }
+
+    pub fn default_cap(mut self, cap: impl Into<Uint128>) -> Self {
+        self.default_cap = Some(cap.into());
+        self
+    }
+
+    pub fn mode(mut self, mode: TaxMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn cap(&self, denom: &str) -> Option<Uint128> {
+        self.caps.get(denom).copied().or(self.default_cap)
+    }
+
+    /// Tax due on transferring `coin`.
+    pub fn tax_on(&self, coin: &Coin) -> Uint128 {
+        if self.mode == TaxMode::Stability && coin.denom == "uluna" {
+            return Uint128::new(0u128);
+        }
+        let rate = self.rate.raw().max(0) as u128;
+        let one = Dec::one().raw() as u128;
+        let amount = coin.amount.u128();
+        // amount * rate / one without overflowing for large amounts.
+        let tax = (amount / one) * rate + (amount % one) * rate / one;
+        match self.cap(&coin.denom) {
+            Some(cap) => Uint128::new(tax.min(cap.u128())),
+            None => Uint128::new(tax),
+        }
+    }
+
+    /// Whether messages of this type are taxed. `MsgExec` is taxed on the
+    /// messages it wraps.
+    pub fn is_taxable(type_url: &str) -> bool {
+        [
+            bank::MsgSend::TYPE_URL,
+            bank::MsgMultiSend::TYPE_URL,
+            market::MsgSwapSend::TYPE_URL,
+            wasm::MsgInstantiateContract::<Value>::TYPE_URL,
+            wasm::MsgExecuteContract::<Value>::TYPE_URL,
+            wasm::wasmd::MsgInstantiateContract::<Value>::TYPE_URL,
+            wasm::wasmd::MsgExecuteContract::<Value>::TYPE_URL,
+            authz::MsgExec::TYPE_URL,
+        ]
+        .contains(&type_url)
+    }
+
+    /// Total tax due on `msgs`, to be added to the transaction's fee.
+    pub fn compute(&self, msgs: &[Any]) -> Result<Coins, String> {
+        let mut total: BTreeMap<String, u128> = BTreeMap::new();
+        for coin in taxed_coins(msgs)? {
+            let tax = self.tax_on(&coin).u128();
+            if tax > 0 {
+                *total.entry(coin.denom).or_default() += tax;
+            }
+        }
+        Ok(total
+            .into_iter()
+            .map(|(denom, amount)| Coin::new(denom, amount))
+            .collect())
+    }
+}
+
+/// The coins transferred by each taxable message, in order. Coins of
+/// different messages are taxed (and capped) separately.
+fn taxed_coins(msgs: &[Any]) -> Result<Vec<Coin>, String> {
+    let mut coins = vec![];
+    for any in msgs {
+        match any.type_url.as_str() {
+            bank::MsgSend::TYPE_URL => {
+                coins.extend(bank::MsgSend::from_any(any)?.amount);
+            }
+            bank::MsgMultiSend::TYPE_URL => {
+                for input in bank::MsgMultiSend::from_any(any)?.inputs {
+                    coins.extend(input.coins);
+                }
+            }
+            market::MsgSwapSend::TYPE_URL => {
+                coins.push(market::MsgSwapSend::from_any(any)?.offer_coin);
+            }
+            wasm::MsgInstantiateContract::<Value>::TYPE_URL => {
+                coins.extend(wasm::MsgInstantiateContract::<Value>::from_any(any)?.init_coins);
+            }
+            wasm::MsgExecuteContract::<Value>::TYPE_URL => {
+                coins.extend(wasm::MsgExecuteContract::<Value>::from_any(any)?.coins);
+            }
+            wasm::wasmd::MsgInstantiateContract::<Value>::TYPE_URL => {
+                coins.extend(wasm::wasmd::MsgInstantiateContract::<Value>::from_any(any)?.funds);
+            }
+            wasm::wasmd::MsgExecuteContract::<Value>::TYPE_URL => {
+                coins.extend(wasm::wasmd::MsgExecuteContract::<Value>::from_any(any)?.funds);
+            }
+            authz::MsgExec::TYPE_URL => {
+                coins.extend(taxed_coins(&authz::MsgExec::from_any(any)?.msgs)?);
+            }
+            _ => {}
+        }
+    }
+    Ok(coins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENDER: &str = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+    const RECIPIENT: &str = "terra1pdx498r0hrc2fj36sjhs8vuhrz9hd2cw0yhqtk";
+
+    fn send(amount: &str) -> Any {
+        bank::MsgSend::new(SENDER, RECIPIENT, amount)
+            .to_any()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_computes_stability_tax() {
+        let calculator = TaxCalculator::new(
+            Dec::new(5 * 10i128.pow(15)),
+            vec![TaxCap {
+                denom: "ukrw".into(),
+                tax_cap: Uint128::new(1000u128),
+            }],
+        )
+        .default_cap(1_000_000u128);
+
+        let msgs = vec![
+            send("1000000uusd,1000000uluna"),
+            send("1000000ukrw"),
+            send("1000000uusd"),
+        ];
+        assert_eq!(
+            calculator.compute(&msgs).unwrap(),
+            Coins::from("1000ukrw,10000uusd")
+        );
+        // Unknown messages and exempt denoms pay nothing.
+        assert_eq!(
+            calculator.compute(&[send("1000000uluna")]).unwrap(),
+            Coins::new()
+        );
+        // Truncated, not rounded.
+        assert_eq!(
+            calculator.compute(&[send("199uusd")]).unwrap(),
+            Coins::new()
+        );
+    }
+
+    #[test]
+    fn it_computes_burn_tax_through_exec() {
+        let calculator =
+            TaxCalculator::new(Dec::new(2 * 10i128.pow(15)), vec![]).mode(TaxMode::Burn);
+        let exec = authz::MsgExec::new(SENDER, vec![send("5000000uluna")])
+            .to_any()
+            .unwrap();
+        assert!(TaxCalculator::is_taxable(&exec.type_url));
+        assert_eq!(
+            calculator.compute(&[exec]).unwrap(),
+            Coins::from("10000uluna")
+        );
+    }
+}
@@ -0,0 +1,10 @@
+pub mod msgs;
+pub use msgs::*;
+
+pub mod plan;
+pub use plan::*;
+
+pub mod proposals;
+pub use proposals::*;
+
+pub mod proto;
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::upgrade::{proto, Plan};
+use crate::{AccAddress, ProtoMsg};
+
+/// Schedules `plan`. Since SDK 0.46 this replaces `SoftwareUpgradeProposal`
+/// and is executed by `authority`, normally the gov module account.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgSoftwareUpgrade")]
+#[msgtype_pb("/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade")]
+pub struct MsgSoftwareUpgrade {
+    pub authority: AccAddress,
+    pub plan: Plan,
+}
+
+impl MsgSoftwareUpgrade {
+    pub fn new(authority: impl Into<AccAddress>, plan: Plan) -> Self {
+        Self {
+            authority: authority.into(),
+            plan,
+        }
+    }
+}
+
+impl ProtoMsg for MsgSoftwareUpgrade {
+    type Proto = proto::MsgSoftwareUpgrade;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgSoftwareUpgrade {
+            authority: self.authority.to_string(),
+            plan: Some(self.plan.to_proto()?),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            authority: proto.authority.into(),
+            plan: Plan::from_proto(proto.plan.ok_or("missing plan")?)?,
+        })
+    }
+}
+
+/// Cancels the currently scheduled plan, if any.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("cosmos-sdk/MsgCancelUpgrade")]
+#[msgtype_pb("/cosmos.upgrade.v1beta1.MsgCancelUpgrade")]
+pub struct MsgCancelUpgrade {
+    pub authority: AccAddress,
+}
+
+impl MsgCancelUpgrade {
+    pub fn new(authority: impl Into<AccAddress>) -> Self {
+        Self {
+            authority: authority.into(),
+        }
+    }
+}
+
+impl ProtoMsg for MsgCancelUpgrade {
+    type Proto = proto::MsgCancelUpgrade;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgCancelUpgrade {
+            authority: self.authority.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            authority: proto.authority.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_software_upgrade() {
+        let msg = MsgSoftwareUpgrade::new(
+            "terra10d07y265gmmuvt4z0w9aw880jnsr700juxf95n",
+            Plan::new("v2.2.0", 3_000_000, ""),
+        );
+        assert_eq!(
+            msg.to_json().unwrap(),
+            json!({
+                "type": "cosmos-sdk/MsgSoftwareUpgrade",
+                "value": {
+                    "authority": "terra10d07y265gmmuvt4z0w9aw880jnsr700juxf95n",
+                    "plan": { "name": "v2.2.0", "height": "3000000", "info": "" }
+                }
+            })
+        );
+        assert_eq!(
+            MsgSoftwareUpgrade::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+
+        let msg = MsgCancelUpgrade::new("terra10d07y265gmmuvt4z0w9aw880jnsr700juxf95n");
+        assert_eq!(
+            MsgCancelUpgrade::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+    }
+}
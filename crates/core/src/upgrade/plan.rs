@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::upgrade::proto;
+use crate::ProtoMsg;
+
+/// A scheduled software upgrade. The chain halts at `height` until a binary
+/// that handles the upgrade `name` is running.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Plan {
+    pub name: String,
+    #[serde(with = "crate::internal::serde_str")]
+    pub height: i64,
+    #[serde(default)]
+    pub info: String,
+}
+
+impl Plan {
+    pub fn new(name: impl Into<String>, height: i64, info: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            height,
+            info: info.into(),
+        }
+    }
+
+    /// Blocks left before the chain halts for this plan, zero once `height`
+    /// has been reached.
+    pub fn blocks_until_halt(&self, current_height: i64) -> i64 {
+        (self.height - current_height).max(0)
+    }
+
+    /// Estimated halt time, assuming blocks keep coming every `block_time`.
+    pub fn estimated_halt_time(
+        &self,
+        current_height: i64,
+        now: DateTime<Utc>,
+        block_time: Duration,
+    ) -> DateTime<Utc> {
+        let blocks = u32::try_from(self.blocks_until_halt(current_height)).unwrap_or(u32::MAX);
+        chrono::Duration::from_std(block_time.saturating_mul(blocks))
+            .ok()
+            .and_then(|remaining| now.checked_add_signed(remaining))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Whether the halt height is at most `blocks` away.
+    pub fn is_within(&self, current_height: i64, blocks: i64) -> bool {
+        self.blocks_until_halt(current_height) <= blocks
+    }
+}
+
+impl ProtoMsg for Plan {
+    type Proto = proto::Plan;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::Plan {
+            name: self.name.clone(),
+            height: self.height,
+            info: self.info.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.name, proto.height, proto.info))
+    }
+}
+
+/// The consensus version of a module, as tracked for in-place migrations.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ModuleVersion {
+    pub name: String,
+    #[serde(with = "crate::internal::serde_str")]
+    pub version: u64,
+}
+
+impl ProtoMsg for ModuleVersion {
+    type Proto = proto::ModuleVersion;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::ModuleVersion {
+            name: self.name.clone(),
+            version: self.version,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            name: proto.name,
+            version: proto.version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_estimates_halt_time() {
+        let plan = Plan::new("v2.2.0", 3_000_000, "");
+        let now: DateTime<Utc> = "2023-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(plan.blocks_until_halt(2_999_400), 600);
+        assert!(plan.is_within(2_999_400, 1000));
+        assert!(!plan.is_within(2_990_000, 1000));
+        assert_eq!(
+            plan.estimated_halt_time(2_999_400, now, Duration::from_secs(6)),
+            "2023-01-01T01:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(plan.blocks_until_halt(3_000_001), 0);
+    }
+}
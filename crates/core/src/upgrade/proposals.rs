@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use terra_sdk_internal::MsgType;
+
+use crate::upgrade::{proto, Plan};
+use crate::ProtoMsg;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("upgrade/SoftwareUpgradeProposal")]
+#[msgtype_pb("/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal")]
+pub struct SoftwareUpgradeProposal {
+    pub title: String,
+    pub description: String,
+    pub plan: Plan,
+}
+
+impl ProtoMsg for SoftwareUpgradeProposal {
+    type Proto = proto::SoftwareUpgradeProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::SoftwareUpgradeProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            plan: Some(self.plan.to_proto()?),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            plan: Plan::from_proto(proto.plan.ok_or("missing plan")?)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("upgrade/CancelSoftwareUpgradeProposal")]
+#[msgtype_pb("/cosmos.upgrade.v1beta1.CancelSoftwareUpgradeProposal")]
+pub struct CancelSoftwareUpgradeProposal {
+    pub title: String,
+    pub description: String,
+}
+
+impl ProtoMsg for CancelSoftwareUpgradeProposal {
+    type Proto = proto::CancelSoftwareUpgradeProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::CancelSoftwareUpgradeProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+        })
+    }
+}
@@ -0,0 +1,53 @@
+//! Protobuf counterparts of the `cosmos.upgrade.v1beta1` types.
+
+use prost::Message;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Plan {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+    #[prost(string, tag = "4")]
+    pub info: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct SoftwareUpgradeProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(message, optional, tag = "3")]
+    pub plan: Option<Plan>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CancelSoftwareUpgradeProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgSoftwareUpgrade {
+    #[prost(string, tag = "1")]
+    pub authority: String,
+    #[prost(message, optional, tag = "2")]
+    pub plan: Option<Plan>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgCancelUpgrade {
+    #[prost(string, tag = "1")]
+    pub authority: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ModuleVersion {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(uint64, tag = "2")]
+    pub version: u64,
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::wasm::proto;
+use crate::{AccAddress, ProtoMsg};
+
+/// Who may instantiate a stored code.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AccessType {
+    Unspecified = 0,
+    Nobody = 1,
+    OnlyAddress = 2,
+    Everybody = 3,
+}
+
+impl AccessType {
+    fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(AccessType::Unspecified),
+            1 => Ok(AccessType::Nobody),
+            2 => Ok(AccessType::OnlyAddress),
+            3 => Ok(AccessType::Everybody),
+            other => Err(format!("invalid access type: {}", other)),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            AccessType::Unspecified => "Unspecified",
+            AccessType::Nobody => "Nobody",
+            AccessType::OnlyAddress => "OnlyAddress",
+            AccessType::Everybody => "Everybody",
+        }
+    }
+}
+
+impl Serialize for AccessType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Accepts both the Amino names (`OnlyAddress`) and the protobuf JSON names
+/// (`ACCESS_TYPE_ONLY_ADDRESS`).
+impl<'de> Deserialize<'de> for AccessType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "Unspecified" | "ACCESS_TYPE_UNSPECIFIED" => Ok(AccessType::Unspecified),
+            "Nobody" | "ACCESS_TYPE_NOBODY" => Ok(AccessType::Nobody),
+            "OnlyAddress" | "ACCESS_TYPE_ONLY_ADDRESS" => Ok(AccessType::OnlyAddress),
+            "Everybody" | "ACCESS_TYPE_EVERYBODY" => Ok(AccessType::Everybody),
+            other => Err(serde::de::Error::custom(format!(
+                "invalid access type: {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct AccessConfig {
+    pub permission: AccessType,
+    #[serde(default)]
+    pub address: String,
+}
+
+impl AccessConfig {
+    pub fn nobody() -> Self {
+        Self {
+            permission: AccessType::Nobody,
+            address: String::new(),
+        }
+    }
+
+    pub fn everybody() -> Self {
+        Self {
+            permission: AccessType::Everybody,
+            address: String::new(),
+        }
+    }
+
+    pub fn only_address(address: impl Into<AccAddress>) -> Self {
+        Self {
+            permission: AccessType::OnlyAddress,
+            address: address.into().to_string(),
+        }
+    }
+}
+
+impl ProtoMsg for AccessConfig {
+    type Proto = proto::AccessConfig;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::AccessConfig {
+            permission: self.permission as i32,
+            address: self.address.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            permission: AccessType::from_i32(proto.permission)?,
+            address: proto.address,
+        })
+    }
+}
@@ -0,0 +1,305 @@
+//! Contract events in transaction results.
+//!
+//! Contracts emit attributes on a `wasm` event and custom `wasm-<type>`
+//! events. Chains on Tendermint 0.34 merge same-typed events of a message
+//! in its log, so one `wasm` event may hold the attributes of several
+//! contracts; each `_contract_address` (`contract_address` on Terra
+//! classic) starts those of the next one.
+
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::auth::{Attribute, Event, TxResponse};
+use crate::AccAddress;
+
+const CONTRACT_ADDRESS_KEYS: [&str; 2] = ["_contract_address", "contract_address"];
+
+/// The attributes one contract emitted on a `wasm` or `wasm-*` event.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WasmEvent {
+    pub kind: String,
+    pub contract_address: AccAddress,
+    /// Index of the message that emitted the event, if the chain reports
+    /// it.
+    pub msg_index: Option<u32>,
+    /// Attributes other than the contract address and message index.
+    pub attributes: Vec<Attribute>,
+}
+
+impl WasmEvent {
+    /// `transfer` for a `wasm-transfer` event; `None` for `wasm`.
+    pub fn custom_type(&self) -> Option<&str> {
+        self.kind.strip_prefix("wasm-")
+    }
+
+    /// Value of the first attribute named `key`.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.as_str())
+    }
+
+    /// Deserializes the attributes as a JSON object of strings, e.g. into
+    /// a struct with one field per attribute. The first of repeated keys is
+    /// used.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, String> {
+        let mut object = Map::new();
+        for attribute in &self.attributes {
+            object
+                .entry(attribute.key.clone())
+                .or_insert_with(|| Value::String(attribute.value.clone()));
+        }
+        serde_json::from_value(Value::Object(object))
+            .map_err(|e| format!("invalid {} event: {}", self.kind, e))
+    }
+}
+
+/// A contract created by the transaction.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InstantiatedContract {
+    pub code_id: u64,
+    pub contract_address: AccAddress,
+}
+
+fn is_wasm_event(kind: &str) -> bool {
+    kind == "wasm" || kind.starts_with("wasm-")
+}
+
+fn split_wasm_event(event: &Event, msg_index: Option<u32>) -> Vec<WasmEvent> {
+    // Events of newer chains carry their own message index.
+    let msg_index = event
+        .attribute("msg_index")
+        .and_then(|index| index.parse().ok())
+        .or(msg_index);
+    let mut events: Vec<WasmEvent> = vec![];
+    for attribute in &event.attributes {
+        if CONTRACT_ADDRESS_KEYS.contains(&attribute.key.as_str()) {
+            events.push(WasmEvent {
+                kind: event.kind.clone(),
+                contract_address: AccAddress::unchecked(attribute.value.clone()),
+                msg_index,
+                attributes: vec![],
+            });
+        } else if attribute.key != "msg_index" {
+            // Attributes before any contract address cannot be attributed.
+            if let Some(current) = events.last_mut() {
+                current.attributes.push(attribute.clone());
+            }
+        }
+    }
+    events
+}
+
+impl TxResponse {
+    /// Events whose type matches, with the index of the message emitting
+    /// them where known.
+    fn indexed_events(&self, matches: impl Fn(&str) -> bool) -> Vec<(&Event, Option<u32>)> {
+        if self.logs.is_empty() {
+            self.events
+                .iter()
+                .filter(|e| matches(&e.kind))
+                .map(|e| (e, None))
+                .collect()
+        } else {
+            self.logs
+                .iter()
+                .flat_map(|log| log.events.iter().map(|e| (e, Some(log.msg_index))))
+                .filter(|(e, _)| matches(&e.kind))
+                .collect()
+        }
+    }
+
+    /// All contract events, in order.
+    pub fn wasm_events(&self) -> Vec<WasmEvent> {
+        self.indexed_events(is_wasm_event)
+            .into_iter()
+            .flat_map(|(event, msg_index)| split_wasm_event(event, msg_index))
+            .collect()
+    }
+
+    /// Contract events grouped by the contract emitting them.
+    pub fn wasm_events_by_contract(&self) -> BTreeMap<AccAddress, Vec<WasmEvent>> {
+        let mut grouped: BTreeMap<AccAddress, Vec<WasmEvent>> = BTreeMap::new();
+        for event in self.wasm_events() {
+            grouped
+                .entry(event.contract_address.clone())
+                .or_default()
+                .push(event);
+        }
+        grouped
+    }
+
+    /// Contract events grouped by the index of the message emitting them.
+    /// Events of unknown index are left out.
+    pub fn wasm_events_by_msg(&self) -> BTreeMap<u32, Vec<WasmEvent>> {
+        let mut grouped: BTreeMap<u32, Vec<WasmEvent>> = BTreeMap::new();
+        for event in self.wasm_events() {
+            if let Some(index) = event.msg_index {
+                grouped.entry(index).or_default().push(event);
+            }
+        }
+        grouped
+    }
+
+    /// Parses every `wasm-<custom_type>` event into `T`.
+    pub fn parse_wasm_events<T: DeserializeOwned>(
+        &self,
+        custom_type: &str,
+    ) -> Result<Vec<T>, String> {
+        self.wasm_events()
+            .iter()
+            .filter(|e| e.custom_type() == Some(custom_type))
+            .map(WasmEvent::parse)
+            .collect()
+    }
+
+    /// Contracts created by the transaction, from `instantiate` events
+    /// (`instantiate_contract` on Terra classic).
+    pub fn instantiated_contracts(&self) -> Result<Vec<InstantiatedContract>, String> {
+        let mut contracts = vec![];
+        let is_instantiate = |kind: &str| kind == "instantiate" || kind == "instantiate_contract";
+        for (event, _) in self.indexed_events(is_instantiate) {
+            let code_ids = event.attributes_named("code_id");
+            let addresses = event.attributes.iter().filter_map(|a| {
+                CONTRACT_ADDRESS_KEYS
+                    .contains(&a.key.as_str())
+                    .then_some(a.value.as_str())
+            });
+            for (code_id, address) in code_ids.zip(addresses) {
+                contracts.push(InstantiatedContract {
+                    code_id: code_id
+                        .parse()
+                        .map_err(|_| format!("invalid code_id: {}", code_id))?,
+                    contract_address: AccAddress::unchecked(address),
+                });
+            }
+        }
+        Ok(contracts)
+    }
+
+    /// Ids of the codes stored by the transaction, from `store_code`
+    /// events.
+    pub fn stored_code_ids(&self) -> Result<Vec<u64>, String> {
+        self.indexed_events(|kind| kind == "store_code")
+            .into_iter()
+            .flat_map(|(event, _)| event.attributes_named("code_id"))
+            .map(|code_id| {
+                code_id
+                    .parse()
+                    .map_err(|_| format!("invalid code_id: {}", code_id))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    const TOKEN: &str = "terra1token";
+    const PAIR: &str = "terra1pair";
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Transfer {
+        from: String,
+        to: String,
+        #[serde(with = "crate::internal::serde_str")]
+        amount: u128,
+    }
+
+    fn response() -> TxResponse {
+        serde_json::from_value(json!({
+            "height": "10",
+            "txhash": "AA",
+            "logs": [
+                {
+                    "msg_index": 0,
+                    "events": [
+                        { "type": "instantiate", "attributes": [
+                            { "key": "_contract_address", "value": PAIR },
+                            { "key": "code_id", "value": "7" }
+                        ] },
+                        { "type": "store_code", "attributes": [{ "key": "code_id", "value": "7" }] }
+                    ]
+                },
+                {
+                    "msg_index": 1,
+                    "events": [
+                        { "type": "wasm", "attributes": [
+                            { "key": "_contract_address", "value": PAIR },
+                            { "key": "action", "value": "swap" },
+                            { "key": "_contract_address", "value": TOKEN },
+                            { "key": "action", "value": "transfer" }
+                        ] },
+                        { "type": "wasm-transfer", "attributes": [
+                            { "key": "_contract_address", "value": TOKEN },
+                            { "key": "from", "value": PAIR },
+                            { "key": "to", "value": "terra1user" },
+                            { "key": "amount", "value": "100" }
+                        ] }
+                    ]
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_groups_wasm_events() {
+        let response = response();
+        let events = response.wasm_events();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].contract_address.to_string(), PAIR);
+        assert_eq!(events[0].attribute("action"), Some("swap"));
+        assert_eq!(events[1].attribute("action"), Some("transfer"));
+        assert_eq!(events[2].custom_type(), Some("transfer"));
+
+        let by_contract = response.wasm_events_by_contract();
+        assert_eq!(by_contract[&AccAddress::unchecked(TOKEN)].len(), 2);
+        assert_eq!(by_contract[&AccAddress::unchecked(PAIR)].len(), 1);
+        let by_msg = response.wasm_events_by_msg();
+        assert_eq!(by_msg.keys().collect::<Vec<_>>(), vec![&1]);
+
+        assert_eq!(
+            response.parse_wasm_events::<Transfer>("transfer").unwrap(),
+            vec![Transfer {
+                from: PAIR.into(),
+                to: "terra1user".into(),
+                amount: 100
+            }]
+        );
+        assert_eq!(
+            response.instantiated_contracts().unwrap(),
+            vec![InstantiatedContract {
+                code_id: 7,
+                contract_address: AccAddress::unchecked(PAIR)
+            }]
+        );
+        assert_eq!(response.stored_code_ids().unwrap(), vec![7]);
+    }
+
+    #[test]
+    fn it_reads_msg_index_from_events() {
+        let response: TxResponse = serde_json::from_value(json!({
+            "height": "10",
+            "txhash": "AA",
+            "logs": [],
+            "events": [
+                { "type": "wasm", "attributes": [
+                    { "key": "_contract_address", "value": TOKEN },
+                    { "key": "action", "value": "burn" },
+                    { "key": "msg_index", "value": "2" }
+                ] }
+            ]
+        }))
+        .unwrap();
+        let events = response.wasm_events();
+        assert_eq!(events[0].msg_index, Some(2));
+        assert_eq!(events[0].attributes.len(), 1);
+    }
+}
@@ -0,0 +1,81 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
+
+use crate::AccAddress;
+
+/// Pre-serialized contract message JSON, sent exactly as given. Use it as the
+/// message type parameter when the message is built outside of Rust types.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct RawJson(Box<RawValue>);
+
+impl RawJson {
+    pub fn new(json: impl Into<String>) -> Result<Self, String> {
+        RawValue::from_string(json.into())
+            .map(RawJson)
+            .map_err(|e| format!("invalid JSON: {}", e))
+    }
+
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+}
+
+impl PartialEq for RawJson {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for RawJson {}
+
+/// Contract messages travel as raw JSON bytes in protobuf.
+pub(crate) fn to_json_bytes<T: Serialize>(msg: &T) -> Result<Vec<u8>, String> {
+    serde_json::to_vec(msg).map_err(|e| format!("contract msg is not valid JSON: {}", e))
+}
+
+pub(crate) fn from_json_bytes<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+    serde_json::from_slice(bytes).map_err(|e| format!("invalid contract msg: {}", e))
+}
+
+/// Protobuf encodes a missing address as an empty string.
+pub(crate) fn optional_address(address: String) -> Option<AccAddress> {
+    if address.is_empty() {
+        None
+    } else {
+        Some(address.into())
+    }
+}
+
+pub(crate) fn optional_address_to_string(address: &Option<AccAddress>) -> String {
+    address.as_ref().map(|a| a.to_string()).unwrap_or_default()
+}
+
+/// Deserializes a missing, `null` or empty address as `None`.
+pub(crate) fn deserialize_optional_address<'de, D>(
+    deserializer: D,
+) -> Result<Option<AccAddress>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let address = Option::<String>::deserialize(deserializer)?;
+    Ok(address.and_then(optional_address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_keeps_raw_json_verbatim() {
+        let raw = RawJson::new(r#"{"transfer": {"amount":"10"}}"#).unwrap();
+        assert_eq!(
+            String::from_utf8(to_json_bytes(&raw).unwrap()).unwrap(),
+            r#"{"transfer": {"amount":"10"}}"#
+        );
+        let back: RawJson = from_json_bytes(&to_json_bytes(&raw).unwrap()).unwrap();
+        assert_eq!(back, raw);
+        assert!(RawJson::new("{").is_err());
+    }
+}
@@ -1,2 +1,21 @@
+pub mod access;
+pub use access::*;
+
+pub mod events;
+pub use events::*;
+
+pub mod json;
+pub use json::RawJson;
+
 pub mod msgs;
 pub use msgs::*;
+
+pub mod proposals;
+pub use proposals::*;
+
+pub mod proto;
+
+pub mod upload;
+pub use upload::*;
+
+pub mod wasmd;
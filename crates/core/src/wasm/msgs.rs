@@ -1,48 +1,429 @@
-use crate::{AccAddress, Coins};
+//! Terra classic (`terra.wasm.v1beta1`) contract messages. See
+//! [`crate::wasm::wasmd`] for the `cosmwasm.wasm.v1` shapes.
+//!
+//! Contract messages are generic over any `T: Serialize`, defaulting to a
+//! `serde_json::Value`; use [`RawJson`](crate::wasm::RawJson) to send
+//! pre-serialized JSON as is.
 
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use terra_sdk_internal::MsgType;
+
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::wasm::json::{
+    deserialize_optional_address, from_json_bytes, optional_address, optional_address_to_string,
+    to_json_bytes,
+};
+use crate::wasm::proto::terra as proto;
+use crate::{AccAddress, Coins, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgStoreCode")]
+#[msgtype_pb("/terra.wasm.v1beta1.MsgStoreCode")]
 pub struct MsgStoreCode {
     pub sender: AccAddress,
-    pub wasm_byte_code: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub wasm_byte_code: Vec<u8>,
+}
+
+impl MsgStoreCode {
+    pub fn new(sender: impl Into<AccAddress>, wasm_byte_code: Vec<u8>) -> Self {
+        Self {
+            sender: sender.into(),
+            wasm_byte_code,
+        }
+    }
+}
+
+impl ProtoMsg for MsgStoreCode {
+    type Proto = proto::MsgStoreCode;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgStoreCode {
+            sender: self.sender.to_string(),
+            wasm_byte_code: self.wasm_byte_code.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self::new(proto.sender, proto.wasm_byte_code))
+    }
 }
 
+/// Replaces the bytecode of `code_id`. Only allowed for codes stored before
+/// Columbus-5 by their original uploader.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgMigrateCode")]
+#[msgtype_pb("/terra.wasm.v1beta1.MsgMigrateCode")]
 pub struct MsgMigrateCode {
     pub sender: AccAddress,
+    #[serde(with = "crate::internal::serde_str")]
     pub code_id: u64,
-    pub wasm_byte_code: String,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub wasm_byte_code: Vec<u8>,
 }
 
-pub struct MsgInstantiateContract {
+impl ProtoMsg for MsgMigrateCode {
+    type Proto = proto::MsgMigrateCode;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgMigrateCode {
+            code_id: self.code_id,
+            sender: self.sender.to_string(),
+            wasm_byte_code: self.wasm_byte_code.clone(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            code_id: proto.code_id,
+            wasm_byte_code: proto.wasm_byte_code,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgInstantiateContract")]
+#[msgtype_pb("/terra.wasm.v1beta1.MsgInstantiateContract")]
+pub struct MsgInstantiateContract<T = Value> {
     pub sender: AccAddress,
-    pub admin: AccAddress,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_address"
+    )]
+    pub admin: Option<AccAddress>,
+    #[serde(with = "crate::internal::serde_str")]
     pub code_id: u64,
-    /// TODO: type
-    pub init_msg: String,
+    pub init_msg: T,
     pub init_coins: Coins,
 }
 
-pub struct MsgExecuteContract {
+impl<T> MsgInstantiateContract<T> {
+    pub fn new(
+        sender: impl Into<AccAddress>,
+        code_id: u64,
+        init_msg: T,
+        init_coins: impl Into<Coins>,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            admin: None,
+            code_id,
+            init_msg,
+            init_coins: init_coins.into(),
+        }
+    }
+
+    /// Sets the account allowed to migrate the contract.
+    pub fn admin(mut self, admin: impl Into<AccAddress>) -> Self {
+        self.admin = Some(admin.into());
+        self
+    }
+
+    /// Like `new`, but fails if `init_msg` does not serialize to JSON.
+    pub fn try_new(
+        sender: impl Into<AccAddress>,
+        code_id: u64,
+        init_msg: T,
+        init_coins: impl Into<Coins>,
+    ) -> Result<Self, String>
+    where
+        T: Serialize,
+    {
+        let msg = Self::new(sender, code_id, init_msg, init_coins);
+        to_json_bytes(&msg.init_msg)?;
+        Ok(msg)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ProtoMsg for MsgInstantiateContract<T> {
+    type Proto = proto::MsgInstantiateContract;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgInstantiateContract {
+            sender: self.sender.to_string(),
+            admin: optional_address_to_string(&self.admin),
+            code_id: self.code_id,
+            init_msg: to_json_bytes(&self.init_msg)?,
+            init_coins: coins_to_proto(&self.init_coins),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            admin: optional_address(proto.admin),
+            code_id: proto.code_id,
+            init_msg: from_json_bytes(&proto.init_msg)?,
+            init_coins: coins_from_proto(proto.init_coins)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgExecuteContract")]
+#[msgtype_pb("/terra.wasm.v1beta1.MsgExecuteContract")]
+pub struct MsgExecuteContract<T = Value> {
     pub sender: AccAddress,
     pub contract: AccAddress,
-    /// TODO: type
-    pub execute_msg: String,
+    pub execute_msg: T,
     pub coins: Coins,
 }
 
-pub struct MsgMigrateContract {
+impl<T> MsgExecuteContract<T> {
+    pub fn new(
+        sender: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        execute_msg: T,
+        coins: impl Into<Coins>,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            contract: contract.into(),
+            execute_msg,
+            coins: coins.into(),
+        }
+    }
+
+    /// Like `new`, but fails if `execute_msg` does not serialize to JSON.
+    pub fn try_new(
+        sender: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        execute_msg: T,
+        coins: impl Into<Coins>,
+    ) -> Result<Self, String>
+    where
+        T: Serialize,
+    {
+        let msg = Self::new(sender, contract, execute_msg, coins);
+        to_json_bytes(&msg.execute_msg)?;
+        Ok(msg)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ProtoMsg for MsgExecuteContract<T> {
+    type Proto = proto::MsgExecuteContract;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgExecuteContract {
+            sender: self.sender.to_string(),
+            contract: self.contract.to_string(),
+            execute_msg: to_json_bytes(&self.execute_msg)?,
+            coins: coins_to_proto(&self.coins),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            contract: proto.contract.into(),
+            execute_msg: from_json_bytes(&proto.execute_msg)?,
+            coins: coins_from_proto(proto.coins)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgMigrateContract")]
+#[msgtype_pb("/terra.wasm.v1beta1.MsgMigrateContract")]
+pub struct MsgMigrateContract<T = Value> {
     pub admin: AccAddress,
     pub contract: AccAddress,
+    #[serde(with = "crate::internal::serde_str")]
     pub new_code_id: u64,
-    /// TODO: type
-    pub migrate_msg: String,
+    pub migrate_msg: T,
 }
 
-pub struct MsgClearContractAdmin {
-    pub admin: AccAddress,
-    pub contract: AccAddress,
+impl<T> MsgMigrateContract<T> {
+    pub fn new(
+        admin: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        new_code_id: u64,
+        migrate_msg: T,
+    ) -> Self {
+        Self {
+            admin: admin.into(),
+            contract: contract.into(),
+            new_code_id,
+            migrate_msg,
+        }
+    }
+
+    /// Like `new`, but fails if `migrate_msg` does not serialize to JSON.
+    pub fn try_new(
+        admin: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        new_code_id: u64,
+        migrate_msg: T,
+    ) -> Result<Self, String>
+    where
+        T: Serialize,
+    {
+        let msg = Self::new(admin, contract, new_code_id, migrate_msg);
+        to_json_bytes(&msg.migrate_msg)?;
+        Ok(msg)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ProtoMsg for MsgMigrateContract<T> {
+    type Proto = proto::MsgMigrateContract;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgMigrateContract {
+            admin: self.admin.to_string(),
+            contract: self.contract.to_string(),
+            new_code_id: self.new_code_id,
+            migrate_msg: to_json_bytes(&self.migrate_msg)?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            admin: proto.admin.into(),
+            contract: proto.contract.into(),
+            new_code_id: proto.new_code_id,
+            migrate_msg: from_json_bytes(&proto.migrate_msg)?,
+        })
+    }
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgUpdateContractAdmin")]
+#[msgtype_pb("/terra.wasm.v1beta1.MsgUpdateContractAdmin")]
 pub struct MsgUpdateContractAdmin {
     pub admin: AccAddress,
     pub new_admin: AccAddress,
     pub contract: AccAddress,
 }
+
+impl ProtoMsg for MsgUpdateContractAdmin {
+    type Proto = proto::MsgUpdateContractAdmin;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgUpdateContractAdmin {
+            admin: self.admin.to_string(),
+            new_admin: self.new_admin.to_string(),
+            contract: self.contract.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            admin: proto.admin.into(),
+            new_admin: proto.new_admin.into(),
+            contract: proto.contract.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgClearContractAdmin")]
+#[msgtype_pb("/terra.wasm.v1beta1.MsgClearContractAdmin")]
+pub struct MsgClearContractAdmin {
+    pub admin: AccAddress,
+    pub contract: AccAddress,
+}
+
+impl ProtoMsg for MsgClearContractAdmin {
+    type Proto = proto::MsgClearContractAdmin;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgClearContractAdmin {
+            admin: self.admin.to_string(),
+            contract: self.contract.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            admin: proto.admin.into(),
+            contract: proto.contract.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::RawJson;
+    use crate::{JsonSer, Msg};
+    use serde_json::json;
+
+    const SENDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+    const CONTRACT: &str = "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4";
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        Transfer { recipient: String, amount: String },
+    }
+
+    #[test]
+    fn it_encodes_typed_execute_msg() {
+        let msg = MsgExecuteContract::new(
+            SENDER,
+            CONTRACT,
+            ExecuteMsg::Transfer {
+                recipient: SENDER.into(),
+                amount: "10".into(),
+            },
+            Coins::new(),
+        );
+        let json = msg.to_json().unwrap();
+        assert_eq!(json["type"], "wasm/MsgExecuteContract");
+        assert_eq!(
+            json["value"]["execute_msg"],
+            json!({ "transfer": { "recipient": SENDER, "amount": "10" } })
+        );
+        let any = msg.to_any().unwrap();
+        assert_eq!(any.type_url, "/terra.wasm.v1beta1.MsgExecuteContract");
+        assert_eq!(
+            MsgExecuteContract::<ExecuteMsg>::from_any(&any).unwrap(),
+            msg
+        );
+
+        let untyped = MsgExecuteContract::<Value>::from_any(&any).unwrap();
+        assert_eq!(untyped.execute_msg["transfer"]["amount"], "10");
+    }
+
+    #[test]
+    fn it_encodes_instantiate_with_raw_json() {
+        let raw = RawJson::new(r#"{"count":1}"#).unwrap();
+        let msg = MsgInstantiateContract::new(SENDER, 7, raw, "100uluna");
+        let json = msg.to_json().unwrap();
+        assert!(json["value"].get("admin").is_none());
+        assert_eq!(json["value"]["code_id"], "7");
+        assert_eq!(json["value"]["init_msg"], json!({ "count": 1 }));
+        assert_eq!(msg.to_proto().unwrap().init_msg, br#"{"count":1}"#.to_vec());
+
+        let msg = msg.admin(SENDER);
+        let decoded = MsgInstantiateContract::<RawJson>::from_any(&msg.to_any().unwrap()).unwrap();
+        assert_eq!(decoded, msg);
+        let amino: MsgInstantiateContract = MsgInstantiateContract::from_amino(&json).unwrap();
+        assert_eq!(amino.admin, None);
+    }
+
+    #[test]
+    fn it_rejects_msgs_that_are_not_json() {
+        // JSON object keys must be strings.
+        let msg = std::collections::BTreeMap::from([(vec![1u8], 1u8)]);
+        assert!(MsgExecuteContract::try_new(SENDER, CONTRACT, msg.clone(), Coins::new()).is_err());
+        assert!(MsgExecuteContract::try_new(SENDER, CONTRACT, json!({}), Coins::new()).is_ok());
+        // The fields are public, so packing checks again.
+        let msg = MsgExecuteContract::new(SENDER, CONTRACT, msg, Coins::new());
+        assert!(msg.to_any().is_err());
+    }
+
+    #[test]
+    fn it_encodes_bytecode_as_base64() {
+        let msg = MsgStoreCode::new(SENDER, vec![0x00, 0x61, 0x73, 0x6d]);
+        assert_eq!(
+            msg.to_json().unwrap()["value"]["wasm_byte_code"],
+            "AGFzbQ=="
+        );
+        assert_eq!(MsgStoreCode::from_any(&msg.to_any().unwrap()).unwrap(), msg);
+    }
+}
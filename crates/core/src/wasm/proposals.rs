@@ -0,0 +1,393 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use terra_sdk_internal::MsgType;
+
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::wasm::json::{from_json_bytes, optional_address, to_json_bytes};
+use crate::wasm::{proto, AccessConfig};
+use crate::{AccAddress, Coins, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/StoreCodeProposal")]
+#[msgtype_pb("/cosmwasm.wasm.v1.StoreCodeProposal")]
+pub struct StoreCodeProposal {
+    pub title: String,
+    pub description: String,
+    pub run_as: AccAddress,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub wasm_byte_code: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instantiate_permission: Option<AccessConfig>,
+}
+
+impl ProtoMsg for StoreCodeProposal {
+    type Proto = proto::StoreCodeProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::StoreCodeProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            run_as: self.run_as.to_string(),
+            wasm_byte_code: self.wasm_byte_code.clone(),
+            instantiate_permission: self
+                .instantiate_permission
+                .as_ref()
+                .map(ProtoMsg::to_proto)
+                .transpose()?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            run_as: proto.run_as.into(),
+            wasm_byte_code: proto.wasm_byte_code,
+            instantiate_permission: proto
+                .instantiate_permission
+                .map(AccessConfig::from_proto)
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/InstantiateContractProposal")]
+#[msgtype_pb("/cosmwasm.wasm.v1.InstantiateContractProposal")]
+pub struct InstantiateContractProposal {
+    pub title: String,
+    pub description: String,
+    pub run_as: AccAddress,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<AccAddress>,
+    #[serde(with = "crate::internal::serde_str")]
+    pub code_id: u64,
+    pub label: String,
+    pub msg: Value,
+    pub funds: Coins,
+}
+
+impl ProtoMsg for InstantiateContractProposal {
+    type Proto = proto::InstantiateContractProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::InstantiateContractProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            run_as: self.run_as.to_string(),
+            admin: self
+                .admin
+                .as_ref()
+                .map(|a| a.to_string())
+                .unwrap_or_default(),
+            code_id: self.code_id,
+            label: self.label.clone(),
+            msg: to_json_bytes(&self.msg)?,
+            funds: coins_to_proto(&self.funds),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            run_as: proto.run_as.into(),
+            admin: optional_address(proto.admin),
+            code_id: proto.code_id,
+            label: proto.label,
+            msg: from_json_bytes(&proto.msg)?,
+            funds: coins_from_proto(proto.funds)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MigrateContractProposal")]
+#[msgtype_pb("/cosmwasm.wasm.v1.MigrateContractProposal")]
+pub struct MigrateContractProposal {
+    pub title: String,
+    pub description: String,
+    pub contract: AccAddress,
+    #[serde(with = "crate::internal::serde_str")]
+    pub code_id: u64,
+    pub msg: Value,
+}
+
+impl ProtoMsg for MigrateContractProposal {
+    type Proto = proto::MigrateContractProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MigrateContractProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            contract: self.contract.to_string(),
+            code_id: self.code_id,
+            msg: to_json_bytes(&self.msg)?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            contract: proto.contract.into(),
+            code_id: proto.code_id,
+            msg: from_json_bytes(&proto.msg)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/SudoContractProposal")]
+#[msgtype_pb("/cosmwasm.wasm.v1.SudoContractProposal")]
+pub struct SudoContractProposal {
+    pub title: String,
+    pub description: String,
+    pub contract: AccAddress,
+    pub msg: Value,
+}
+
+impl ProtoMsg for SudoContractProposal {
+    type Proto = proto::SudoContractProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::SudoContractProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            contract: self.contract.to_string(),
+            msg: to_json_bytes(&self.msg)?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            contract: proto.contract.into(),
+            msg: from_json_bytes(&proto.msg)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/ExecuteContractProposal")]
+#[msgtype_pb("/cosmwasm.wasm.v1.ExecuteContractProposal")]
+pub struct ExecuteContractProposal {
+    pub title: String,
+    pub description: String,
+    pub run_as: AccAddress,
+    pub contract: AccAddress,
+    pub msg: Value,
+    pub funds: Coins,
+}
+
+impl ProtoMsg for ExecuteContractProposal {
+    type Proto = proto::ExecuteContractProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::ExecuteContractProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            run_as: self.run_as.to_string(),
+            contract: self.contract.to_string(),
+            msg: to_json_bytes(&self.msg)?,
+            funds: coins_to_proto(&self.funds),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            run_as: proto.run_as.into(),
+            contract: proto.contract.into(),
+            msg: from_json_bytes(&proto.msg)?,
+            funds: coins_from_proto(proto.funds)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/UpdateAdminProposal")]
+#[msgtype_pb("/cosmwasm.wasm.v1.UpdateAdminProposal")]
+pub struct UpdateAdminProposal {
+    pub title: String,
+    pub description: String,
+    pub new_admin: AccAddress,
+    pub contract: AccAddress,
+}
+
+impl ProtoMsg for UpdateAdminProposal {
+    type Proto = proto::UpdateAdminProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::UpdateAdminProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            new_admin: self.new_admin.to_string(),
+            contract: self.contract.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            new_admin: proto.new_admin.into(),
+            contract: proto.contract.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/ClearAdminProposal")]
+#[msgtype_pb("/cosmwasm.wasm.v1.ClearAdminProposal")]
+pub struct ClearAdminProposal {
+    pub title: String,
+    pub description: String,
+    pub contract: AccAddress,
+}
+
+impl ProtoMsg for ClearAdminProposal {
+    type Proto = proto::ClearAdminProposal;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::ClearAdminProposal {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            contract: self.contract.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            title: proto.title,
+            description: proto.description,
+            contract: proto.contract.into(),
+        })
+    }
+}
+
+macro_rules! codes_proposal {
+    ($name:ident, $amino:literal, $type_url:literal) => {
+        #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+        #[msgtype($amino)]
+        #[msgtype_pb($type_url)]
+        pub struct $name {
+            pub title: String,
+            pub description: String,
+            #[serde(with = "crate::internal::serde_str::vec")]
+            pub code_ids: Vec<u64>,
+        }
+
+        impl ProtoMsg for $name {
+            type Proto = proto::CodesProposal;
+
+            fn to_proto(&self) -> Result<Self::Proto, String> {
+                Ok(proto::CodesProposal {
+                    title: self.title.clone(),
+                    description: self.description.clone(),
+                    code_ids: self.code_ids.clone(),
+                })
+            }
+
+            fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+                Ok(Self {
+                    title: proto.title,
+                    description: proto.description,
+                    code_ids: proto.code_ids,
+                })
+            }
+        }
+    };
+}
+
+codes_proposal!(
+    PinCodesProposal,
+    "wasm/PinCodesProposal",
+    "/cosmwasm.wasm.v1.PinCodesProposal"
+);
+codes_proposal!(
+    UnpinCodesProposal,
+    "wasm/UnpinCodesProposal",
+    "/cosmwasm.wasm.v1.UnpinCodesProposal"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+    use serde_json::json;
+
+    #[test]
+    fn it_round_trips_instantiate_proposal() {
+        let proposal = InstantiateContractProposal {
+            title: "cw20".into(),
+            description: "instantiate a token".into(),
+            run_as: "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw".into(),
+            admin: None,
+            code_id: 3,
+            label: "token".into(),
+            msg: json!({ "name": "Token", "decimals": 6 }),
+            funds: Coins::new(),
+        };
+        let json = proposal.to_json().unwrap();
+        assert_eq!(json["value"]["code_id"], "3");
+        assert!(json["value"].get("admin").is_none());
+
+        let any = proposal.to_any().unwrap();
+        assert_eq!(
+            any.type_url,
+            "/cosmwasm.wasm.v1.InstantiateContractProposal"
+        );
+        assert_eq!(
+            InstantiateContractProposal::from_any(&any).unwrap(),
+            proposal
+        );
+    }
+
+    #[test]
+    fn it_round_trips_store_code_proposal() {
+        let proposal = StoreCodeProposal {
+            title: "store".into(),
+            description: "store code".into(),
+            run_as: "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw".into(),
+            wasm_byte_code: vec![0x00, 0x61, 0x73, 0x6d],
+            instantiate_permission: Some(AccessConfig::everybody()),
+        };
+        let json = proposal.to_json().unwrap();
+        assert_eq!(json["value"]["wasm_byte_code"], "AGFzbQ==");
+        assert_eq!(
+            json["value"]["instantiate_permission"]["permission"],
+            "Everybody"
+        );
+        assert_eq!(StoreCodeProposal::from_amino(&json).unwrap(), proposal);
+        assert_eq!(
+            StoreCodeProposal::from_any(&proposal.to_any().unwrap()).unwrap(),
+            proposal
+        );
+
+        let pin = PinCodesProposal {
+            title: "pin".into(),
+            description: "pin codes".into(),
+            code_ids: vec![1, 2],
+        };
+        assert_eq!(
+            pin.to_json().unwrap()["value"]["code_ids"],
+            json!(["1", "2"])
+        );
+    }
+
+    #[test]
+    fn it_reads_proto_json_access_type() {
+        let config: AccessConfig = serde_json::from_value(json!({
+            "permission": "ACCESS_TYPE_ONLY_ADDRESS",
+            "address": "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw"
+        }))
+        .unwrap();
+        assert_eq!(
+            config,
+            AccessConfig::only_address("terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw")
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Protobuf counterparts of the `cosmwasm.wasm.v1` (wasmd) types.
+
+use prost::Message;
+
+use crate::sdk::proto::Coin;
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct AccessConfig {
+    #[prost(int32, tag = "1")]
+    pub permission: i32,
+    #[prost(string, tag = "2")]
+    pub address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct StoreCodeProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub run_as: String,
+    #[prost(bytes = "vec", tag = "4")]
+    pub wasm_byte_code: Vec<u8>,
+    #[prost(message, optional, tag = "7")]
+    pub instantiate_permission: Option<AccessConfig>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct InstantiateContractProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub run_as: String,
+    #[prost(string, tag = "4")]
+    pub admin: String,
+    #[prost(uint64, tag = "5")]
+    pub code_id: u64,
+    #[prost(string, tag = "6")]
+    pub label: String,
+    #[prost(bytes = "vec", tag = "7")]
+    pub msg: Vec<u8>,
+    #[prost(message, repeated, tag = "8")]
+    pub funds: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MigrateContractProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "4")]
+    pub contract: String,
+    #[prost(uint64, tag = "5")]
+    pub code_id: u64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub msg: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct SudoContractProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub contract: String,
+    #[prost(bytes = "vec", tag = "4")]
+    pub msg: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ExecuteContractProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub run_as: String,
+    #[prost(string, tag = "4")]
+    pub contract: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub msg: Vec<u8>,
+    #[prost(message, repeated, tag = "6")]
+    pub funds: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct UpdateAdminProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub new_admin: String,
+    #[prost(string, tag = "4")]
+    pub contract: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ClearAdminProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub contract: String,
+}
+
+/// Shared by `PinCodesProposal` and `UnpinCodesProposal`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CodesProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(uint64, repeated, tag = "3")]
+    pub code_ids: Vec<u64>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgStoreCode {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub wasm_byte_code: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub instantiate_permission: Option<AccessConfig>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgInstantiateContract {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub admin: String,
+    #[prost(uint64, tag = "3")]
+    pub code_id: u64,
+    #[prost(string, tag = "4")]
+    pub label: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub msg: Vec<u8>,
+    #[prost(message, repeated, tag = "6")]
+    pub funds: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgExecuteContract {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub contract: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub msg: Vec<u8>,
+    #[prost(message, repeated, tag = "5")]
+    pub funds: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgMigrateContract {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub contract: String,
+    #[prost(uint64, tag = "3")]
+    pub code_id: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub msg: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgUpdateAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub new_admin: String,
+    #[prost(string, tag = "3")]
+    pub contract: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct MsgClearAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "3")]
+    pub contract: String,
+}
+
+/// Protobuf counterparts of the Terra classic `terra.wasm.v1beta1` messages.
+pub mod terra {
+    use prost::Message;
+
+    use crate::sdk::proto::Coin;
+
+    #[derive(Clone, PartialEq, Eq, Message)]
+    pub struct MsgStoreCode {
+        #[prost(string, tag = "1")]
+        pub sender: String,
+        #[prost(bytes = "vec", tag = "2")]
+        pub wasm_byte_code: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Message)]
+    pub struct MsgMigrateCode {
+        #[prost(uint64, tag = "1")]
+        pub code_id: u64,
+        #[prost(string, tag = "2")]
+        pub sender: String,
+        #[prost(bytes = "vec", tag = "3")]
+        pub wasm_byte_code: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Message)]
+    pub struct MsgInstantiateContract {
+        #[prost(string, tag = "1")]
+        pub sender: String,
+        #[prost(string, tag = "2")]
+        pub admin: String,
+        #[prost(uint64, tag = "3")]
+        pub code_id: u64,
+        #[prost(bytes = "vec", tag = "4")]
+        pub init_msg: Vec<u8>,
+        #[prost(message, repeated, tag = "5")]
+        pub init_coins: Vec<Coin>,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Message)]
+    pub struct MsgExecuteContract {
+        #[prost(string, tag = "1")]
+        pub sender: String,
+        #[prost(string, tag = "2")]
+        pub contract: String,
+        #[prost(bytes = "vec", tag = "3")]
+        pub execute_msg: Vec<u8>,
+        #[prost(message, repeated, tag = "5")]
+        pub coins: Vec<Coin>,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Message)]
+    pub struct MsgMigrateContract {
+        #[prost(string, tag = "1")]
+        pub admin: String,
+        #[prost(string, tag = "2")]
+        pub contract: String,
+        #[prost(uint64, tag = "3")]
+        pub new_code_id: u64,
+        #[prost(bytes = "vec", tag = "4")]
+        pub migrate_msg: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Message)]
+    pub struct MsgUpdateContractAdmin {
+        #[prost(string, tag = "1")]
+        pub admin: String,
+        #[prost(string, tag = "2")]
+        pub new_admin: String,
+        #[prost(string, tag = "3")]
+        pub contract: String,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Message)]
+    pub struct MsgClearContractAdmin {
+        #[prost(string, tag = "1")]
+        pub admin: String,
+        #[prost(string, tag = "2")]
+        pub contract: String,
+    }
+}
@@ -0,0 +1,227 @@
+use std::io::{Read, Write};
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+
+use crate::auth::Event;
+use crate::wasm::{msgs, wasmd, AccessConfig};
+use crate::AccAddress;
+
+/// Leading bytes of every wasm module.
+pub const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+
+/// Leading bytes of a gzip stream.
+pub const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Largest (compressed) bytecode accepted in a `MsgStoreCode`.
+pub const MAX_WASM_SIZE: usize = 800 * 1024;
+
+/// Largest module accepted once uncompressed, which also bounds how much
+/// a gzipped upload may expand to.
+pub const MAX_UNCOMPRESSED_WASM_SIZE: usize = 3 * 1024 * 1024;
+
+/// Contract bytecode prepared for upload: gzip-compressed, with the SHA-256
+/// checksum of the uncompressed module that the chain reports as the code
+/// hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmCode {
+    compressed: Vec<u8>,
+    checksum: [u8; 32],
+}
+
+impl WasmCode {
+    /// Reads a `.wasm` file, compressed or not.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let bytes =
+            std::fs::read(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        Self::from_bytes(bytes)
+    }
+
+    /// Accepts a raw wasm module or an already gzipped one.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
+        let (wasm, compressed) = if bytes.starts_with(&GZIP_MAGIC) {
+            let mut wasm = vec![];
+            GzDecoder::new(bytes.as_slice())
+                .take(MAX_UNCOMPRESSED_WASM_SIZE as u64 + 1)
+                .read_to_end(&mut wasm)
+                .map_err(|e| format!("invalid gzip data: {}", e))?;
+            (wasm, bytes)
+        } else {
+            let compressed = gzip(&bytes)?;
+            (bytes, compressed)
+        };
+
+        if wasm.len() > MAX_UNCOMPRESSED_WASM_SIZE {
+            return Err(format!(
+                "uncompressed bytecode exceeds the limit of {} bytes",
+                MAX_UNCOMPRESSED_WASM_SIZE
+            ));
+        }
+        if !wasm.starts_with(&WASM_MAGIC) {
+            return Err("not a wasm module: missing \\0asm header".into());
+        }
+        if compressed.len() > MAX_WASM_SIZE {
+            return Err(format!(
+                "compressed bytecode is {} bytes, limit is {}",
+                compressed.len(),
+                MAX_WASM_SIZE
+            ));
+        }
+        Ok(Self {
+            compressed,
+            checksum: Sha256::digest(&wasm).into(),
+        })
+    }
+
+    /// Gzipped bytecode, as sent in `wasm_byte_code`.
+    pub fn bytecode(&self) -> &[u8] {
+        &self.compressed
+    }
+
+    pub fn checksum(&self) -> &[u8; 32] {
+        &self.checksum
+    }
+
+    /// Hex checksum, as shown by `code_hash`/`data_hash` queries.
+    pub fn checksum_hex(&self) -> String {
+        hex::encode(self.checksum)
+    }
+
+    /// A wasmd `MsgStoreCode`.
+    pub fn store_code(
+        &self,
+        sender: impl Into<AccAddress>,
+        instantiate_permission: Option<AccessConfig>,
+    ) -> wasmd::MsgStoreCode {
+        wasmd::MsgStoreCode {
+            sender: sender.into(),
+            wasm_byte_code: self.compressed.clone(),
+            instantiate_permission,
+        }
+    }
+
+    /// A Terra classic `MsgStoreCode`, which has no instantiate permission.
+    pub fn store_code_classic(&self, sender: impl Into<AccAddress>) -> msgs::MsgStoreCode {
+        msgs::MsgStoreCode::new(sender, self.compressed.clone())
+    }
+
+    /// The `code_id` assigned to this code, read from the `store_code`
+    /// event. Fails if the event reports a different checksum.
+    pub fn code_id_from_events(&self, events: &[Event]) -> Result<u64, String> {
+        let event = store_code_event(events)?;
+        if let Some(checksum) = event.attribute("code_checksum") {
+            if !checksum.eq_ignore_ascii_case(&self.checksum_hex()) {
+                return Err(format!(
+                    "stored code checksum {} does not match {}",
+                    checksum,
+                    self.checksum_hex()
+                ));
+            }
+        }
+        parse_code_id(event)
+    }
+}
+
+fn gzip(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(bytes)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| format!("failed to compress bytecode: {}", e))
+}
+
+fn store_code_event(events: &[Event]) -> Result<&Event, String> {
+    events
+        .iter()
+        .find(|e| e.kind == "store_code")
+        .ok_or_else(|| "no store_code event".to_string())
+}
+
+fn parse_code_id(event: &Event) -> Result<u64, String> {
+    let code_id = event
+        .attribute("code_id")
+        .ok_or("store_code event has no code_id")?;
+    code_id
+        .parse()
+        .map_err(|_| format!("invalid code_id: {}", code_id))
+}
+
+/// The `code_id` from the `store_code` event of a `MsgStoreCode`.
+pub fn code_id_from_events(events: &[Event]) -> Result<u64, String> {
+    parse_code_id(store_code_event(events)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::Attribute;
+    use crate::Msg;
+
+    const SENDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+
+    fn module() -> Vec<u8> {
+        let mut wasm = WASM_MAGIC.to_vec();
+        wasm.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+        wasm.resize(wasm.len() + 4096, 0);
+        wasm
+    }
+
+    fn event(attributes: &[(&str, &str)]) -> Vec<Event> {
+        vec![Event {
+            kind: "store_code".into(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| Attribute {
+                    key: k.to_string(),
+                    value: v.to_string(),
+                })
+                .collect(),
+        }]
+    }
+
+    #[test]
+    fn it_compresses_and_checksums() {
+        let code = WasmCode::from_bytes(module()).unwrap();
+        assert!(code.bytecode().starts_with(&GZIP_MAGIC));
+        assert!(code.bytecode().len() < module().len());
+        assert_eq!(code.checksum_hex(), hex::encode(Sha256::digest(module())));
+
+        let again = WasmCode::from_bytes(code.bytecode().to_vec()).unwrap();
+        assert_eq!(again.checksum(), code.checksum());
+
+        assert!(WasmCode::from_bytes(b"not wasm".to_vec()).is_err());
+    }
+
+    #[test]
+    fn it_rejects_oversized_modules() {
+        let mut wasm = module();
+        wasm.resize(MAX_UNCOMPRESSED_WASM_SIZE + 1, 0);
+        let bomb = gzip(&wasm).unwrap();
+        assert!(bomb.len() < MAX_WASM_SIZE);
+        assert!(WasmCode::from_bytes(bomb).is_err());
+        assert!(WasmCode::from_bytes(wasm).is_err());
+    }
+
+    #[test]
+    fn it_builds_store_code_and_reads_code_id() {
+        let code = WasmCode::from_bytes(module()).unwrap();
+        let msg = code.store_code(SENDER, Some(AccessConfig::nobody()));
+        assert_eq!(
+            wasmd::MsgStoreCode::from_any(&msg.to_any().unwrap()).unwrap(),
+            msg
+        );
+
+        let checksum = code.checksum_hex();
+        let events = event(&[("code_checksum", &checksum), ("code_id", "42")]);
+        assert_eq!(code.code_id_from_events(&events).unwrap(), 42);
+        assert_eq!(code_id_from_events(&event(&[("code_id", "7")])).unwrap(), 7);
+
+        let wrong = event(&[("code_checksum", "00"), ("code_id", "42")]);
+        assert!(code.code_id_from_events(&wrong).is_err());
+        assert!(code_id_from_events(&[]).is_err());
+    }
+}
@@ -0,0 +1,358 @@
+//! wasmd (`cosmwasm.wasm.v1`) contract messages, as used by Terra 2 and
+//! other CosmWasm chains.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use terra_sdk_internal::MsgType;
+
+use crate::sdk::proto::{coins_from_proto, coins_to_proto};
+use crate::wasm::json::{
+    deserialize_optional_address, from_json_bytes, optional_address, optional_address_to_string,
+    to_json_bytes,
+};
+use crate::wasm::{proto, AccessConfig};
+use crate::{AccAddress, Coins, ProtoMsg};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgStoreCode")]
+#[msgtype_pb("/cosmwasm.wasm.v1.MsgStoreCode")]
+pub struct MsgStoreCode {
+    pub sender: AccAddress,
+    #[serde(with = "crate::internal::serde_base64")]
+    pub wasm_byte_code: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instantiate_permission: Option<AccessConfig>,
+}
+
+impl MsgStoreCode {
+    pub fn new(sender: impl Into<AccAddress>, wasm_byte_code: Vec<u8>) -> Self {
+        Self {
+            sender: sender.into(),
+            wasm_byte_code,
+            instantiate_permission: None,
+        }
+    }
+
+    pub fn instantiate_permission(mut self, permission: AccessConfig) -> Self {
+        self.instantiate_permission = Some(permission);
+        self
+    }
+}
+
+impl ProtoMsg for MsgStoreCode {
+    type Proto = proto::MsgStoreCode;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgStoreCode {
+            sender: self.sender.to_string(),
+            wasm_byte_code: self.wasm_byte_code.clone(),
+            instantiate_permission: self
+                .instantiate_permission
+                .as_ref()
+                .map(ProtoMsg::to_proto)
+                .transpose()?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            wasm_byte_code: proto.wasm_byte_code,
+            instantiate_permission: proto
+                .instantiate_permission
+                .map(AccessConfig::from_proto)
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgInstantiateContract")]
+#[msgtype_pb("/cosmwasm.wasm.v1.MsgInstantiateContract")]
+pub struct MsgInstantiateContract<T = Value> {
+    pub sender: AccAddress,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_address"
+    )]
+    pub admin: Option<AccAddress>,
+    #[serde(with = "crate::internal::serde_str")]
+    pub code_id: u64,
+    pub label: String,
+    pub msg: T,
+    pub funds: Coins,
+}
+
+impl<T> MsgInstantiateContract<T> {
+    pub fn new(
+        sender: impl Into<AccAddress>,
+        code_id: u64,
+        label: impl Into<String>,
+        msg: T,
+        funds: impl Into<Coins>,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            admin: None,
+            code_id,
+            label: label.into(),
+            msg,
+            funds: funds.into(),
+        }
+    }
+
+    /// Sets the account allowed to migrate the contract.
+    pub fn admin(mut self, admin: impl Into<AccAddress>) -> Self {
+        self.admin = Some(admin.into());
+        self
+    }
+
+    /// Like `new`, but fails if `msg` does not serialize to JSON.
+    pub fn try_new(
+        sender: impl Into<AccAddress>,
+        code_id: u64,
+        label: impl Into<String>,
+        msg: T,
+        funds: impl Into<Coins>,
+    ) -> Result<Self, String>
+    where
+        T: Serialize,
+    {
+        let msg = Self::new(sender, code_id, label, msg, funds);
+        to_json_bytes(&msg.msg)?;
+        Ok(msg)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ProtoMsg for MsgInstantiateContract<T> {
+    type Proto = proto::MsgInstantiateContract;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgInstantiateContract {
+            sender: self.sender.to_string(),
+            admin: optional_address_to_string(&self.admin),
+            code_id: self.code_id,
+            label: self.label.clone(),
+            msg: to_json_bytes(&self.msg)?,
+            funds: coins_to_proto(&self.funds),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            admin: optional_address(proto.admin),
+            code_id: proto.code_id,
+            label: proto.label,
+            msg: from_json_bytes(&proto.msg)?,
+            funds: coins_from_proto(proto.funds)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgExecuteContract")]
+#[msgtype_pb("/cosmwasm.wasm.v1.MsgExecuteContract")]
+pub struct MsgExecuteContract<T = Value> {
+    pub sender: AccAddress,
+    pub contract: AccAddress,
+    pub msg: T,
+    pub funds: Coins,
+}
+
+impl<T> MsgExecuteContract<T> {
+    pub fn new(
+        sender: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        msg: T,
+        funds: impl Into<Coins>,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            contract: contract.into(),
+            msg,
+            funds: funds.into(),
+        }
+    }
+
+    /// Like `new`, but fails if `msg` does not serialize to JSON.
+    pub fn try_new(
+        sender: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        msg: T,
+        funds: impl Into<Coins>,
+    ) -> Result<Self, String>
+    where
+        T: Serialize,
+    {
+        let msg = Self::new(sender, contract, msg, funds);
+        to_json_bytes(&msg.msg)?;
+        Ok(msg)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ProtoMsg for MsgExecuteContract<T> {
+    type Proto = proto::MsgExecuteContract;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgExecuteContract {
+            sender: self.sender.to_string(),
+            contract: self.contract.to_string(),
+            msg: to_json_bytes(&self.msg)?,
+            funds: coins_to_proto(&self.funds),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            contract: proto.contract.into(),
+            msg: from_json_bytes(&proto.msg)?,
+            funds: coins_from_proto(proto.funds)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgMigrateContract")]
+#[msgtype_pb("/cosmwasm.wasm.v1.MsgMigrateContract")]
+pub struct MsgMigrateContract<T = Value> {
+    pub sender: AccAddress,
+    pub contract: AccAddress,
+    #[serde(with = "crate::internal::serde_str")]
+    pub code_id: u64,
+    pub msg: T,
+}
+
+impl<T> MsgMigrateContract<T> {
+    pub fn new(
+        sender: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        code_id: u64,
+        msg: T,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            contract: contract.into(),
+            code_id,
+            msg,
+        }
+    }
+
+    /// Like `new`, but fails if `msg` does not serialize to JSON.
+    pub fn try_new(
+        sender: impl Into<AccAddress>,
+        contract: impl Into<AccAddress>,
+        code_id: u64,
+        msg: T,
+    ) -> Result<Self, String>
+    where
+        T: Serialize,
+    {
+        let msg = Self::new(sender, contract, code_id, msg);
+        to_json_bytes(&msg.msg)?;
+        Ok(msg)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ProtoMsg for MsgMigrateContract<T> {
+    type Proto = proto::MsgMigrateContract;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgMigrateContract {
+            sender: self.sender.to_string(),
+            contract: self.contract.to_string(),
+            code_id: self.code_id,
+            msg: to_json_bytes(&self.msg)?,
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            contract: proto.contract.into(),
+            code_id: proto.code_id,
+            msg: from_json_bytes(&proto.msg)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgUpdateAdmin")]
+#[msgtype_pb("/cosmwasm.wasm.v1.MsgUpdateAdmin")]
+pub struct MsgUpdateAdmin {
+    pub sender: AccAddress,
+    pub new_admin: AccAddress,
+    pub contract: AccAddress,
+}
+
+impl ProtoMsg for MsgUpdateAdmin {
+    type Proto = proto::MsgUpdateAdmin;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgUpdateAdmin {
+            sender: self.sender.to_string(),
+            new_admin: self.new_admin.to_string(),
+            contract: self.contract.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            new_admin: proto.new_admin.into(),
+            contract: proto.contract.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, MsgType)]
+#[msgtype("wasm/MsgClearAdmin")]
+#[msgtype_pb("/cosmwasm.wasm.v1.MsgClearAdmin")]
+pub struct MsgClearAdmin {
+    pub sender: AccAddress,
+    pub contract: AccAddress,
+}
+
+impl ProtoMsg for MsgClearAdmin {
+    type Proto = proto::MsgClearAdmin;
+
+    fn to_proto(&self) -> Result<Self::Proto, String> {
+        Ok(proto::MsgClearAdmin {
+            sender: self.sender.to_string(),
+            contract: self.contract.to_string(),
+        })
+    }
+
+    fn from_proto(proto: Self::Proto) -> Result<Self, String> {
+        Ok(Self {
+            sender: proto.sender.into(),
+            contract: proto.contract.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonSer, Msg};
+    use serde_json::json;
+
+    const SENDER: &str = "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw";
+
+    #[test]
+    fn it_encodes_wasmd_instantiate() {
+        let msg = MsgInstantiateContract::new(SENDER, 1, "counter", json!({ "count": 0 }), "")
+            .admin(SENDER);
+        let json = msg.to_json().unwrap();
+        assert_eq!(json["value"]["label"], "counter");
+        assert_eq!(json["value"]["admin"], SENDER);
+        assert_eq!(json["value"]["msg"], json!({ "count": 0 }));
+
+        let any = msg.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmwasm.wasm.v1.MsgInstantiateContract");
+        assert_eq!(MsgInstantiateContract::from_any(&any).unwrap(), msg);
+    }
+}
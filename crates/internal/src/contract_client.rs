@@ -0,0 +1,561 @@
+use std::path::{Path, PathBuf};
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use serde_json::{Map, Value};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, LitStr, Path as SynPath, Token, Visibility};
+
+/// `contract_client!(krate; pub Name, "schema/dir")`, where `krate` is the
+/// path of the crate re-exporting the runtime dependencies.
+struct ContractClientInput {
+    krate: SynPath,
+    vis: Visibility,
+    name: Ident,
+    schema_dir: LitStr,
+}
+
+impl Parse for ContractClientInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let krate = input.parse()?;
+        input.parse::<Token![;]>()?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let schema_dir = input.parse()?;
+        Ok(Self {
+            krate,
+            vis,
+            name,
+            schema_dir,
+        })
+    }
+}
+
+/// One `ExecuteMsg` or `QueryMsg` variant.
+struct Variant {
+    name: String,
+    description: Option<String>,
+    /// `None` for unit variants, serialized as a bare string.
+    fields: Option<Vec<Field>>,
+}
+
+struct Field {
+    name: String,
+    ty: FieldType,
+    optional: bool,
+}
+
+enum FieldType {
+    String,
+    Bool,
+    Int(&'static str),
+    Uint128,
+    Decimal,
+    Vec(Box<FieldType>),
+    Json,
+}
+
+/// Schemas of a contract: either the separate `execute_msg.json` and
+/// `query_msg.json` files, or a single combined file with `execute` and
+/// `query` keys as written by newer `cosmwasm-schema` versions.
+struct ContractSchema {
+    files: Vec<PathBuf>,
+    execute: Option<Value>,
+    query: Option<Value>,
+}
+
+fn read_json(path: &Path) -> Result<Value, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&content).map_err(|e| format!("invalid JSON in {}: {}", path.display(), e))
+}
+
+fn load_schema(dir: &Path) -> Result<ContractSchema, String> {
+    let mut schema = ContractSchema {
+        files: vec![],
+        execute: None,
+        query: None,
+    };
+    let mut entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("failed to read schema directory {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    for path in entries {
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let json = read_json(&path)?;
+        if json.get("contract_name").is_some() {
+            schema.execute = json.get("execute").cloned().filter(|v| !v.is_null());
+            schema.query = json.get("query").cloned().filter(|v| !v.is_null());
+            schema.files.push(path);
+            continue;
+        }
+        match stem.as_str() {
+            "execute_msg" | "execute" | "handle_msg" => schema.execute = Some(json),
+            "query_msg" | "query" => schema.query = Some(json),
+            _ => continue,
+        }
+        schema.files.push(path);
+    }
+
+    if schema.execute.is_none() && schema.query.is_none() {
+        return Err(format!(
+            "no execute or query schema found in {}",
+            dir.display()
+        ));
+    }
+    Ok(schema)
+}
+
+fn resolve<'a>(schema: &'a Value, root: &'a Value) -> &'a Value {
+    let reference = schema
+        .get("$ref")
+        .or_else(|| {
+            schema
+                .get("allOf")
+                .and_then(Value::as_array)
+                .filter(|all| all.len() == 1)
+                .and_then(|all| all[0].get("$ref"))
+        })
+        .and_then(Value::as_str);
+    match reference.and_then(|r| r.rsplit('/').next()) {
+        Some(name) => root
+            .get("definitions")
+            .or_else(|| root.get("$defs"))
+            .and_then(|defs| defs.get(name))
+            .unwrap_or(schema),
+        None => schema,
+    }
+}
+
+fn ref_name(schema: &Value) -> Option<&str> {
+    schema
+        .get("$ref")
+        .or_else(|| {
+            schema
+                .get("allOf")
+                .and_then(Value::as_array)
+                .and_then(|all| all.first())
+                .and_then(|first| first.get("$ref"))
+        })
+        .and_then(Value::as_str)
+        .and_then(|r| r.rsplit('/').next())
+}
+
+fn is_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+/// Maps a property schema to a Rust type; the flag is set when the schema
+/// itself allows `null`.
+fn field_type(schema: &Value, root: &Value) -> (FieldType, bool) {
+    if let Some(any_of) = schema.get("anyOf").and_then(Value::as_array) {
+        let non_null = any_of.iter().filter(|s| !is_null(s)).collect::<Vec<_>>();
+        let nullable = non_null.len() < any_of.len();
+        if non_null.len() == 1 {
+            return (field_type(non_null[0], root).0, nullable);
+        }
+        return (FieldType::Json, nullable);
+    }
+
+    match ref_name(schema) {
+        Some("Uint128") | Some("Uint64") => return (FieldType::Uint128, false),
+        Some("Decimal") => return (FieldType::Decimal, false),
+        Some(_) => {
+            let resolved = resolve(schema, root);
+            if resolved.get("type").and_then(Value::as_str) == Some("string")
+                && resolved.get("enum").is_none()
+            {
+                return (FieldType::String, false);
+            }
+            return (FieldType::Json, false);
+        }
+        None => {}
+    }
+
+    let (ty, nullable) = match schema.get("type") {
+        Some(Value::String(ty)) => (ty.as_str(), false),
+        Some(Value::Array(types)) => {
+            let non_null = types
+                .iter()
+                .filter_map(Value::as_str)
+                .filter(|t| *t != "null")
+                .collect::<Vec<_>>();
+            if non_null.len() != 1 {
+                return (FieldType::Json, true);
+            }
+            (non_null[0], non_null.len() < types.len())
+        }
+        _ => return (FieldType::Json, false),
+    };
+
+    let field = match ty {
+        "string" if schema.get("enum").is_none() => FieldType::String,
+        "boolean" => FieldType::Bool,
+        "integer" => FieldType::Int(match schema.get("format").and_then(Value::as_str) {
+            Some("uint8") => "u8",
+            Some("uint16") => "u16",
+            Some("uint32") => "u32",
+            Some("uint64") => "u64",
+            Some("int8") => "i8",
+            Some("int16") => "i16",
+            Some("int32") => "i32",
+            _ => "i64",
+        }),
+        "array" => match schema.get("items") {
+            Some(items) if items.is_object() => {
+                let (item, nullable) = field_type(items, root);
+                if nullable {
+                    FieldType::Json
+                } else {
+                    FieldType::Vec(Box::new(item))
+                }
+            }
+            _ => FieldType::Json,
+        },
+        _ => FieldType::Json,
+    };
+    (field, nullable)
+}
+
+fn object_fields(schema: &Value, root: &Value) -> Option<Vec<Field>> {
+    let schema = resolve(schema, root);
+    let properties = schema.get("properties").and_then(Value::as_object)?;
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+        .unwrap_or_default();
+    Some(
+        properties
+            .iter()
+            .map(|(name, property)| {
+                let (ty, nullable) = field_type(property, root);
+                Field {
+                    name: name.clone(),
+                    ty,
+                    optional: nullable || !required.contains(&name.as_str()),
+                }
+            })
+            .collect(),
+    )
+}
+
+fn description(schema: &Value) -> Option<String> {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+fn variants(root: &Value) -> Result<Vec<Variant>, String> {
+    let one_of = root
+        .get("oneOf")
+        .or_else(|| root.get("anyOf"))
+        .and_then(Value::as_array)
+        .ok_or("message schema has no oneOf")?;
+    let mut variants = vec![];
+    for schema in one_of {
+        if let Some(names) = schema.get("enum").and_then(Value::as_array) {
+            for name in names.iter().filter_map(Value::as_str) {
+                variants.push(Variant {
+                    name: name.to_string(),
+                    description: description(schema),
+                    fields: None,
+                });
+            }
+            continue;
+        }
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_else(Map::new);
+        let (name, inner) = properties
+            .into_iter()
+            .next()
+            .ok_or("message variant has no properties")?;
+        variants.push(Variant {
+            name,
+            description: description(schema),
+            fields: Some(object_fields(&inner, root).unwrap_or_default()),
+        });
+    }
+    Ok(variants)
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+    "unsized", "virtual", "yield",
+];
+
+/// Keywords that cannot be raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Names used by the generated methods themselves, so that no message
+/// field can shadow them.
+const RESERVED: &[&str] = &["__sender", "__funds", "__api", "__fields", "__msg"];
+
+/// A valid identifier for a schema name: other characters become `_`,
+/// leading digits get a `_` prefix and keywords are escaped.
+fn ident(name: &str) -> Ident {
+    let mut sanitized = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    if sanitized.is_empty() || sanitized == "_" {
+        sanitized = "__".into();
+    }
+    if NON_RAW_KEYWORDS.contains(&sanitized.as_str()) {
+        sanitized.push('_');
+    }
+    if KEYWORDS.contains(&sanitized.as_str()) {
+        Ident::new_raw(&sanitized, Span::call_site())
+    } else {
+        Ident::new(&sanitized, Span::call_site())
+    }
+}
+
+/// `prefix_name`, which needs no escaping.
+fn prefixed_ident(prefix: &str, name: &str) -> Ident {
+    let ident = ident(name).to_string();
+    format_ident!("{}_{}", prefix, ident.trim_start_matches("r#"))
+}
+
+/// Paths to the runtime dependencies of the generated code.
+struct Paths {
+    krate: TokenStream2,
+    core: TokenStream2,
+    json: TokenStream2,
+}
+
+fn rust_type(ty: &FieldType, paths: &Paths) -> TokenStream2 {
+    let Paths { core, json, .. } = paths;
+    match ty {
+        FieldType::String => quote! { ::std::string::String },
+        FieldType::Bool => quote! { bool },
+        FieldType::Int(int) => {
+            let int = format_ident!("{}", int);
+            quote! { #int }
+        }
+        FieldType::Uint128 => quote! { #core::Uint128 },
+        FieldType::Decimal => quote! { #core::Dec },
+        FieldType::Vec(item) => {
+            let item = rust_type(item, paths);
+            quote! { ::std::vec::Vec<#item> }
+        }
+        FieldType::Json => quote! { #json::Value },
+    }
+}
+
+/// Method parameters and the JSON message they build.
+fn params_and_body(
+    variant: &Variant,
+    paths: &Paths,
+) -> Result<(Vec<TokenStream2>, TokenStream2), String> {
+    let name = &variant.name;
+    let json = &paths.json;
+    match &variant.fields {
+        None => Ok((vec![], quote! { #json::Value::String(#name.to_string()) })),
+        Some(fields) => {
+            let mut params = vec![];
+            let mut inserts = vec![];
+            let mut idents = RESERVED.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+            for field in fields {
+                let ident = ident(&field.name);
+                if idents.contains(&ident.to_string()) {
+                    return Err(format!(
+                        "field `{}` of `{}` clashes with another parameter as `{}`",
+                        field.name, name, ident
+                    ));
+                }
+                idents.push(ident.to_string());
+                let key = &field.name;
+                let ty = rust_type(&field.ty, paths);
+                let param_ty = match (&field.ty, field.optional) {
+                    (FieldType::String, false) => {
+                        quote! { impl ::std::convert::Into<::std::string::String> }
+                    }
+                    (_, false) => ty,
+                    (_, true) => quote! { ::std::option::Option<#ty> },
+                };
+                let value = match (&field.ty, field.optional) {
+                    (FieldType::String, false) => {
+                        quote! { ::std::convert::Into::<::std::string::String>::into(#ident) }
+                    }
+                    _ => quote! { #ident },
+                };
+                params.push(quote! { #ident: #param_ty });
+                let insert = quote! {
+                    __fields.insert(
+                        #key.to_string(),
+                        #json::to_value(#value).unwrap_or(#json::Value::Null),
+                    );
+                };
+                // Contracts may reject an explicit `null` for a missing field.
+                inserts.push(if field.optional {
+                    quote! {
+                        if let ::std::option::Option::Some(#ident) = #ident {
+                            #insert
+                        }
+                    }
+                } else {
+                    insert
+                });
+            }
+            let body = quote! {{
+                let mut __fields = #json::Map::new();
+                #(#inserts)*
+                let mut __msg = #json::Map::new();
+                __msg.insert(#name.to_string(), #json::Value::Object(__fields));
+                #json::Value::Object(__msg)
+            }};
+            Ok((params, body))
+        }
+    }
+}
+
+fn doc(variant: &Variant) -> TokenStream2 {
+    match &variant.description {
+        Some(description) => quote! { #[doc = #description] },
+        None => quote! {},
+    }
+}
+
+pub(crate) fn do_contract_client(input: TokenStream) -> TokenStream {
+    let ContractClientInput {
+        krate,
+        vis,
+        name,
+        schema_dir,
+    } = parse_macro_input!(input as ContractClientInput);
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".into());
+    let dir = Path::new(&manifest_dir).join(schema_dir.value());
+    let error = |msg: String| {
+        syn::Error::new(schema_dir.span(), msg)
+            .to_compile_error()
+            .into()
+    };
+    let schema = match load_schema(&dir) {
+        Ok(schema) => schema,
+        Err(e) => return error(e),
+    };
+
+    let paths = Paths {
+        krate: quote! { #krate },
+        core: quote! { #krate::__private::terra_sdk_core },
+        json: quote! { #krate::__private::serde_json },
+    };
+    let Paths { krate, core, json } = &paths;
+
+    let mut methods = vec![];
+    let mut method_names = vec![];
+    if let Some(execute) = &schema.execute {
+        let variants = match variants(execute) {
+            Ok(variants) => variants,
+            Err(e) => return error(format!("execute schema: {}", e)),
+        };
+        for variant in variants.iter() {
+            let method = prefixed_ident("execute", &variant.name);
+            if method_names.contains(&method) {
+                return error(format!("execute schema: duplicate method `{}`", method));
+            }
+            method_names.push(method.clone());
+            let (params, body) = match params_and_body(variant, &paths) {
+                Ok(params_and_body) => params_and_body,
+                Err(e) => return error(format!("execute schema: {}", e)),
+            };
+            let doc = doc(variant);
+            methods.push(quote! {
+                #doc
+                #[allow(clippy::too_many_arguments)]
+                pub fn #method(
+                    &self,
+                    __sender: impl ::std::convert::Into<#core::AccAddress>,
+                    __funds: impl ::std::convert::Into<#core::Coins>,
+                    #(#params,)*
+                ) -> #core::wasm::MsgExecuteContract<#json::Value> {
+                    #core::wasm::MsgExecuteContract::new(
+                        __sender,
+                        self.contract.clone(),
+                        #body,
+                        __funds,
+                    )
+                }
+            });
+        }
+    }
+    if let Some(query) = &schema.query {
+        let variants = match variants(query) {
+            Ok(variants) => variants,
+            Err(e) => return error(format!("query schema: {}", e)),
+        };
+        for variant in variants.iter() {
+            let method = prefixed_ident("query", &variant.name);
+            if method_names.contains(&method) {
+                return error(format!("query schema: duplicate method `{}`", method));
+            }
+            method_names.push(method.clone());
+            let (params, body) = match params_and_body(variant, &paths) {
+                Ok(params_and_body) => params_and_body,
+                Err(e) => return error(format!("query schema: {}", e)),
+            };
+            let doc = doc(variant);
+            methods.push(quote! {
+                #doc
+                #[allow(clippy::too_many_arguments)]
+                pub async fn #method<T, R>(
+                    &self,
+                    __api: &#krate::WasmApi<T>,
+                    #(#params,)*
+                ) -> ::std::result::Result<R, ::std::string::String>
+                where
+                    T: #krate::ApiRequester,
+                    R: #krate::__private::serde::de::DeserializeOwned,
+                {
+                    __api.contract_query(&self.contract, &#body).await
+                }
+            });
+        }
+    }
+
+    let tracked = schema.files.iter().map(|path| {
+        let path = path.to_string_lossy().to_string();
+        quote! { const _: &str = ::std::include_str!(#path); }
+    });
+
+    let expanded = quote! {
+        #(#tracked)*
+
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis struct #name {
+            pub contract: #core::AccAddress,
+        }
+
+        impl #name {
+            pub fn new(contract: impl ::std::convert::Into<#core::AccAddress>) -> Self {
+                Self {
+                    contract: contract.into(),
+                }
+            }
+
+            #(#methods)*
+        }
+    };
+    expanded.into()
+}
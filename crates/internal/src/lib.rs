@@ -1,7 +1,25 @@
 use proc_macro::TokenStream;
+mod contract_client;
 mod msg_type;
 
+/// Implements `MsgType` and `JsonSer` (Amino JSON) for a message struct.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize, MsgType)]
+/// #[msgtype("bank/MsgSend")]
+/// #[msgtype_pb("/cosmos.bank.v1beta1.MsgSend")]
+/// pub struct MsgSend { .. }
+/// ```
 #[proc_macro_derive(MsgType, attributes(msgtype, msgtype_pb))]
 pub fn derive_msg_type(input: TokenStream) -> TokenStream {
     msg_type::do_derive_msg_type(input)
 }
+
+/// Generates a typed contract client from a CosmWasm `schema/` directory.
+///
+/// Not meant to be called directly; use `terra_sdk_client_lcd::contract_client!`,
+/// which supplies the path to the runtime dependencies.
+#[proc_macro]
+pub fn contract_client(input: TokenStream) -> TokenStream {
+    contract_client::do_contract_client(input)
+}
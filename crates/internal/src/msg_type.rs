@@ -1,24 +1,74 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Path};
+use syn::{parse_macro_input, Attribute, DeriveInput, LitStr};
+
+fn find_attr_str(attrs: &[Attribute], name: &str) -> syn::Result<Option<LitStr>> {
+    for attr in attrs {
+        if attr.path().is_ident(name) {
+            return attr.parse_args::<LitStr>().map(Some);
+        }
+    }
+    Ok(None)
+}
 
 pub(crate) fn do_derive_msg_type(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let DeriveInput {
-        ident, data, attrs, ..
+        ident,
+        attrs,
+        generics,
+        ..
     } = ast;
 
-    let expanded = quote! {
-        impl<'de> MsgType<'de> for #ident {
-            fn to_json(&self) -> ::serde_json::Value {
-                "hello".into()
-            }
+    let amino_type = match find_attr_str(&attrs, "msgtype") {
+        Ok(Some(lit)) => lit,
+        Ok(None) => {
+            return syn::Error::new(ident.span(), "missing #[msgtype(\"...\")] attribute")
+                .to_compile_error()
+                .into()
+        }
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let type_url = match find_attr_str(&attrs, "msgtype_pb") {
+        Ok(Some(lit)) => lit,
+        Ok(None) => {
+            return syn::Error::new(ident.span(), "missing #[msgtype_pb(\"...\")] attribute")
+                .to_compile_error()
+                .into()
+        }
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-            fn from_json(data: &::serde_json::Value) -> Self {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let json_where = match where_clause {
+        Some(clause) => quote! { #clause, Self: ::serde::Serialize },
+        None => quote! { where Self: ::serde::Serialize },
+    };
 
-            }
+    let expanded = quote! {
+        impl #impl_generics crate::MsgType for #ident #ty_generics #where_clause {
+            const AMINO_TYPE: &'static str = #amino_type;
+            const TYPE_URL: &'static str = #type_url;
         }
 
+        impl #impl_generics crate::JsonSer for #ident #ty_generics #json_where {
+            fn to_json(&self) -> ::std::result::Result<::serde_json::Value, ::std::string::String> {
+                let value = crate::internal::json_form::with_form(false, || {
+                    ::serde_json::to_value(self)
+                })
+                .map_err(|e| {
+                    ::std::format!(
+                        "failed to serialize {}: {}",
+                        ::std::stringify!(#ident),
+                        e
+                    )
+                })?;
+                ::std::result::Result::Ok(::serde_json::json!({
+                    "type": #amino_type,
+                    "value": value,
+                }))
+            }
+        }
     };
 
     expanded.into()
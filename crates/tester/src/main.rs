@@ -1,6 +1,10 @@
-use terra_sdk_core::{bank::MsgSend, sdk::*};
+use terra_sdk_core::{bank::MsgSend, JsonSer};
 
 fn main() {
-    let send = MsgSend::from();
-    println!("Hello, world!");
+    let send = MsgSend::new(
+        "terra1y4umfuqfg76t8mfcff6zzx7elvy93jtp4xcdvw",
+        "terra1v9ku44wycfnsucez6fp085f5fsksp47u9x8jr4",
+        "1000000uluna",
+    );
+    println!("{}", send.to_json().unwrap());
 }